@@ -14,6 +14,9 @@ pub enum DataError {
         sub_kind: SubKind,
     },
 
+    #[error("unknown ExchangeId: {0}")]
+    UnknownExchange(String),
+
     #[error(
         "\
         InvalidSequence: first_update_id {first_update_id} does not follow on from the \
@@ -51,7 +51,9 @@ impl AsRef<str> for GateioMarket {
 
 fn gateio_market(instrument: &Instrument) -> GateioMarket {
     use InstrumentKind::*;
-    let Instrument { base, quote, kind } = instrument;
+    let Instrument {
+        base, quote, kind, ..
+    } = instrument;
 
     GateioMarket(
         match kind {
@@ -1,5 +1,6 @@
 use self::subscription::ExchangeSub;
 use crate::{
+    error::DataError,
     instrument::InstrumentData,
     subscriber::{validator::SubscriptionValidator, Subscriber},
     subscription::{Map, SubKind, SubscriptionKind},
@@ -12,6 +13,7 @@ use barter_integration::{
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{
     fmt::{Debug, Display},
+    str::FromStr,
     time::Duration,
 };
 use url::Url;
@@ -199,6 +201,32 @@ impl Display for ExchangeId {
     }
 }
 
+impl FromStr for ExchangeId {
+    type Err = DataError;
+
+    /// Parse an [`ExchangeId`] from it's (case-insensitive) &str representation.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "binance_spot" => Ok(ExchangeId::BinanceSpot),
+            "binance_futures_usd" => Ok(ExchangeId::BinanceFuturesUsd),
+            "bitfinex" => Ok(ExchangeId::Bitfinex),
+            "bitmex" => Ok(ExchangeId::Bitmex),
+            "bybit_spot" => Ok(ExchangeId::BybitSpot),
+            "bybit_perpetuals_usd" => Ok(ExchangeId::BybitPerpetualsUsd),
+            "coinbase" => Ok(ExchangeId::Coinbase),
+            "gateio_spot" => Ok(ExchangeId::GateioSpot),
+            "gateio_futures_usd" => Ok(ExchangeId::GateioFuturesUsd),
+            "gateio_futures_btc" => Ok(ExchangeId::GateioFuturesBtc),
+            "gateio_perpetuals_usd" => Ok(ExchangeId::GateioPerpetualsUsd),
+            "gateio_perpetuals_btc" => Ok(ExchangeId::GateioPerpetualsBtc),
+            "gateio_options" => Ok(ExchangeId::GateioOptions),
+            "kraken" => Ok(ExchangeId::Kraken),
+            "okx" => Ok(ExchangeId::Okx),
+            _ => Err(DataError::UnknownExchange(input.to_owned())),
+        }
+    }
+}
+
 impl ExchangeId {
     /// Return the &str representation of this [`ExchangeId`]
     pub fn as_str(&self) -> &'static str {
@@ -281,3 +309,64 @@ impl ExchangeId {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exchange_id_from_str() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<ExchangeId>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: as_str representation
+                input: "binance_spot",
+                expected: Some(ExchangeId::BinanceSpot),
+            },
+            TestCase {
+                // TC1: case-insensitive
+                input: "COINBASE",
+                expected: Some(ExchangeId::Coinbase),
+            },
+            TestCase {
+                // TC2: unknown exchange
+                input: "binance",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = ExchangeId::from_str(test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_exchange_id_from_str_round_trips_as_str() {
+        let exchanges = [
+            ExchangeId::BinanceFuturesUsd,
+            ExchangeId::BinanceSpot,
+            ExchangeId::Bitfinex,
+            ExchangeId::Bitmex,
+            ExchangeId::BybitSpot,
+            ExchangeId::BybitPerpetualsUsd,
+            ExchangeId::Coinbase,
+            ExchangeId::GateioSpot,
+            ExchangeId::GateioFuturesUsd,
+            ExchangeId::GateioFuturesBtc,
+            ExchangeId::GateioPerpetualsBtc,
+            ExchangeId::GateioPerpetualsUsd,
+            ExchangeId::GateioOptions,
+            ExchangeId::Kraken,
+            ExchangeId::Okx,
+        ];
+
+        for exchange in exchanges {
+            assert_eq!(ExchangeId::from_str(exchange.as_str()).unwrap(), exchange);
+        }
+    }
+}
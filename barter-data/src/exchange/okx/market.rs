@@ -47,7 +47,9 @@ impl AsRef<str> for OkxMarket {
 
 fn okx_market(instrument: &Instrument) -> OkxMarket {
     use InstrumentKind::*;
-    let Instrument { base, quote, kind } = instrument;
+    let Instrument {
+        base, quote, kind, ..
+    } = instrument;
 
     OkxMarket(match kind {
         Spot => format!("{base}-{quote}").to_uppercase(),
@@ -1,6 +1,8 @@
 use super::SubscriptionKind;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use thiserror::Error;
 
 /// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`Candle`]
 /// [`MarketEvent<T>`](crate::event::MarketEvent) events.
@@ -11,8 +13,25 @@ impl SubscriptionKind for Candles {
     type Event = Candle;
 }
 
+/// Reasons a [`Candle`] fails [`Candle::validate`].
+#[derive(Copy, Clone, PartialEq, Debug, Error)]
+pub enum CandleValidationError {
+    #[error("Candle {field} price must be finite & positive: {value}")]
+    InvalidPrice { field: &'static str, value: f64 },
+
+    #[error("Candle high {high} is below the low {low}")]
+    HighBelowLow { high: f64, low: f64 },
+
+    #[error("Candle volume must be finite & non-negative: {0}")]
+    InvalidVolume(f64),
+}
+
 /// Normalised Barter OHLCV [`Candle`] model.
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+///
+/// Equality & ordering compare the `close_time` first, followed by every other field, using
+/// [`f64::total_cmp`] for prices & volume. This makes them total & NaN-safe (NaN == NaN), but
+/// means 0.0 & -0.0 are not considered equal.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
 pub struct Candle {
     pub close_time: DateTime<Utc>,
     pub open: f64,
@@ -22,3 +41,280 @@ pub struct Candle {
     pub volume: f64,
     pub trade_count: u64,
 }
+
+impl Candle {
+    /// Constructs a [`Candle`] from it's OHLCV values, validating them via [`Candle::validate`].
+    /// The `trade_count` is zero.
+    pub fn from_ohlcv(
+        close_time: DateTime<Utc>,
+        open: f64,
+        high: f64,
+        low: f64,
+        close: f64,
+        volume: f64,
+    ) -> Result<Candle, CandleValidationError> {
+        let candle = Candle {
+            close_time,
+            open,
+            high,
+            low,
+            close,
+            volume,
+            trade_count: 0,
+        };
+
+        candle.validate().map(|_| candle)
+    }
+
+    /// Constructs a [`Candle`] where the open, high & low equal the close price, with zero
+    /// volume & trades. Useful for tests that only care about the close price.
+    pub fn from_close_only(close_time: DateTime<Utc>, close: f64) -> Candle {
+        Candle {
+            close_time,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 0.0,
+            trade_count: 0,
+        }
+    }
+
+    /// Validates the [`Candle`] has finite & positive prices, a high that is not below the low,
+    /// and a finite & non-negative volume.
+    pub fn validate(&self) -> Result<(), CandleValidationError> {
+        for (field, value) in [
+            ("open", self.open),
+            ("high", self.high),
+            ("low", self.low),
+            ("close", self.close),
+        ] {
+            if !(value.is_finite() && value > 0.0) {
+                return Err(CandleValidationError::InvalidPrice { field, value });
+            }
+        }
+
+        if self.high < self.low {
+            return Err(CandleValidationError::HighBelowLow {
+                high: self.high,
+                low: self.low,
+            });
+        }
+
+        if !(self.volume.is_finite() && self.volume >= 0.0) {
+            return Err(CandleValidationError::InvalidVolume(self.volume));
+        }
+
+        Ok(())
+    }
+
+    /// Typical price of the [`Candle`] - (high + low + close) / 3.
+    pub fn typical_price(&self) -> f64 {
+        (self.high + self.low + self.close) / 3.0
+    }
+
+    /// Weighted close price of the [`Candle`] - (high + low + 2 * close) / 4.
+    pub fn weighted_close(&self) -> f64 {
+        (self.high + self.low + 2.0 * self.close) / 4.0
+    }
+
+    /// Midpoint between the high & low price of the [`Candle`] - (high + low) / 2.
+    pub fn hl_midpoint(&self) -> f64 {
+        (self.high + self.low) / 2.0
+    }
+
+    /// Range between the high & low price of the [`Candle`] - high - low.
+    pub fn range(&self) -> f64 {
+        self.high - self.low
+    }
+
+    /// Determines if both [`Candle`]s cover the same period (ie/ have the same `close_time`),
+    /// regardless of their OHLCV values.
+    pub fn is_same_period(&self, other: &Candle) -> bool {
+        self.close_time == other.close_time
+    }
+
+    /// Key identifying the period of the [`Candle`], used to deduplicate a data feed via a
+    /// `HashMap` or `HashSet`. Formed of the `close_time` seconds since the epoch & the
+    /// sub-second nanoseconds.
+    pub fn deduplication_key(&self) -> (i64, u32) {
+        (
+            self.close_time.timestamp(),
+            self.close_time.timestamp_subsec_nanos(),
+        )
+    }
+}
+
+impl PartialEq for Candle {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candle {}
+
+impl PartialOrd for Candle {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candle {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.close_time
+            .cmp(&other.close_time)
+            .then_with(|| self.open.total_cmp(&other.open))
+            .then_with(|| self.high.total_cmp(&other.high))
+            .then_with(|| self.low.total_cmp(&other.low))
+            .then_with(|| self.close.total_cmp(&other.close))
+            .then_with(|| self.volume.total_cmp(&other.volume))
+            .then_with(|| self.trade_count.cmp(&other.trade_count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candle_derived_prices() {
+        let candle = Candle {
+            close_time: Utc::now(),
+            open: 100.0,
+            high: 120.0,
+            low: 90.0,
+            close: 111.0,
+            volume: 1000.0,
+            trade_count: 10,
+        };
+
+        assert_eq!(candle.typical_price(), 107.0);
+        assert_eq!(candle.weighted_close(), 108.0);
+        assert_eq!(candle.hl_midpoint(), 105.0);
+        assert_eq!(candle.range(), 30.0);
+    }
+
+    #[test]
+    fn test_candle_from_ohlcv_validates_inputs() {
+        let close_time = Utc::now();
+
+        let candle = Candle::from_ohlcv(close_time, 100.0, 120.0, 90.0, 111.0, 1000.0).unwrap();
+        assert_eq!(candle.range(), 30.0);
+        assert_eq!(candle.trade_count, 0);
+
+        assert_eq!(
+            Candle::from_ohlcv(close_time, 100.0, 90.0, 120.0, 111.0, 1000.0),
+            Err(CandleValidationError::HighBelowLow {
+                high: 90.0,
+                low: 120.0
+            })
+        );
+        assert_eq!(
+            Candle::from_ohlcv(close_time, 100.0, 120.0, 0.0, 111.0, 1000.0),
+            Err(CandleValidationError::InvalidPrice {
+                field: "low",
+                value: 0.0
+            })
+        );
+        assert!(matches!(
+            Candle::from_ohlcv(close_time, 100.0, 120.0, 90.0, f64::NAN, 1000.0),
+            Err(CandleValidationError::InvalidPrice { field: "close", .. })
+        ));
+        assert_eq!(
+            Candle::from_ohlcv(close_time, 100.0, 120.0, 90.0, 111.0, -1.0),
+            Err(CandleValidationError::InvalidVolume(-1.0))
+        );
+    }
+
+    #[test]
+    fn test_candle_from_close_only() {
+        let candle = Candle::from_close_only(Utc::now(), 105.0);
+
+        assert_eq!(candle.open, 105.0);
+        assert_eq!(candle.high, 105.0);
+        assert_eq!(candle.low, 105.0);
+        assert_eq!(candle.volume, 0.0);
+        assert_eq!(candle.range(), 0.0);
+        assert!(candle.validate().is_ok());
+    }
+
+    #[test]
+    fn test_candle_equality_and_ordering() {
+        let close_time = Utc::now();
+        let candle = Candle {
+            close_time,
+            open: 100.0,
+            high: 120.0,
+            low: 90.0,
+            close: 111.0,
+            volume: 1000.0,
+            trade_count: 10,
+        };
+
+        // Identical Candles are equal
+        assert_eq!(candle, candle);
+
+        // NaN values are equal to themselves
+        let nan = Candle {
+            volume: f64::NAN,
+            ..candle
+        };
+        assert_eq!(nan, nan);
+        assert_ne!(nan, candle);
+
+        // Signed zeros are distinguished
+        let zero = Candle {
+            volume: 0.0,
+            ..candle
+        };
+        let negative_zero = Candle {
+            volume: -0.0,
+            ..candle
+        };
+        assert_ne!(zero, negative_zero);
+        assert!(negative_zero < zero);
+
+        // Candles are ordered by close_time first
+        let later = Candle {
+            close_time: close_time + chrono::Duration::minutes(1),
+            open: 1.0,
+            ..candle
+        };
+        assert!(candle < later);
+        assert_eq!(candle.max(later), later);
+    }
+
+    #[test]
+    fn test_candle_same_period_and_deduplication_key() {
+        let close_time = Utc::now();
+        let candle = Candle {
+            close_time,
+            open: 100.0,
+            high: 120.0,
+            low: 90.0,
+            close: 111.0,
+            volume: 1000.0,
+            trade_count: 10,
+        };
+        let revised = Candle {
+            close: 112.0,
+            ..candle
+        };
+        let later = Candle {
+            close_time: close_time + chrono::Duration::nanoseconds(1),
+            ..candle
+        };
+
+        assert!(candle.is_same_period(&revised));
+        assert!(!candle.is_same_period(&later));
+
+        assert_eq!(candle.deduplication_key(), revised.deduplication_key());
+        assert_ne!(candle.deduplication_key(), later.deduplication_key());
+
+        let unique = [candle, revised, later]
+            .iter()
+            .map(Candle::deduplication_key)
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(unique.len(), 2);
+    }
+}
@@ -1,6 +1,16 @@
 use crate::model::instrument::{kind::InstrumentKind, symbol::Symbol};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
 use serde::{Deserialize, Serialize};
-use std::fmt::{Display, Formatter};
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+use thiserror::Error;
 
 pub mod kind;
 pub mod symbol;
@@ -9,13 +19,48 @@ pub mod symbol;
 /// associated instrument type.
 ///
 /// eg/ Instrument { base: "btc", quote: "usdt", kind: Spot }
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+///
+/// The optional `tick_size` & `lot_size` precision rules do not form part of an [`Instrument`]'s
+/// identity, so they are ignored when comparing or hashing [`Instrument`]s.
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct Instrument {
     pub base: Symbol,
     pub quote: Symbol,
     #[serde(rename = "instrument_kind")]
     pub kind: InstrumentKind,
+    /// Minimum price increment. Zero if the [`Instrument`] price is unconstrained.
+    #[serde(default, skip_serializing_if = "is_unconstrained")]
+    pub tick_size: f64,
+    /// Minimum quantity increment. Zero if the [`Instrument`] quantity is unconstrained.
+    #[serde(default, skip_serializing_if = "is_unconstrained")]
+    pub lot_size: f64,
+}
+
+impl PartialEq for Instrument {
+    fn eq(&self, other: &Self) -> bool {
+        self.identity() == other.identity()
+    }
+}
+
+impl Eq for Instrument {}
+
+impl PartialOrd for Instrument {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Instrument {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.identity().cmp(&other.identity())
+    }
+}
+
+impl Hash for Instrument {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.identity().hash(state)
+    }
 }
 
 impl Display for Instrument {
@@ -29,16 +74,26 @@ where
     S: Into<Symbol>,
 {
     fn from((base, quote, kind): (S, S, InstrumentKind)) -> Self {
-        Self {
-            base: base.into(),
-            quote: quote.into(),
-            kind,
-        }
+        Self::new(base, quote, kind)
+    }
+}
+
+impl FromStr for Instrument {
+    type Err = InstrumentParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_from_str(s)
     }
 }
 
+/// Maximum length of an [`Instrument`] base or quote [`Symbol`] accepted by
+/// [`Instrument::try_new`].
+pub const INSTRUMENT_SYMBOL_MAX_LEN: usize = 20;
+
 impl Instrument {
-    /// Constructs a new [`Instrument`] using the provided configuration.
+    /// Constructs a new [`Instrument`] using the provided configuration, without validation.
+    ///
+    /// Prefer [`Instrument::try_new`] when constructing an [`Instrument`] from untrusted input.
     pub fn new<S>(base: S, quote: S, kind: InstrumentKind) -> Self
     where
         S: Into<Symbol>,
@@ -47,8 +102,173 @@ impl Instrument {
             base: base.into(),
             quote: quote.into(),
             kind,
+            tick_size: 0.0,
+            lot_size: 0.0,
+        }
+    }
+
+    /// Constructs a new validated [`Instrument`] using the provided configuration.
+    ///
+    /// Rejects empty base or quote [`Symbol`]s, [`Symbol`]s longer than
+    /// [`INSTRUMENT_SYMBOL_MAX_LEN`], and tautological pairs where the base equals the quote
+    /// (eg/ "usd_usd").
+    pub fn try_new<S>(base: S, quote: S, kind: InstrumentKind) -> Result<Self, InstrumentError>
+    where
+        S: Into<Symbol>,
+    {
+        let instrument = Self::new(base, quote, kind);
+        let (base, quote) = instrument.to_pair();
+
+        let reason = if base.is_empty() || quote.is_empty() {
+            Some("base & quote symbols must not be empty")
+        } else if base.len() > INSTRUMENT_SYMBOL_MAX_LEN || quote.len() > INSTRUMENT_SYMBOL_MAX_LEN
+        {
+            Some("base & quote symbols must not exceed the maximum symbol length")
+        } else if base == quote {
+            Some("base & quote symbols must not be equal")
+        } else {
+            None
+        };
+
+        match reason {
+            None => Ok(instrument),
+            Some(reason) => Err(InstrumentError {
+                base: base.to_owned(),
+                quote: quote.to_owned(),
+                reason,
+            }),
         }
     }
+
+    /// Sets the `tick_size` & `lot_size` precision rules of this [`Instrument`].
+    pub fn with_precision(self, tick_size: f64, lot_size: f64) -> Self {
+        Self {
+            tick_size,
+            lot_size,
+            ..self
+        }
+    }
+
+    /// Parses a "BASE-QUOTE" style pair into an [`InstrumentKind::Spot`] [`Instrument`]. The base
+    /// & quote [`Symbol`]s may be separated by any one of '-', '/' or '_'.
+    ///
+    /// eg/ "BTC-USD", "ETH/USDT", "SOL_PERP"
+    pub fn parse_from_str(s: &str) -> Result<Self, InstrumentParseError> {
+        let parse_error = |reason| InstrumentParseError {
+            input: s.to_owned(),
+            reason,
+        };
+
+        let mut symbols = s.trim().split(['-', '/', '_']);
+
+        let (Some(base), Some(quote), None) = (symbols.next(), symbols.next(), symbols.next())
+        else {
+            return Err(parse_error(
+                "expected exactly one '-', '/' or '_' separator",
+            ));
+        };
+
+        if base.is_empty() || quote.is_empty() {
+            return Err(parse_error("base & quote symbols must not be empty"));
+        }
+
+        Ok(Self::new(base, quote, InstrumentKind::Spot))
+    }
+
+    /// Returns the `(base, quote)` [`Symbol`]s of this [`Instrument`] as `&str`s.
+    pub fn to_pair(&self) -> (&str, &str) {
+        (self.base.as_ref(), self.quote.as_ref())
+    }
+
+    /// Truncates the price toward zero to the nearest multiple of the `tick_size`, ensuring it
+    /// never exceeds the input price. Prices of unconstrained [`Instrument`]s are unchanged.
+    pub fn round_price(&self, price: f64) -> f64 {
+        truncate_to_step(price, self.tick_size)
+    }
+
+    /// Truncates the quantity toward zero to the nearest multiple of the `lot_size`, ensuring it
+    /// never exceeds the input quantity. Quantities of unconstrained [`Instrument`]s are unchanged.
+    pub fn round_quantity(&self, quantity: f64) -> f64 {
+        truncate_to_step(quantity, self.lot_size)
+    }
+
+    /// Validates that an order price & quantity conform to this [`Instrument`]'s `tick_size` &
+    /// `lot_size` precision rules.
+    pub fn validate_order(
+        &self,
+        price: f64,
+        quantity: f64,
+    ) -> Result<(), InstrumentValidationError> {
+        if self.round_price(price) != price {
+            return Err(InstrumentValidationError::InvalidPrice {
+                price,
+                tick_size: self.tick_size,
+            });
+        }
+
+        if self.round_quantity(quantity) != quantity {
+            return Err(InstrumentValidationError::InvalidQuantity {
+                quantity,
+                lot_size: self.lot_size,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Fields that uniquely identify an [`Instrument`], excluding the precision rules.
+    fn identity(&self) -> (&Symbol, &Symbol, &InstrumentKind) {
+        (&self.base, &self.quote, &self.kind)
+    }
+}
+
+/// Truncates the value toward zero to the nearest multiple of the step. Decimal arithmetic is used
+/// to avoid floating point error (eg/ 0.3 / 0.1 = 2.9999999999999996). Values are unchanged if the
+/// step is not positive, or either cannot be represented as a [`Decimal`].
+fn truncate_to_step(value: f64, step: f64) -> f64 {
+    if step <= 0.0 {
+        return value;
+    }
+
+    let truncate = || {
+        let (value, step) = (Decimal::from_f64(value)?, Decimal::from_f64(step)?);
+        ((value / step).trunc() * step).to_f64()
+    };
+
+    truncate().unwrap_or(value)
+}
+
+/// Determines if an [`Instrument`] precision rule is unset.
+fn is_unconstrained(step: &f64) -> bool {
+    *step == 0.0
+}
+
+/// Error returned when parsing an [`Instrument`] from a "BASE-QUOTE" style `&str` fails.
+#[derive(Clone, Eq, PartialEq, Debug, Error)]
+#[error("failed to parse Instrument from {input:?}: {reason}")]
+pub struct InstrumentParseError {
+    pub input: String,
+    pub reason: &'static str,
+}
+
+/// Error returned when constructing an invalid [`Instrument`] via [`Instrument::try_new`].
+#[derive(Clone, Eq, PartialEq, Debug, Error)]
+#[error("invalid Instrument {base}_{quote}: {reason}")]
+pub struct InstrumentError {
+    pub base: String,
+    pub quote: String,
+    pub reason: &'static str,
+}
+
+/// Error returned when an order price or quantity does not conform to an [`Instrument`]'s
+/// precision rules.
+#[derive(Copy, Clone, PartialEq, Debug, Error)]
+pub enum InstrumentValidationError {
+    #[error("order price {price} is not a multiple of the tick size {tick_size}")]
+    InvalidPrice { price: f64, tick_size: f64 },
+
+    #[error("order quantity {quantity} is not a multiple of the lot size {lot_size}")]
+    InvalidQuantity { quantity: f64, lot_size: f64 },
 }
 
 #[cfg(test)]
@@ -162,4 +382,146 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_instrument_parse_from_str() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<Instrument, ()>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Dash separated
+                input: "BTC-USD",
+                expected: Ok(Instrument::from(("btc", "usd", InstrumentKind::Spot))),
+            },
+            TestCase {
+                // TC1: Slash separated
+                input: "ETH/USDT",
+                expected: Ok(Instrument::from(("eth", "usdt", InstrumentKind::Spot))),
+            },
+            TestCase {
+                // TC2: Underscore separated
+                input: "SOL_PERP",
+                expected: Ok(Instrument::from(("sol", "perp", InstrumentKind::Spot))),
+            },
+            TestCase {
+                // TC3: Invalid, no separator
+                input: "BTCUSD",
+                expected: Err(()),
+            },
+            TestCase {
+                // TC4: Invalid, too many separators
+                input: "BTC-USD-PERP",
+                expected: Err(()),
+            },
+            TestCase {
+                // TC5: Invalid, empty quote
+                input: "BTC-",
+                expected: Err(()),
+            },
+            TestCase {
+                // TC6: Invalid, empty input
+                input: "",
+                expected: Err(()),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input.parse::<Instrument>();
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(error), Err(_)) => {
+                    assert_eq!(error.input, test.input, "TC{} failed", index)
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_instrument_try_new() {
+        let invalid = |base: &str, quote: &str| {
+            Instrument::try_new(base, quote, InstrumentKind::Spot)
+                .unwrap_err()
+                .reason
+        };
+
+        assert_eq!(
+            Instrument::try_new("BTC", "usdt", InstrumentKind::Spot),
+            Ok(Instrument::from(("btc", "usdt", InstrumentKind::Spot)))
+        );
+        assert_eq!(
+            invalid("", "usdt"),
+            "base & quote symbols must not be empty"
+        );
+        assert_eq!(invalid("btc", ""), "base & quote symbols must not be empty");
+        assert_eq!(
+            invalid("usd", "USD"),
+            "base & quote symbols must not be equal"
+        );
+        assert_eq!(
+            invalid(&"a".repeat(INSTRUMENT_SYMBOL_MAX_LEN + 1), "usd"),
+            "base & quote symbols must not exceed the maximum symbol length"
+        );
+        assert!(Instrument::try_new(
+            "a".repeat(INSTRUMENT_SYMBOL_MAX_LEN),
+            "usd".to_owned(),
+            InstrumentKind::Spot
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_instrument_to_pair() {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        assert_eq!(instrument.to_pair(), ("btc", "usdt"));
+    }
+
+    #[test]
+    fn test_instrument_round_price_and_quantity() {
+        let instrument =
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)).with_precision(0.01, 0.5);
+
+        assert_eq!(instrument.round_price(0.12345), 0.12);
+        assert_eq!(instrument.round_price(0.3), 0.3);
+        assert_eq!(instrument.round_quantity(1.7), 1.5);
+        assert_eq!(instrument.round_quantity(-1.7), -1.5);
+
+        // Unconstrained Instrument is unchanged
+        let unconstrained = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        assert_eq!(unconstrained.round_price(0.12345), 0.12345);
+        assert_eq!(unconstrained.round_quantity(1.7), 1.7);
+
+        // Precision rules do not affect Instrument identity
+        assert_eq!(instrument, unconstrained);
+    }
+
+    #[test]
+    fn test_instrument_validate_order() {
+        let instrument =
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)).with_precision(0.01, 0.5);
+
+        assert_eq!(instrument.validate_order(0.12, 1.5), Ok(()));
+        assert_eq!(
+            instrument.validate_order(0.12345, 1.5),
+            Err(InstrumentValidationError::InvalidPrice {
+                price: 0.12345,
+                tick_size: 0.01
+            })
+        );
+        assert_eq!(
+            instrument.validate_order(0.12, 1.7),
+            Err(InstrumentValidationError::InvalidQuantity {
+                quantity: 1.7,
+                lot_size: 0.5
+            })
+        );
+    }
 }
@@ -125,6 +125,12 @@ impl Display for Exchange {
     }
 }
 
+impl AsRef<str> for Exchange {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 /// New type representing a unique `String` identifier for a stream that has been subscribed to.
 /// This is used to identify data structures received over the socket.
 ///
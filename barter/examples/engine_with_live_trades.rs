@@ -187,6 +187,10 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // Fill Event occurred in Engine
                 println!("{fill_event:?}");
             }
+            Event::FillRejected(fill_rejected) => {
+                // FillRejected Event occurred in Engine
+                println!("{fill_rejected:?}");
+            }
             Event::PositionNew(new_position) => {
                 // PositionNew Event occurred in Engine
                 println!("{new_position:?}");
@@ -0,0 +1,104 @@
+use barter::{
+    data::live,
+    engine::{Engine, PaperTradeConfig},
+    event::{Event, EventTx},
+    execution::MakerTakerFeeSchedule,
+    strategy::example::{Config as StrategyConfig, RSIStrategy},
+};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    exchange::{binance::spot::BinanceSpot, ExchangeId},
+    streams::Streams,
+    subscription::trade::PublicTrades,
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, Instrument},
+    Market,
+};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+const ENGINE_RUN_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[tokio::main]
+async fn main() {
+    // Create Event channel to listen to all Engine Events in real-time
+    let (event_tx, event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+
+    // Create the Market to paper trade
+    let market = Market::new("binance_spot", ("btc", "usdt", InstrumentKind::Spot));
+
+    // Build paper trading Engine with a simulated execution handler & in-memory Portfolio
+    let (engine, _command_tx) = Engine::paper_trade(
+        market,
+        live::MarketFeed::new(stream_market_event_trades().await),
+        RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+        PaperTradeConfig {
+            starting_cash: 10_000.0,
+            default_order_value: 100.0,
+            slippage_pct: 0.0005, // Simulated fills are 5 bps worse than the market price
+        },
+        MakerTakerFeeSchedule {
+            maker_pct: 0.0002,
+            taker_pct: 0.001,
+        },
+        event_tx,
+    )
+    .expect("failed to build paper trading engine");
+
+    // Run Engine paper trading & listen to Events it produces
+    tokio::spawn(listen_to_engine_events(event_rx));
+
+    let _ = tokio::time::timeout(ENGINE_RUN_TIMEOUT, engine.run()).await;
+}
+
+async fn stream_market_event_trades() -> mpsc::UnboundedReceiver<MarketEvent<Instrument, DataKind>>
+{
+    // Initialise PublicTrades Streams for BinanceSpot
+    let mut streams = Streams::<PublicTrades>::builder()
+        .subscribe([(
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        )])
+        .init()
+        .await
+        .unwrap();
+
+    // Select the ExchangeId::BinanceSpot stream
+    let mut trade_rx = streams.select(ExchangeId::BinanceSpot).unwrap();
+
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        while let Some(trade) = trade_rx.recv().await {
+            let _ = tx.send(MarketEvent::from(trade));
+        }
+    });
+
+    rx
+}
+
+// Listen to Events that occur in the paper trading Engine
+async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
+    while let Some(event) = event_rx.recv().await {
+        match event {
+            Event::Fill(fill_event) => {
+                println!("{fill_event:?}");
+            }
+            Event::PositionNew(new_position) => {
+                println!("{new_position:?}");
+            }
+            Event::PositionExit(exited_position) => {
+                println!("{exited_position:?}");
+            }
+            Event::Balance(balance_update) => {
+                println!("{balance_update:?}");
+            }
+            _ => {}
+        }
+    }
+}
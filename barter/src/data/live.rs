@@ -1,5 +1,9 @@
 use super::{Feed, MarketGenerator};
+use crate::engine::trader::BackpressurePolicy;
+use parking_lot::Mutex;
+use std::sync::Arc;
 use tokio::sync::mpsc;
+use tracing::warn;
 
 /// Live [`Feed`] of market events.
 #[derive(Debug)]
@@ -19,6 +23,19 @@ impl<Event> MarketGenerator<Event> for MarketFeed<Event> {
     }
 }
 
+/// Bounded [`mpsc::Receiver`] [`Feed`] of market events, enabling market events to be sourced
+/// from async tasks. Blocks whilst waiting for the next event, so must be polled from outside an
+/// async context (eg/ a [`Trader`](crate::engine::trader::Trader) run via
+/// [`Engine::run_async`](crate::engine::Engine::run_async)).
+impl<Event> MarketGenerator<Event> for mpsc::Receiver<Event> {
+    fn next(&mut self) -> Feed<Event> {
+        match self.blocking_recv() {
+            Some(event) => Feed::Next(event),
+            None => Feed::Finished,
+        }
+    }
+}
+
 impl<Event> MarketFeed<Event> {
     /// Initialises a live [`MarketFeed`] that yields market `Event`s from the provided
     /// [`mpsc::UnboundedReceiver`].
@@ -34,3 +51,170 @@ impl<Event> MarketFeed<Event> {
         Self { market_rx }
     }
 }
+
+/// Live [`Feed`] of market events received via a bounded channel, created with
+/// [`BoundedMarketFeed::new`]. Producers send market events via the paired [`BoundedMarketTx`],
+/// which applies a [`BackpressurePolicy`] once the channel is full because market events are
+/// arriving faster than the [`Trader`](crate::engine::trader::Trader) consumes them.
+#[derive(Debug)]
+pub struct BoundedMarketFeed<Event> {
+    market_rx: Arc<Mutex<mpsc::Receiver<Event>>>,
+}
+
+impl<Event> MarketGenerator<Event> for BoundedMarketFeed<Event> {
+    fn next(&mut self) -> Feed<Event> {
+        loop {
+            // Lock is released every iteration so a BoundedMarketTx can drop the oldest event
+            match self.market_rx.lock().try_recv() {
+                Ok(event) => break Feed::Next(event),
+                Err(mpsc::error::TryRecvError::Empty) => continue,
+                Err(mpsc::error::TryRecvError::Disconnected) => break Feed::Finished,
+            }
+        }
+    }
+}
+
+impl<Event> BoundedMarketFeed<Event> {
+    /// Initialises a [`BoundedMarketFeed`] that buffers at most `capacity` market events, and
+    /// the [`BoundedMarketTx`] used to send market events to it with the provided
+    /// [`BackpressurePolicy`].
+    pub fn new(capacity: usize, policy: BackpressurePolicy) -> (BoundedMarketTx<Event>, Self) {
+        let (market_tx, market_rx) = mpsc::channel(capacity);
+        let market_rx = Arc::new(Mutex::new(market_rx));
+
+        let tx = BoundedMarketTx {
+            market_tx,
+            market_rx: Arc::clone(&market_rx),
+            policy,
+        };
+
+        (tx, Self { market_rx })
+    }
+}
+
+impl<Event> Drop for BoundedMarketFeed<Event> {
+    /// Closes the channel, since each [`BoundedMarketTx`] shares ownership of the
+    /// [`mpsc::Receiver`]. Pending & subsequent sends then return an error rather than waiting
+    /// on, or dropping events into, a [`BoundedMarketFeed`] nobody reads.
+    fn drop(&mut self) {
+        self.market_rx.lock().close();
+    }
+}
+
+/// Sending half of a [`BoundedMarketFeed`] that applies a [`BackpressurePolicy`] when the
+/// channel is full.
+#[derive(Debug)]
+pub struct BoundedMarketTx<Event> {
+    market_tx: mpsc::Sender<Event>,
+    market_rx: Arc<Mutex<mpsc::Receiver<Event>>>,
+    policy: BackpressurePolicy,
+}
+
+impl<Event> Clone for BoundedMarketTx<Event> {
+    fn clone(&self) -> Self {
+        Self {
+            market_tx: self.market_tx.clone(),
+            market_rx: Arc::clone(&self.market_rx),
+            policy: self.policy,
+        }
+    }
+}
+
+impl<Event> BoundedMarketTx<Event> {
+    /// Sends a market event to the [`BoundedMarketFeed`]. If the channel is full, the
+    /// [`BackpressurePolicy`] is applied:
+    ///  - [`BackpressurePolicy::DropOldest`]: the oldest buffered market event is dropped to make
+    ///    room, so the producer never waits.
+    ///  - [`BackpressurePolicy::Block`]: waits until the [`BoundedMarketFeed`] has consumed a
+    ///    market event.
+    ///
+    /// Returns the market event if the [`BoundedMarketFeed`] has been dropped.
+    pub async fn send(&self, event: Event) -> Result<(), mpsc::error::SendError<Event>> {
+        match self.policy {
+            BackpressurePolicy::Block => self.market_tx.send(event).await,
+            BackpressurePolicy::DropOldest => {
+                let mut event = event;
+                loop {
+                    match self.market_tx.try_send(event) {
+                        Ok(()) => break Ok(()),
+                        Err(mpsc::error::TrySendError::Closed(event)) => {
+                            break Err(mpsc::error::SendError(event))
+                        }
+                        Err(mpsc::error::TrySendError::Full(rejected)) => {
+                            warn!(
+                                capacity = self.market_tx.max_capacity(),
+                                policy = ?self.policy,
+                                "BoundedMarketFeed full, dropping oldest market event"
+                            );
+                            let _ = self.market_rx.lock().try_recv();
+                            event = rejected;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain(feed: &mut BoundedMarketFeed<u32>) -> Vec<u32> {
+        std::iter::from_fn(|| feed.market_rx.lock().try_recv().ok()).collect()
+    }
+
+    #[tokio::test]
+    async fn bounded_market_feed_drops_oldest_events_once_full() {
+        let (tx, mut feed) = BoundedMarketFeed::new(3, BackpressurePolicy::DropOldest);
+
+        // Producer never waits, & only the most recent capacity events are buffered
+        for event in 1..=10 {
+            tx.send(event).await.unwrap();
+        }
+        assert_eq!(feed.next(), Feed::Next(8));
+        assert_eq!(drain(&mut feed), vec![9, 10]);
+
+        drop(tx);
+        assert_eq!(feed.next(), Feed::Finished);
+    }
+
+    #[tokio::test]
+    async fn bounded_market_feed_blocks_producer_until_consumed() {
+        let (tx, mut feed) = BoundedMarketFeed::new(2, BackpressurePolicy::Block);
+        tx.send(1).await.unwrap();
+        tx.send(2).await.unwrap();
+
+        // Producer waits whilst the channel is full
+        let blocked = tokio::time::timeout(std::time::Duration::from_millis(20), tx.send(3));
+        assert!(blocked.await.is_err());
+
+        assert_eq!(feed.next(), Feed::Next(1));
+        tx.send(3).await.unwrap();
+        assert_eq!(drain(&mut feed), vec![2, 3]);
+    }
+
+    #[tokio::test]
+    async fn bounded_market_tx_send_errors_once_feed_is_dropped_whilst_full() {
+        // Block: producer waiting on a full channel is released with an error
+        let (tx, feed) = BoundedMarketFeed::new(1, BackpressurePolicy::Block);
+        tx.send(1).await.unwrap();
+
+        let blocked = tokio::spawn({
+            let tx = tx.clone();
+            async move { tx.send(2).await }
+        });
+        tokio::task::yield_now().await;
+
+        drop(feed);
+        assert_eq!(blocked.await.unwrap().unwrap_err().0, 2);
+        assert_eq!(tx.send(3).await.unwrap_err().0, 3);
+
+        // DropOldest: events are no longer dropped into a feed nobody reads
+        let (tx, feed) = BoundedMarketFeed::new(1, BackpressurePolicy::DropOldest);
+        tx.send(1).await.unwrap();
+
+        drop(feed);
+        assert_eq!(tx.send(2).await.unwrap_err().0, 2);
+    }
+}
@@ -1,5 +1,9 @@
+use barter_data::event::MarketEvent;
+use barter_integration::model::instrument::Instrument;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use uuid::Uuid;
 
 /// Barter data module specific errors.
 pub mod error;
@@ -21,9 +25,34 @@ pub trait MarketGenerator<Event> {
 pub enum Feed<Event> {
     Next(Event),
     Unhealthy,
+    /// The feed has lost it's connection, but may recover if polled again (eg/ a reconnecting
+    /// WebSocket). See [`ReconnectPolicy`](crate::engine::trader::ReconnectPolicy).
+    ///
+    /// Only yielded by custom [`MarketGenerator`]s able to recover a connection. The live
+    /// [`MarketFeed`](live::MarketFeed) yields [`Feed::Finished`] once it's channel closes, since
+    /// a closed channel can never recover. Note this variant is a breaking change for downstream
+    /// code that exhaustively matches on [`Feed`].
+    Disconnected,
     Finished,
 }
 
+/// Returns the trace UUID of a [`MarketEvent`], derived from it's exchange, instrument &
+/// timestamps. [`MarketEvent`]s carry no trace identifier, so deriving it ensures every state
+/// change triggered by the same [`MarketEvent`] shares the same trace UUID.
+pub fn market_trace_id<Kind>(market: &MarketEvent<Instrument, Kind>) -> Uuid {
+    let hash = |salt: u64| {
+        let mut hasher = DefaultHasher::new();
+        salt.hash(&mut hasher);
+        market.exchange.hash(&mut hasher);
+        market.instrument.hash(&mut hasher);
+        market.exchange_time.hash(&mut hasher);
+        market.received_time.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    Uuid::from_u64_pair(hash(0), hash(1))
+}
+
 /// Metadata detailing the [`Candle`](barter_data::subscription::candle::Candle) or
 /// [`Trade`](barter_data::subscription::trade::PublicTrade) close price & it's associated
 /// timestamp. Used to propagate key market information in downstream Events.
@@ -0,0 +1,352 @@
+use super::{error::EngineError, trader::Trader, Engine, PaperTradePortfolio};
+use crate::{
+    data::historical,
+    event::{Event, EventTx},
+    execution::{simulated::SimulatedExecution, FeeSchedule},
+    portfolio::{
+        allocator::DefaultAllocator, portfolio::MetaPortfolio, position::Position,
+        repository::in_memory::InMemoryRepository, repository::PositionHandler, risk::DefaultRisk,
+    },
+    statistic::{
+        metric::EquityPoint,
+        summary::{
+            trading::{Config as StatisticConfig, TradingSummary},
+            Initialiser, PositionSummariser,
+        },
+    },
+    strategy::SignalGenerator,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::{instrument::Instrument, Market, MarketId};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{sync::Arc, time::Instant};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+/// Historical [`MarketEvent`] feed used by [`Engine::backtest`].
+pub type BacktestFeed = historical::MarketFeed<
+    std::vec::IntoIter<MarketEvent<Instrument, DataKind>>,
+    MarketEvent<Instrument, DataKind>,
+>;
+
+/// Configuration for running a backtest via [`Engine::backtest`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BacktestConfig {
+    /// Cash balance the backtest [`MetaPortfolio`] starts with.
+    pub starting_cash: f64,
+    /// Default [`OrderEvent`](crate::portfolio::OrderEvent) value used by the
+    /// [`DefaultAllocator`].
+    pub default_order_value: f64,
+}
+
+/// Aggregated output of a backtest run via [`Engine::backtest`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BacktestResult {
+    /// [`TradingSummary`] generated from every closed [`Position`].
+    pub stats: TradingSummary,
+    /// Portfolio total equity, starting with the starting cash & updated with every Balance
+    /// change generated by a fill.
+    pub equity_curve: Vec<EquityPoint>,
+    /// Every [`Position`] exited during the backtest.
+    pub closed_positions: Vec<Position>,
+    /// Counts of the [`Event`]s generated during the backtest.
+    pub engine_metrics: EngineMetrics,
+}
+
+/// Counts of the [`Event`]s generated whilst running a backtest, and the wall-clock time taken.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct EngineMetrics {
+    /// Number of [`MarketEvent`]s consumed.
+    pub market_events: usize,
+    /// Number of [`Signal`](crate::strategy::Signal)s generated by the strategy.
+    pub signals: usize,
+    /// Number of [`OrderEvent`](crate::portfolio::OrderEvent)s generated by the Portfolio.
+    pub orders: usize,
+    /// Number of [`FillEvent`](crate::execution::FillEvent)s generated by the execution handler.
+    pub fills: usize,
+    /// Wall-clock time taken to run the backtest.
+    pub elapsed: std::time::Duration,
+}
+
+impl EngineMetrics {
+    /// Updates the [`EngineMetrics`] event counts using the input [`Event`].
+    fn update(&mut self, event: &Event) {
+        match event {
+            Event::Market(_) => self.market_events += 1,
+            Event::Signal(_) => self.signals += 1,
+            Event::OrderNew(_) => self.orders += 1,
+            Event::Fill(_) => self.fills += 1,
+            _ => {}
+        }
+    }
+}
+
+impl<Strategy, Schedule>
+    Engine<
+        EventTx,
+        TradingSummary,
+        PaperTradePortfolio,
+        BacktestFeed,
+        Strategy,
+        SimulatedExecution<Schedule>,
+    >
+where
+    Strategy: SignalGenerator + Send + 'static,
+    Schedule: FeeSchedule + Send + 'static,
+{
+    /// Runs a backtest of the provided strategy over the historical [`MarketEvent`]s, returning
+    /// the aggregated [`BacktestResult`].
+    ///
+    /// Internally constructs an in-memory [`MetaPortfolio`] & a [`SimulatedExecution`] handler
+    /// using the provided [`FeeSchedule`], and runs a single [`Trader`] on the current thread
+    /// until the data is exhausted. The traded [`Market`] is taken from the first
+    /// [`MarketEvent`], and every other [`MarketEvent`] must be for the same [`Market`].
+    pub fn backtest(
+        strategy: Strategy,
+        config: BacktestConfig,
+        data: Vec<MarketEvent<Instrument, DataKind>>,
+        fee_schedule: Schedule,
+    ) -> Result<BacktestResult, EngineError> {
+        let started = Instant::now();
+
+        let statistic_config = StatisticConfig {
+            starting_equity: config.starting_cash,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        };
+
+        let Some(first) = data.first() else {
+            return Ok(BacktestResult {
+                stats: TradingSummary::init(statistic_config),
+                equity_curve: Vec::new(),
+                closed_positions: Vec::new(),
+                engine_metrics: EngineMetrics::default(),
+            });
+        };
+        let market = Market::new(first.exchange.clone(), first.instrument.clone());
+
+        // Single Trader & Portfolio statistics only cover the first MarketEvent's Market
+        if let Some(other) = data.iter().find(|event| {
+            event.exchange != market.exchange || event.instrument != market.instrument
+        }) {
+            return Err(EngineError::MixedBacktestMarkets {
+                expected: MarketId::from(&market),
+                found: MarketId::from(&Market::<Instrument>::new(
+                    other.exchange.clone(),
+                    other.instrument.clone(),
+                )),
+            });
+        }
+        let starting_equity = EquityPoint {
+            time: first.exchange_time,
+            total: config.starting_cash,
+        };
+
+        let engine_id = Uuid::new_v4();
+        let portfolio: Arc<Mutex<PaperTradePortfolio>> = Arc::new(Mutex::new(
+            MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(vec![market.clone()])
+                .starting_cash(config.starting_cash)
+                .repository(InMemoryRepository::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: config.default_order_value,
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(statistic_config)
+                .build_and_init()?,
+        ));
+
+        // Command transmitter is kept alive so the Trader only stops once the data is exhausted
+        let (_command_tx, command_rx) = mpsc::channel(1);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+
+        Trader::<_, TradingSummary, _, _, _, _>::builder()
+            .engine_id(engine_id)
+            .market(market)
+            .command_rx(command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(Arc::clone(&portfolio))
+            .data(historical::MarketFeed::new(data))
+            .strategy(strategy)
+            .execution(SimulatedExecution::with_fee_schedule(fee_schedule))
+            .build()?
+            .run();
+
+        // Aggregate the Events generated during the backtest
+        let mut engine_metrics = EngineMetrics::default();
+        let mut equity_curve = vec![starting_equity];
+        while let Ok(event) = event_rx.try_recv() {
+            engine_metrics.update(&event);
+            if let Event::Balance(balance) = event {
+                equity_curve.push(EquityPoint::from(balance));
+            }
+        }
+
+        let closed_positions = portfolio.lock().get_exited_positions(engine_id)?;
+
+        let mut stats = TradingSummary::init(statistic_config);
+        stats.generate_summary(&closed_positions);
+
+        engine_metrics.elapsed = started.elapsed();
+
+        Ok(BacktestResult {
+            stats,
+            equity_curve,
+            closed_positions,
+            engine_metrics,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::MarketMeta,
+        execution::MakerTakerFeeSchedule,
+        strategy::{Decision, Signal, SignalStrength},
+        test_util::market_event_trade,
+    };
+    use barter_data::subscription::trade::PublicTrade;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use chrono::{Duration, Utc};
+    use std::collections::HashMap;
+
+    /// Enters a long Position on the first MarketEvent & exits it on the final MarketEvent.
+    struct RoundTripStrategy {
+        exit_at: usize,
+        seen: usize,
+    }
+
+    impl SignalGenerator for RoundTripStrategy {
+        fn generate_signal(
+            &mut self,
+            market: &MarketEvent<Instrument, DataKind>,
+        ) -> Option<Signal> {
+            let DataKind::Trade(trade) = &market.kind else {
+                return None;
+            };
+
+            let decision = match self.seen {
+                0 => Some(Decision::Long),
+                seen if seen == self.exit_at => Some(Decision::CloseLong),
+                _ => None,
+            };
+            self.seen += 1;
+
+            decision.map(|decision| Signal {
+                time: market.exchange_time,
+                exchange: market.exchange.clone(),
+                instrument: market.instrument.clone(),
+                signals: HashMap::from([(decision, SignalStrength(1.0))]),
+                market_meta: MarketMeta {
+                    close: trade.price,
+                    time: market.exchange_time,
+                },
+            })
+        }
+    }
+
+    fn trades(prices: &[f64]) -> Vec<MarketEvent<Instrument, DataKind>> {
+        let start = Utc::now() - Duration::days(1);
+        prices
+            .iter()
+            .enumerate()
+            .map(|(index, &price)| {
+                let mut market = market_event_trade(Side::Buy);
+                market.exchange_time = start + Duration::minutes(index as i64);
+                market.kind = DataKind::Trade(PublicTrade {
+                    id: index.to_string(),
+                    price,
+                    amount: 1.0,
+                    side: Side::Buy,
+                });
+                market
+            })
+            .collect()
+    }
+
+    fn config() -> BacktestConfig {
+        BacktestConfig {
+            starting_cash: 10_000.0,
+            default_order_value: 1000.0,
+        }
+    }
+
+    #[test]
+    fn backtest_aggregates_closed_positions_equity_curve_and_metrics() {
+        let result = Engine::backtest(
+            RoundTripStrategy {
+                exit_at: 3,
+                seen: 0,
+            },
+            config(),
+            trades(&[100.0, 105.0, 110.0, 120.0]),
+            MakerTakerFeeSchedule {
+                maker_pct: 0.0,
+                taker_pct: 0.001,
+            },
+        )
+        .unwrap();
+
+        // Long 10 units at 100.0 & exit at 120.0, paying 0.1% taker fees on entry & exit
+        assert_eq!(result.closed_positions.len(), 1);
+        let position = &result.closed_positions[0];
+        assert_eq!(position.enter_fees_total, 1.0);
+        assert_eq!(position.exit_fees_total, 1.2);
+        assert!((position.realised_profit_loss - (200.0 - 2.2)).abs() < 1e-9);
+
+        assert_eq!(result.stats.pnl_returns.total.count, 1);
+
+        // Starting equity, followed by a Balance for the entry & exit fills
+        assert_eq!(result.equity_curve.len(), 3);
+        assert_eq!(result.equity_curve[0].total, 10_000.0);
+        assert!((result.equity_curve[2].total - (10_000.0 + 197.8)).abs() < 1e-9);
+
+        assert_eq!(result.engine_metrics.market_events, 4);
+        assert_eq!(result.engine_metrics.signals, 2);
+        assert_eq!(result.engine_metrics.orders, 2);
+        assert_eq!(result.engine_metrics.fills, 2);
+    }
+
+    #[test]
+    fn backtest_with_no_data_returns_empty_result() {
+        let result = Engine::backtest(
+            RoundTripStrategy {
+                exit_at: 1,
+                seen: 0,
+            },
+            config(),
+            Vec::new(),
+            MakerTakerFeeSchedule::default(),
+        )
+        .unwrap();
+
+        assert!(result.closed_positions.is_empty());
+        assert!(result.equity_curve.is_empty());
+        assert_eq!(result.engine_metrics, EngineMetrics::default());
+    }
+
+    #[test]
+    fn backtest_with_data_for_more_than_one_market_returns_error() {
+        let mut data = trades(&[100.0, 105.0, 110.0]);
+        data[2].instrument = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        let result = Engine::backtest(
+            RoundTripStrategy {
+                exit_at: 2,
+                seen: 0,
+            },
+            config(),
+            data,
+            MakerTakerFeeSchedule::default(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(EngineError::MixedBacktestMarkets { .. })
+        ));
+    }
+}
@@ -1,4 +1,5 @@
-use crate::portfolio::repository::error::RepositoryError;
+use crate::portfolio::{error::PortfolioError, repository::error::RepositoryError};
+use barter_integration::model::MarketId;
 use thiserror::Error;
 
 /// All errors generated in barter-engine.
@@ -9,4 +10,10 @@ pub enum EngineError {
 
     #[error("Failed to interact with repository")]
     RepositoryInteractionError(#[from] RepositoryError),
+
+    #[error("Failed to interact with portfolio: {0}")]
+    PortfolioInteractionError(#[from] PortfolioError),
+
+    #[error("Backtest data contains more than one market: expected {expected}, found {found}")]
+    MixedBacktestMarkets { expected: MarketId, found: MarketId },
 }
@@ -2,28 +2,49 @@ use crate::{
     data::MarketGenerator,
     engine::{error::EngineError, trader::Trader},
     event::{Event, MessageTransmitter},
-    execution::ExecutionClient,
+    execution::{simulated::SimulatedExecution, ExecutionClient, FeeSchedule},
     portfolio::{
+        allocator::DefaultAllocator,
+        error::PortfolioError,
+        portfolio::MetaPortfolio,
         position::Position,
-        repository::{PositionHandler, StatisticHandler},
+        repository::{in_memory::InMemoryRepository, PositionHandler, StatisticHandler},
+        risk::DefaultRisk,
         FillUpdater, MarketUpdater, OrderGenerator,
     },
-    statistic::summary::{PositionSummariser, TableBuilder},
+    statistic::summary::{
+        trading::{Config as StatisticConfig, TradingSummary},
+        Initialiser, PositionSummariser, TableBuilder,
+    },
     strategy::SignalGenerator,
 };
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::Instrument, Market, MarketId};
 use parking_lot::Mutex;
 use prettytable::Table;
-use serde::Serialize;
-use std::{collections::HashMap, fmt::Debug, sync::Arc, thread};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
 use tokio::sync::{mpsc, oneshot};
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, info_span, trace, warn, Instrument as _};
 use uuid::Uuid;
 
+/// All-in-one backtest runner for a single strategy over historical data. See
+/// [`Engine::backtest`].
+pub mod backtest;
+
 /// Barter Engine module specific errors.
 pub mod error;
 
+/// Runtime metrics emitted by a [`Trader`] via the `metrics` crate facade.
+#[cfg(feature = "metrics")]
+pub mod telemetry;
+
 /// Contains the trading event loop for a Trader capable of trading a single market pair. A Trader
 /// has it's own Data handler, Strategy & Execution handler, as well as shared access to a global
 /// Portfolio instance.
@@ -47,6 +68,69 @@ pub enum Command {
     ExitPosition(Market),
 }
 
+impl Command {
+    /// Returns the name of the [`Command`] variant. Useful as a structured field when logging.
+    pub fn command_type(&self) -> &'static str {
+        match self {
+            Command::FetchOpenPositions(_) => "fetch_open_positions",
+            Command::Terminate(_) => "terminate",
+            Command::ExitAllPositions => "exit_all_positions",
+            Command::ExitPosition(_) => "exit_position",
+        }
+    }
+}
+
+/// Default maximum time an [`Engine`] waits for every open [`Position`] to be exited when
+/// terminating, before it's [`Trader`]s are stopped regardless.
+pub const DEFAULT_LIQUIDATION_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Interval at which an [`Engine`] polls the Portfolio for remaining open [`Position`]s whilst
+/// liquidating.
+const LIQUIDATION_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Lifecycle state of an [`Engine`]. See [`Engine::status`] & [`EngineMonitor`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum EngineStatus {
+    /// [`Engine`] has been constructed, but [`Engine::run`] has not yet started it's [`Trader`]s.
+    #[default]
+    Initialising,
+    /// [`Engine`] is running it's [`Trader`]s & actioning remote [`Command`]s.
+    Consuming,
+    /// [`Engine`] has stopped for the provided reason.
+    Terminated { reason: String },
+}
+
+impl EngineStatus {
+    /// Determines if the [`Engine`] is running (ie/ [`EngineStatus::Consuming`]).
+    pub fn is_alive(&self) -> bool {
+        matches!(self, EngineStatus::Consuming)
+    }
+}
+
+/// Cloneable handle for observing the [`EngineStatus`] of an [`Engine`], including from another
+/// thread whilst [`Engine::run`] has ownership of the [`Engine`].
+#[derive(Debug, Clone, Default)]
+pub struct EngineMonitor {
+    status: Arc<Mutex<EngineStatus>>,
+}
+
+impl EngineMonitor {
+    /// Returns the current [`EngineStatus`].
+    pub fn status(&self) -> EngineStatus {
+        self.status.lock().clone()
+    }
+
+    /// Determines if the [`Engine`] is running. See [`EngineStatus::is_alive`].
+    pub fn is_alive(&self) -> bool {
+        self.status.lock().is_alive()
+    }
+
+    /// Updates the current [`EngineStatus`].
+    fn set(&self, status: EngineStatus) {
+        *self.status.lock() = status;
+    }
+}
+
 /// Lego components for constructing an [`Engine`] via the new() constructor method.
 #[derive(Debug)]
 pub struct EngineLego<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -114,6 +198,10 @@ where
     /// Uses trading session's exited [`Position`]s to calculate an average statistical summary
     /// across all [`Market`]s traded.
     statistics_summary: Statistic,
+    /// Shared [`EngineStatus`] that can be observed without ownership of the [`Engine`].
+    monitor: EngineMonitor,
+    /// Maximum time to wait for every open [`Position`] to be exited when terminating.
+    liquidation_timeout: Duration,
 }
 
 impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -145,9 +233,27 @@ where
             traders: lego.traders,
             trader_command_txs: lego.trader_command_txs,
             statistics_summary: lego.statistics_summary,
+            monitor: EngineMonitor::default(),
+            liquidation_timeout: DEFAULT_LIQUIDATION_TIMEOUT,
         }
     }
 
+    /// Returns the current [`EngineStatus`] of this [`Engine`].
+    pub fn status(&self) -> EngineStatus {
+        self.monitor.status()
+    }
+
+    /// Determines if this [`Engine`] is running. See [`EngineStatus::is_alive`].
+    pub fn is_alive(&self) -> bool {
+        self.monitor.is_alive()
+    }
+
+    /// Returns an [`EngineMonitor`] that can observe the [`EngineStatus`] of this [`Engine`] after
+    /// it has been moved into [`Engine::run`].
+    pub fn monitor(&self) -> EngineMonitor {
+        self.monitor.clone()
+    }
+
     /// Builder to construct [`Engine`] instances.
     pub fn builder() -> EngineBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution> {
         EngineBuilder::new()
@@ -159,40 +265,84 @@ where
     /// (eg/ due to a finished [`MarketGenerator`]), the [`Engine`] terminates & prints a summary
     /// for the trading session.
     pub async fn run(mut self) {
-        // Run Traders on threads & send notification when they have stopped organically
-        let mut notify_traders_stopped = self.run_traders().await;
+        let span = info_span!("engine", engine_id = %self.engine_id);
+        async move {
+            // Run Traders on threads & send notification when they have stopped organically
+            let notify_traders_stopped = self.run_traders().await;
+            self.consume(notify_traders_stopped).await
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Run the trading [`Engine`] in an async runtime. Equivalent to [`Engine::run`], except each
+    /// [`Trader`] runs on the tokio blocking thread pool via [`tokio::task::spawn_blocking`], so
+    /// the CPU-bound [`Trader`] event-loop never blocks the async runtime.
+    ///
+    /// Pair with an [`mpsc::Receiver`] market feed (see [`MarketGenerator`]) to source
+    /// [`MarketEvent`]s from async tasks (eg/ websocket streams).
+    #[cfg(feature = "tokio")]
+    pub async fn run_async(mut self) {
+        let span = info_span!("engine", engine_id = %self.engine_id);
+        async move {
+            // Run Traders on the blocking pool & send notification when they have stopped
+            let notify_traders_stopped = self.run_traders_blocking();
+            self.consume(notify_traders_stopped).await
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Engine Consume state. Actions remote [`Command`]s until a [`Command::Terminate`] is
+    /// received, the `command_rx` is dropped, or every [`Trader`] stops organically.
+    async fn consume(mut self, mut notify_traders_stopped: mpsc::Receiver<bool>) {
+        self.monitor.set(EngineStatus::Consuming);
 
-        loop {
+        debug!(
+            transition = "Initialise -> Consume",
+            traders = self.trader_command_txs.len(),
+            "Engine started Traders"
+        );
+
+        let reason = loop {
             // Action received commands from remote, or wait for all Traders to stop organically
             tokio::select! {
                 _ = notify_traders_stopped.recv() => {
-                    break;
+                    break "all Traders stopped".to_owned();
                 },
 
                 command = self.command_rx.recv() => {
-                    if let Some(command) = command {
-                        match command {
-                            Command::FetchOpenPositions(positions_tx) => {
-                                self.fetch_open_positions(positions_tx).await;
-                            },
-                            Command::Terminate(message) => {
-                                self.terminate_traders(message).await;
-                                break;
-                            },
-                            Command::ExitPosition(market) => {
-                                self.exit_position(market).await;
-                            },
-                            Command::ExitAllPositions => {
-                                self.exit_all_positions().await;
-                            },
-                        }
-                    } else {
+                    let Some(command) = command else {
                         // Terminate traders due to dropped receiver
-                        break;
+                        break "remote Command transmitter dropped".to_owned();
+                    };
+
+                    trace!(command_type = command.command_type(), "Engine consuming Command");
+
+                    match command {
+                        Command::FetchOpenPositions(positions_tx) => {
+                            self.fetch_open_positions(positions_tx).await;
+                        },
+                        Command::Terminate(message) => {
+                            self.terminate_traders(message.clone()).await;
+                            break message;
+                        },
+                        Command::ExitPosition(market) => {
+                            self.exit_position(market).await;
+                        },
+                        Command::ExitAllPositions => {
+                            self.exit_all_positions().await;
+                        },
                     }
                 }
             }
-        }
+        };
+
+        debug!(
+            transition = "Consume -> Terminate",
+            reason, "Engine stopped consuming Commands"
+        );
+        self.monitor.set(EngineStatus::Terminated { reason });
 
         // Print Trading Session Summary
         self.generate_session_summary().printstd();
@@ -215,7 +365,8 @@ where
         let (notify_tx, notify_rx) = mpsc::channel(1);
 
         // Create Task that notifies Engine when the Traders have stopped organically
-        tokio::spawn(async move {
+        // '--> Joining blocks, so run on the blocking pool to avoid stalling the async runtime
+        tokio::task::spawn_blocking(move || {
             for handle in thread_handles {
                 if let Err(err) = handle.join() {
                     error!(
@@ -225,6 +376,37 @@ where
                 }
             }
 
+            let _ = notify_tx.blocking_send(true);
+        });
+
+        notify_rx
+    }
+
+    /// Runs each [`Trader`] on the tokio blocking thread pool. Sends a message on the returned
+    /// `mpsc::Receiver<bool>` if all the [`Trader`]s have stopped organically.
+    #[cfg(feature = "tokio")]
+    fn run_traders_blocking(&mut self) -> mpsc::Receiver<bool> {
+        // Extract Traders out of the Engine so we can move them into blocking tasks
+        let traders = std::mem::take(&mut self.traders);
+
+        let task_handles = traders
+            .into_iter()
+            .map(|trader| tokio::task::spawn_blocking(move || trader.run()))
+            .collect::<Vec<_>>();
+
+        // Create channel to notify the Engine when the Traders have stopped organically
+        let (notify_tx, notify_rx) = mpsc::channel(1);
+
+        tokio::spawn(async move {
+            for handle in task_handles {
+                if let Err(err) = handle.await {
+                    error!(
+                        error = &*format!("{:?}", err),
+                        "Trader blocking task has panicked during execution",
+                    )
+                }
+            }
+
             let _ = notify_tx.send(true).await;
         });
 
@@ -254,8 +436,14 @@ where
     /// Terminate every running [`Trader`] associated with this [`Engine`].
     async fn terminate_traders(&self, message: String) {
         // Firstly, exit all Positions
-        self.exit_all_positions().await;
-        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        if let Err(error) = self.liquidate_open_positions().await {
+            error!(
+                %error,
+                error_code = error.error_code(),
+                action = "terminating Traders regardless",
+                "failed to liquidate every open Position"
+            );
+        }
 
         // Distribute Command::Terminate to all the Engine's Traders
         for (market, command_tx) in self.trader_command_txs.iter() {
@@ -273,6 +461,50 @@ where
         }
     }
 
+    /// Exits every open [`Position`] associated with this [`Engine`], waiting up to the
+    /// `liquidation_timeout` for the [`Trader`]s to exit them.
+    ///
+    /// Returns [`PortfolioError::LiquidationTimeout`] if no [`Position`]s were exited before the
+    /// timeout, or [`PortfolioError::PartialLiquidation`] if only some of them were.
+    async fn liquidate_open_positions(&self) -> Result<(), PortfolioError> {
+        let started = Instant::now();
+
+        let open = self.open_position_count()?;
+        if open == 0 {
+            return Ok(());
+        }
+
+        self.exit_all_positions().await;
+
+        let remaining = loop {
+            let remaining = self.open_position_count()?;
+            if remaining == 0 {
+                return Ok(());
+            }
+            if started.elapsed() >= self.liquidation_timeout {
+                break remaining;
+            }
+            tokio::time::sleep(LIQUIDATION_POLL_INTERVAL).await;
+        };
+
+        match open.saturating_sub(remaining) {
+            0 => Err(PortfolioError::LiquidationTimeout {
+                position_count: remaining,
+                elapsed: started.elapsed(),
+            }),
+            closed => Err(PortfolioError::PartialLiquidation { closed, remaining }),
+        }
+    }
+
+    /// Returns the number of open [`Position`]s across every [`Market`] traded by this [`Engine`].
+    fn open_position_count(&self) -> Result<usize, PortfolioError> {
+        Ok(self
+            .portfolio
+            .lock()
+            .get_open_positions(self.engine_id, self.trader_command_txs.keys())?
+            .len())
+    }
+
     /// Exit every open [`Position`] associated with this [`Engine`].
     async fn exit_all_positions(&self) {
         for (market, command_tx) in self.trader_command_txs.iter() {
@@ -356,6 +588,109 @@ where
     }
 }
 
+/// Configuration for constructing a paper trading [`Engine`] via [`Engine::paper_trade`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PaperTradeConfig {
+    /// Cash balance the paper trading [`MetaPortfolio`] starts with.
+    pub starting_cash: f64,
+    /// Default [`OrderEvent`](crate::portfolio::OrderEvent) value used by the
+    /// [`DefaultAllocator`].
+    pub default_order_value: f64,
+    /// Slippage percentage in decimal form (eg/ 0.001 for 0.1%) that moves every simulated fill
+    /// price against the order.
+    pub slippage_pct: f64,
+}
+
+/// [`MetaPortfolio`] used by a paper trading [`Engine`] constructed via [`Engine::paper_trade`].
+pub type PaperTradePortfolio = MetaPortfolio<
+    InMemoryRepository<TradingSummary>,
+    DefaultAllocator,
+    DefaultRisk,
+    TradingSummary,
+>;
+
+impl<EventTx, Data, Strategy, Schedule>
+    Engine<
+        EventTx,
+        TradingSummary,
+        PaperTradePortfolio,
+        Data,
+        Strategy,
+        SimulatedExecution<Schedule>,
+    >
+where
+    EventTx: MessageTransmitter<Event> + Send + 'static,
+    Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send + 'static,
+    Strategy: SignalGenerator + Send + 'static,
+    Schedule: FeeSchedule + Send + 'static,
+{
+    /// Constructs a paper trading [`Engine`] with a single [`Trader`] for the provided [`Market`].
+    /// Wires up a [`SimulatedExecution`] handler using the provided [`FeeSchedule`] & configured
+    /// slippage, and an in-memory [`MetaPortfolio`], so a strategy can be validated against live
+    /// (or historical) data without risking real capital.
+    ///
+    /// Returns the [`Engine`] alongside the `mpsc::Sender<Command>` used to control it remotely
+    /// (eg/ [`Command::Terminate`]).
+    pub fn paper_trade(
+        market: Market,
+        data: Data,
+        strategy: Strategy,
+        config: PaperTradeConfig,
+        fee_schedule: Schedule,
+        event_tx: EventTx,
+    ) -> Result<(Self, mpsc::Sender<Command>), EngineError> {
+        let engine_id = Uuid::new_v4();
+        let (command_tx, command_rx) = mpsc::channel(20);
+
+        let statistic_config = StatisticConfig {
+            starting_equity: config.starting_cash,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        };
+
+        let portfolio = Arc::new(Mutex::new(
+            MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(vec![market.clone()])
+                .starting_cash(config.starting_cash)
+                .repository(InMemoryRepository::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: config.default_order_value,
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(statistic_config)
+                .build_and_init()?,
+        ));
+
+        let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+        let trader = Trader::builder()
+            .engine_id(engine_id)
+            .market(market.clone())
+            .command_rx(trader_command_rx)
+            .event_tx(event_tx)
+            .portfolio(Arc::clone(&portfolio))
+            .data(data)
+            .strategy(strategy)
+            .execution(
+                SimulatedExecution::with_fee_schedule(fee_schedule)
+                    .slippage_pct(config.slippage_pct),
+            )
+            .build()?;
+
+        let engine = Engine::builder()
+            .engine_id(engine_id)
+            .command_rx(command_rx)
+            .portfolio(portfolio)
+            .traders(vec![trader])
+            .trader_command_txs(HashMap::from([(market, trader_command_tx)]))
+            .statistics_summary(TradingSummary::init(statistic_config))
+            .build()?;
+
+        Ok((engine, command_tx))
+    }
+}
+
 /// Builder to construct [`Engine`] instances.
 #[derive(Debug, Default)]
 pub struct EngineBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -373,6 +708,7 @@ where
     traders: Option<Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>>,
     trader_command_txs: Option<HashMap<Market, mpsc::Sender<Command>>>,
     statistics_summary: Option<Statistic>,
+    liquidation_timeout: Option<Duration>,
 }
 
 impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -398,6 +734,7 @@ where
             traders: None,
             trader_command_txs: None,
             statistics_summary: None,
+            liquidation_timeout: None,
         }
     }
 
@@ -446,6 +783,15 @@ where
         }
     }
 
+    /// Maximum time to wait for every open [`Position`] to be exited when terminating. Defaults
+    /// to [`DEFAULT_LIQUIDATION_TIMEOUT`].
+    pub fn liquidation_timeout(self, value: Duration) -> Self {
+        Self {
+            liquidation_timeout: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(
         self,
     ) -> Result<Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
@@ -468,6 +814,302 @@ where
             statistics_summary: self
                 .statistics_summary
                 .ok_or(EngineError::BuilderIncomplete("statistics_summary"))?,
+            monitor: EngineMonitor::default(),
+            liquidation_timeout: self
+                .liquidation_timeout
+                .unwrap_or(DEFAULT_LIQUIDATION_TIMEOUT),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{historical, Feed},
+        event::EventTx,
+        execution::{error::ExecutionError, Fees, FillEvent, MakerTakerFeeSchedule},
+        portfolio::{position::determine_position_id, OrderEvent},
+        strategy::Signal,
+        test_util::{market_event_trade, position},
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+
+    /// Mock strategy that never generates a [`Signal`].
+    #[derive(Debug)]
+    struct NoSignalStrategy;
+
+    impl SignalGenerator for NoSignalStrategy {
+        fn generate_signal(&mut self, _: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+            None
+        }
+    }
+
+    #[test]
+    fn paper_trade_wires_single_trader_with_in_memory_portfolio() {
+        let market = Market::new("binance_spot", ("btc", "usdt", InstrumentKind::Spot));
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+
+        let (engine, _command_tx) = Engine::paper_trade(
+            market.clone(),
+            historical::MarketFeed::new(vec![market_event_trade(Side::Buy)]),
+            NoSignalStrategy,
+            PaperTradeConfig {
+                starting_cash: 5_000.0,
+                default_order_value: 50.0,
+                slippage_pct: 0.0005,
+            },
+            MakerTakerFeeSchedule {
+                maker_pct: 0.0,
+                taker_pct: 0.001,
+            },
+            EventTx::new(event_tx),
+        )
+        .unwrap();
+
+        assert_eq!(engine.traders.len(), 1);
+        assert!(engine.trader_command_txs.contains_key(&market));
+
+        let snapshot = engine.portfolio.lock().snapshot([market].iter()).unwrap();
+        assert_eq!(snapshot.total_equity, 5_000.0);
+        assert_eq!(snapshot.available_cash, 5_000.0);
+        assert!(snapshot.open_positions.is_empty());
+    }
+
+    /// Constructs a paper trading [`Engine`] using the provided feed, returning it alongside
+    /// the `mpsc::Sender<Command>` used to control it & an [`EngineMonitor`] observing it.
+    fn monitored_paper_trade_engine<Data>(
+        data: Data,
+    ) -> (
+        Engine<
+            EventTx,
+            TradingSummary,
+            PaperTradePortfolio,
+            Data,
+            NoSignalStrategy,
+            SimulatedExecution,
+        >,
+        mpsc::Sender<Command>,
+        EngineMonitor,
+    )
+    where
+        Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send + 'static,
+    {
+        let market = Market::new("binance_spot", ("btc", "usdt", InstrumentKind::Spot));
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+
+        let (engine, command_tx) = Engine::paper_trade(
+            market,
+            data,
+            NoSignalStrategy,
+            PaperTradeConfig {
+                starting_cash: 5_000.0,
+                default_order_value: 50.0,
+                slippage_pct: 0.0,
+            },
+            Fees::default(),
+            EventTx::new(event_tx),
+        )
+        .unwrap();
+
+        let monitor = engine.monitor();
+        (engine, command_tx, monitor)
+    }
+
+    #[tokio::test]
+    async fn engine_monitor_observes_status_after_engine_is_moved() {
+        let (engine, command_tx, monitor) = monitored_paper_trade_engine(IdleFeed);
+        assert_eq!(monitor.status(), EngineStatus::Initialising);
+        assert!(!monitor.is_alive());
+
+        // Engine is moved into the spawned task, leaving only the EngineMonitor
+        let run = tokio::spawn(engine.run());
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !monitor.is_alive() {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
         })
+        .await
+        .expect("Engine never started consuming Commands");
+        assert_eq!(monitor.status(), EngineStatus::Consuming);
+
+        command_tx
+            .send(Command::Terminate("test finished".to_owned()))
+            .await
+            .unwrap();
+        tokio::time::timeout(Duration::from_secs(5), run)
+            .await
+            .expect("Engine did not terminate")
+            .unwrap();
+
+        assert_eq!(
+            monitor.status(),
+            EngineStatus::Terminated {
+                reason: "test finished".to_owned()
+            }
+        );
+        assert!(!monitor.is_alive());
+    }
+
+    #[tokio::test]
+    async fn engine_monitor_observes_termination_once_every_trader_stops() {
+        let (engine, _command_tx, monitor) = monitored_paper_trade_engine(
+            historical::MarketFeed::new(vec![market_event_trade(Side::Buy)]),
+        );
+
+        tokio::time::timeout(Duration::from_secs(5), engine.run())
+            .await
+            .expect("Engine did not terminate once it's Trader stopped");
+
+        assert_eq!(
+            monitor.status(),
+            EngineStatus::Terminated {
+                reason: "all Traders stopped".to_owned()
+            }
+        );
+        assert!(!monitor.is_alive());
+    }
+
+    /// Mock feed that never yields a [`MarketEvent`], keeping a [`Trader`] alive until it is
+    /// terminated.
+    struct IdleFeed;
+
+    impl MarketGenerator<MarketEvent<Instrument, DataKind>> for IdleFeed {
+        fn next(&mut self) -> Feed<MarketEvent<Instrument, DataKind>> {
+            thread::sleep(Duration::from_millis(1));
+            Feed::Unhealthy
+        }
+    }
+
+    /// Mock execution handler that either fills every [`OrderEvent`], or always times out.
+    struct LiquidationExecution {
+        times_out: bool,
+    }
+
+    impl ExecutionClient for LiquidationExecution {
+        fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
+            if self.times_out {
+                return Err(ExecutionError::RequestTimeout { elapsed_ms: 5_000 });
+            }
+
+            FillEvent::builder()
+                .time(order.time)
+                .exchange(order.exchange.clone())
+                .instrument(order.instrument.clone())
+                .market_meta(order.market_meta)
+                .decision(order.decision)
+                .quantity(order.quantity)
+                .fill_value_gross(order.quantity.abs() * order.market_meta.close)
+                .fees(Fees::default())
+                .build()
+        }
+    }
+
+    /// Runs an [`Engine`] with an open [`Position`] for each provided [`LiquidationExecution`]
+    /// `times_out` flag, returning the result of liquidating them.
+    async fn liquidate_with(times_out: &[bool]) -> Result<(), PortfolioError> {
+        let engine_id = Uuid::new_v4();
+        let markets = ["btc", "eth"]
+            .map(|base| Market::new("binance", (base, "usdt", InstrumentKind::Spot)))
+            .into_iter()
+            .take(times_out.len())
+            .collect::<Vec<_>>();
+
+        let portfolio: Arc<Mutex<PaperTradePortfolio>> = Arc::new(Mutex::new(
+            MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(markets.clone())
+                .starting_cash(1_000.0)
+                .repository(InMemoryRepository::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: 100.0,
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(StatisticConfig {
+                    starting_equity: 1_000.0,
+                    trading_days_per_year: 365,
+                    risk_free_return: 0.0,
+                })
+                .build_and_init()
+                .unwrap(),
+        ));
+
+        let mut traders = Vec::new();
+        let mut trader_command_txs = HashMap::new();
+        for (market, &times_out) in markets.into_iter().zip(times_out) {
+            let mut open = position();
+            open.position_id =
+                determine_position_id(engine_id, &market.exchange, &market.instrument);
+            open.exchange = market.exchange.clone();
+            open.instrument = market.instrument.clone();
+            open.meta.enter_time = chrono::Utc::now() - chrono::Duration::minutes(1);
+            portfolio.lock().set_open_position(open).unwrap();
+
+            let (command_tx, command_rx) = mpsc::channel(10);
+            let (event_tx, _event_rx) = mpsc::unbounded_channel();
+            traders.push(
+                Trader::builder()
+                    .engine_id(engine_id)
+                    .market(market.clone())
+                    .command_rx(command_rx)
+                    .event_tx(EventTx::new(event_tx))
+                    .portfolio(Arc::clone(&portfolio))
+                    .data(IdleFeed)
+                    .strategy(NoSignalStrategy)
+                    .execution(LiquidationExecution { times_out })
+                    .build()
+                    .unwrap(),
+            );
+            trader_command_txs.insert(market, command_tx);
+        }
+
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let mut engine = Engine::builder()
+            .engine_id(engine_id)
+            .command_rx(command_rx)
+            .portfolio(portfolio)
+            .traders(traders)
+            .trader_command_txs(trader_command_txs)
+            .statistics_summary(TradingSummary::init(StatisticConfig {
+                starting_equity: 1_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            }))
+            .liquidation_timeout(Duration::from_millis(200))
+            .build()
+            .unwrap();
+
+        let _notify_traders_stopped = engine.run_traders().await;
+        let result = engine.liquidate_open_positions().await;
+
+        for command_tx in engine.trader_command_txs.values() {
+            let _ = command_tx
+                .send(Command::Terminate("test finished".to_owned()))
+                .await;
+        }
+
+        result
+    }
+
+    #[tokio::test]
+    async fn terminate_liquidation_reports_positions_that_timed_out() {
+        assert!(liquidate_with(&[false, false]).await.is_ok());
+
+        assert!(matches!(
+            liquidate_with(&[true, true]).await,
+            Err(PortfolioError::LiquidationTimeout {
+                position_count: 2,
+                elapsed,
+            }) if elapsed >= Duration::from_millis(200)
+        ));
+
+        assert!(matches!(
+            liquidate_with(&[false, true]).await,
+            Err(PortfolioError::PartialLiquidation {
+                closed: 1,
+                remaining: 1
+            })
+        ));
     }
 }
@@ -0,0 +1,60 @@
+use crate::event::Event;
+use barter_integration::model::Market;
+use metrics::{counter, gauge, histogram};
+use std::time::Duration;
+
+/// Counter incremented for every [`MarketEvent`](barter_data::event::MarketEvent) consumed.
+pub const MARKET_EVENTS: &str = "barter.engine.events.market";
+
+/// Counter incremented for every [`FillEvent`](crate::execution::FillEvent) consumed.
+pub const FILL_EVENTS: &str = "barter.engine.events.fill";
+
+/// Gauge tracking the number of open [`Position`](crate::portfolio::position::Position)s.
+pub const OPEN_POSITIONS: &str = "barter.portfolio.open_positions";
+
+/// Gauge tracking the unrealised P&L of the open
+/// [`Position`](crate::portfolio::position::Position) in each [`Market`].
+pub const UNREALISED_PNL: &str = "barter.portfolio.unrealized_pnl";
+
+/// Histogram recording the time taken by the execution handler to generate a
+/// [`FillEvent`](crate::execution::FillEvent), in microseconds.
+pub const FILL_LATENCY_US: &str = "barter.execution.fill_latency_us";
+
+/// Records the consumption of a [`MarketEvent`](barter_data::event::MarketEvent).
+pub(super) fn record_market_event() {
+    counter!(MARKET_EVENTS).increment(1);
+}
+
+/// Records the latest unrealised P&L of the open [`Position`](crate::portfolio::position::Position)
+/// in the provided [`Market`].
+pub(super) fn record_unrealised_profit_loss(market: &Market, unrealised_profit_loss: f64) {
+    gauge!(
+        UNREALISED_PNL,
+        "exchange" => market.exchange.to_string(),
+        "instrument" => market.instrument.to_string()
+    )
+    .set(unrealised_profit_loss);
+}
+
+/// Records the time taken by the execution handler to generate a
+/// [`FillEvent`](crate::execution::FillEvent).
+pub(super) fn record_fill_latency(latency: Duration) {
+    histogram!(FILL_LATENCY_US).record(latency.as_micros() as f64);
+}
+
+/// Records the consumption of a [`FillEvent`](crate::execution::FillEvent), and any
+/// [`Position`](crate::portfolio::position::Position) changes in the [`Event`]s it generated.
+pub(super) fn record_fill(market: &Market, fill_side_effect_events: &[Event]) {
+    counter!(FILL_EVENTS).increment(1);
+
+    for event in fill_side_effect_events {
+        match event {
+            Event::PositionNew(_) => gauge!(OPEN_POSITIONS).increment(1.0),
+            Event::PositionExit(_) => {
+                gauge!(OPEN_POSITIONS).decrement(1.0);
+                record_unrealised_profit_loss(market, 0.0);
+            }
+            _ => {}
+        }
+    }
+}
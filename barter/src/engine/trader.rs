@@ -2,17 +2,29 @@ use super::{error::EngineError, Command};
 use crate::{
     data::{Feed, MarketGenerator},
     event::{Event, MessageTransmitter},
-    execution::ExecutionClient,
-    portfolio::{FillUpdater, MarketUpdater, OrderGenerator},
+    execution::{error::ExecutionError, ExecutionClient, FillEvent, FillRejected},
+    portfolio::{
+        error::PortfolioError,
+        position::PositionEvent,
+        risk::{CircuitBreaker, DailyLossLimit},
+        FillUpdater, MarketUpdater, OrderEvent, OrderGenerator,
+    },
     strategy::{SignalForceExit, SignalGenerator},
 };
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::Instrument, Market};
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
-use serde::Serialize;
-use std::{collections::VecDeque, fmt::Debug, marker::PhantomData, sync::Arc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tokio::sync::mpsc;
-use tracing::{debug, info, warn};
+use tracing::{debug, info, info_span, trace, warn};
 use uuid::Uuid;
 
 /// Lego components for constructing a [`Trader`] via the new() constructor method.
@@ -44,6 +56,17 @@ where
     pub strategy: Strategy,
     /// Execution handler that implements [`ExecutionClient`].
     pub execution: Execution,
+    /// Optional [`CircuitBreaker`] that halts new entries after consecutive losing trades.
+    pub circuit_breaker: Option<CircuitBreaker>,
+    /// Optional [`DailyLossLimit`] that stops the [`Trader`] once breached.
+    pub daily_loss_limit: Option<DailyLossLimit>,
+    /// [`ReconnectPolicy`] applied when the [`MarketGenerator`] yields [`Feed::Disconnected`].
+    pub reconnect_policy: ReconnectPolicy,
+    /// Maximum number of [`Event`]s queued in the event_q before the [`BackpressurePolicy`] is
+    /// applied.
+    pub max_queue_depth: usize,
+    /// [`BackpressurePolicy`] applied when the event_q exceeds the `max_queue_depth`.
+    pub backpressure_policy: BackpressurePolicy,
     _statistic_marker: PhantomData<Statistic>,
 }
 
@@ -72,8 +95,9 @@ where
     /// [`Event`] transmitter for sending every [`Event`] the [`Trader`] encounters to an external
     /// sink.
     event_tx: EventTx,
-    /// Queue for storing [`Event`]s used by the trading loop in the run() method.
-    event_q: VecDeque<Event>,
+    /// Queue for storing [`Event`]s used by the trading loop in the run() method. [`FillEvent`]s
+    /// are prioritised so the Portfolio reflects executed trades before the next [`MarketEvent`].
+    event_q: PriorityEventQueue,
     /// Shared-access to a global Portfolio instance that implements [`MarketUpdater`],
     /// [`OrderGenerator`] & [`FillUpdater`].
     portfolio: Arc<Mutex<Portfolio>>,
@@ -83,9 +107,49 @@ where
     strategy: Strategy,
     /// Execution handler that implements [`ExecutionClient`].
     execution: Execution,
+    /// Optional [`CircuitBreaker`] that halts new entries after consecutive losing trades.
+    circuit_breaker: Option<CircuitBreaker>,
+    /// Optional [`DailyLossLimit`] that stops the [`Trader`] once breached.
+    daily_loss_limit: Option<DailyLossLimit>,
+    /// Number of [`MarketEvent`]s consumed, used to determine if the Strategy has completed it's
+    /// [`SignalGenerator::warmup_bars`].
+    market_events_consumed: usize,
+    /// [`MarketEvent`] consumed from the [`Feed`] that did not satisfy a run limit (see
+    /// [`Trader::run_for_duration`]). Handled in place of the next [`Feed`] event.
+    pending_market: Option<MarketEvent<Instrument, DataKind>>,
+    /// [`ReconnectPolicy`] applied when the [`MarketGenerator`] yields [`Feed::Disconnected`].
+    reconnect_policy: ReconnectPolicy,
+    /// Number of consecutive [`Feed::Disconnected`] polls, used to determine the next
+    /// [`ReconnectPolicy`] delay.
+    reconnect_attempt: u32,
+    /// Time at which a disconnected [`MarketGenerator`] should next be polled.
+    reconnect_at: Option<Instant>,
+    /// [`OrderEvent`] rejected by an exchange rate limit, & the time it should be retried.
+    rate_limited_order: Option<(Instant, OrderEvent)>,
+    /// Optional [`PositionEventHook`] called with every [`PositionEvent`] the [`Trader`]
+    /// encounters.
+    position_event_hook: Option<PositionEventHook>,
+    /// Maximum number of [`Event`]s queued in the event_q before the [`BackpressurePolicy`] is
+    /// applied.
+    max_queue_depth: usize,
+    /// [`BackpressurePolicy`] applied when the event_q exceeds the `max_queue_depth`.
+    backpressure_policy: BackpressurePolicy,
     _statistic_marker: PhantomData<Statistic>,
 }
 
+/// Callback invoked with every [`PositionEvent`] a [`Trader`] encounters, enabling custom handling
+/// of [`Position`](crate::portfolio::position::Position) lifecycle events outside of the Strategy
+/// (eg/ updating a live dashboard). See [`Trader::with_position_event_hook`].
+///
+/// Must be `Sync` so the [`Engine::run`](crate::engine::Engine::run) future remains `Send`.
+pub struct PositionEventHook(Box<dyn Fn(&PositionEvent) + Send + Sync>);
+
+impl Debug for PositionEventHook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PositionEventHook")
+    }
+}
+
 impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
     Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
 where
@@ -109,11 +173,22 @@ where
             market: lego.market,
             command_rx: lego.command_rx,
             event_tx: lego.event_tx,
-            event_q: VecDeque::with_capacity(4),
+            event_q: PriorityEventQueue::with_capacity(4),
             portfolio: lego.portfolio,
             data: lego.data,
             strategy: lego.strategy,
             execution: lego.execution,
+            circuit_breaker: lego.circuit_breaker,
+            daily_loss_limit: lego.daily_loss_limit,
+            market_events_consumed: 0,
+            pending_market: None,
+            reconnect_policy: lego.reconnect_policy,
+            reconnect_attempt: 0,
+            reconnect_at: None,
+            rate_limited_order: None,
+            position_event_hook: None,
+            max_queue_depth: lego.max_queue_depth,
+            backpressure_policy: lego.backpressure_policy,
             _statistic_marker: PhantomData,
         }
     }
@@ -127,22 +202,133 @@ where
     /// receives a [`Command::Terminate`] via the mpsc::Receiver command_rx, or the
     /// [`MarketGenerator`] yields [`Feed::Finished`].
     pub fn run(mut self) {
+        let span = info_span!(
+            "trader",
+            engine_id = %self.engine_id,
+            exchange = %self.market.exchange,
+            symbol = %self.market.instrument,
+        );
+        let _span_guard = span.enter();
+
+        debug!(
+            transition = "Initialise -> Consume",
+            "Trader starting trading loop"
+        );
+
         // Run trading loop for this Trader instance
-        'trading: loop {
-            // Check for new remote Commands before continuing to generate another MarketEvent
-            while let Some(command) = self.receive_remote_command() {
-                match command {
-                    Command::Terminate(_) => break 'trading,
-                    Command::ExitPosition(market) => {
-                        self.event_q
-                            .push_back(Event::SignalForceExit(SignalForceExit::from(market)));
-                    }
-                    _ => continue,
+        while self.step() {}
+
+        debug!(
+            engine_id = &*self.engine_id.to_string(),
+            market = &*format!("{:?}", self.market),
+            "Trader trading loop stopped"
+        );
+    }
+
+    /// Run a single iteration of the trading event-loop. Processes any remote [`Command`]s,
+    /// consumes the next [`Feed`] event (or any [`MarketEvent`]s injected via
+    /// [`Trader::inject_market_event`]), and handles every [`Event`] it generates until the
+    /// event_q is empty.
+    ///
+    /// Returns false if the trading loop should stop, either because a [`Command::Terminate`]
+    /// was received, the [`MarketGenerator`] yielded [`Feed::Finished`], or it remained
+    /// [`Feed::Disconnected`] after every attempt permitted by the [`ReconnectPolicy`].
+    pub fn step(&mut self) -> bool {
+        self.step_with(|_| true)
+    }
+
+    /// Run the trading event-loop until `n` [`MarketEvent`]s have been handled before returning
+    /// this [`Trader`]. [`Feed::Unhealthy`] polls are not counted. If the [`MarketGenerator`]
+    /// finishes (or a [`Command::Terminate`] is received) before `n` [`MarketEvent`]s, the
+    /// [`Trader`] is returned in whatever state it reached.
+    pub fn run_for_n_events(mut self, n: usize) -> Self {
+        let target = self.market_events_consumed + n;
+
+        while self.market_events_consumed < target {
+            if !self.step() {
+                break;
+            }
+        }
+
+        self
+    }
+
+    /// Run the trading event-loop until the simulated time of the next [`MarketEvent`] (it's
+    /// exchange_time) exceeds `simulated_start + duration`, then return this [`Trader`]. The
+    /// [`MarketEvent`] that exceeds the limit is retained & handled by the next [`Trader::step`].
+    /// If the [`MarketGenerator`] finishes first, the [`Trader`] is returned in whatever state it
+    /// reached.
+    pub fn run_for_duration(
+        mut self,
+        duration: chrono::Duration,
+        simulated_start: DateTime<Utc>,
+    ) -> Self {
+        let simulated_end = simulated_start + duration;
+
+        while self.step_with(|market| market.exchange_time <= simulated_end) {}
+
+        self
+    }
+
+    /// Run a single iteration of the trading event-loop, stopping before handling the next
+    /// [`MarketEvent`] if it does not satisfy the provided `within_limit` predicate. The
+    /// [`MarketEvent`] is retained so it is handled by a subsequent iteration.
+    fn step_with<F>(&mut self, within_limit: F) -> bool
+    where
+        F: Fn(&MarketEvent<Instrument, DataKind>) -> bool,
+    {
+        // Stop trading if the DailyLossLimit has been breached
+        if self.daily_loss_limit_breached() {
+            return self.stop_trading("daily loss limit breached");
+        }
+
+        // Injected MarketEvents are handled in place of the next Feed event
+        let injected = !self.event_q.is_empty();
+
+        // Check for new remote Commands before continuing to generate another MarketEvent
+        while let Some(command) = self.receive_remote_command() {
+            match command {
+                Command::Terminate(message) => return self.stop_trading(&message),
+                Command::ExitPosition(market) => {
+                    self.event_q
+                        .push_back(Event::SignalForceExit(SignalForceExit::from(market)));
                 }
+                _ => continue,
+            }
+        }
+
+        // Whilst backing off from an exchange rate limit, return so remote Commands are still
+        // checked on the next step
+        // '--> Once elapsed, the re-queued OrderEvent is handled in place of the next Feed event
+        let retrying_order = self.rate_limited_order.is_some();
+        if self.awaiting_rate_limit() {
+            return true;
+        }
+        let injected = injected || retrying_order;
+
+        if !injected {
+            // Whilst waiting to reconnect a disconnected Feed, return so remote Commands are
+            // still checked on the next step
+            if self.awaiting_reconnect() {
+                return true;
             }
 
             // If the Feed<MarketEvent> yields, populate event_q with the next MarketEvent
-            match self.data.next() {
+            // '--> Any MarketEvent retained by a previous run limit is handled first
+            let feed = match self.pending_market.take() {
+                Some(market) => Feed::Next(market),
+                None => self.data.next(),
+            };
+
+            if !matches!(feed, Feed::Disconnected) {
+                self.reconnect_attempt = 0;
+            }
+
+            match feed {
+                Feed::Next(market) if !within_limit(&market) => {
+                    self.pending_market = Some(market);
+                    return self.stop_trading("MarketEvent beyond run limit");
+                }
                 Feed::Next(market) => {
                     self.event_tx.send(Event::Market(market.clone()));
                     self.event_q.push_back(Event::Market(market));
@@ -154,86 +340,457 @@ where
                         action = "continuing while waiting for healthy Feed",
                         "MarketFeed unhealthy"
                     );
-                    continue 'trading;
+                    return true;
                 }
-                Feed::Finished => break 'trading,
+                Feed::Finished => return self.stop_trading("market feed finished"),
+                Feed::Disconnected if self.schedule_reconnect() => return true,
+                Feed::Disconnected => return self.stop_trading("market feed disconnected"),
             }
+        }
 
-            // Handle Events in the event_q
-            // '--> While loop will break when event_q is empty and requires another MarketEvent
-            while let Some(event) = self.event_q.pop_front() {
-                match event {
-                    Event::Market(market) => {
-                        if let Some(signal) = self.strategy.generate_signal(&market) {
+        // Handle Events in the event_q
+        // '--> While loop will break when event_q is empty and requires another MarketEvent
+        while let Some(event) = self.event_q.pop_front() {
+            trace!(
+                exchange = %self.market.exchange,
+                symbol = %self.market.instrument,
+                event_type = event.event_type(),
+                "Trader consuming Event"
+            );
+
+            match event {
+                Event::Market(market) => {
+                    #[cfg(feature = "metrics")]
+                    super::telemetry::record_market_event();
+
+                    self.roll_over_daily_loss_limit(market.exchange_time);
+
+                    // Strategy still consumes MarketEvents whilst warming up
+                    let warming_up = self.market_events_consumed < self.strategy.warmup_bars();
+                    self.market_events_consumed += 1;
+
+                    if let Some(signal) = self.strategy.generate_signal(&market) {
+                        if warming_up {
+                            trace!(
+                                exchange = %self.market.exchange,
+                                symbol = %self.market.instrument,
+                                "Trader discarding Signal generated during Strategy warm-up"
+                            );
+                        } else {
                             self.event_tx.send(Event::Signal(signal.clone()));
                             self.event_q.push_back(Event::Signal(signal));
                         }
+                    }
+
+                    if let Some(position_update) = self
+                        .portfolio
+                        .lock()
+                        .update_from_market(&market)
+                        .expect("failed to update Portfolio from market")
+                    {
+                        #[cfg(feature = "metrics")]
+                        super::telemetry::record_unrealised_profit_loss(
+                            &self.market,
+                            position_update.unrealised_profit_loss,
+                        );
 
-                        if let Some(position_update) = self
-                            .portfolio
-                            .lock()
-                            .update_from_market(&market)
-                            .expect("failed to update Portfolio from market")
-                        {
-                            self.event_tx.send(Event::PositionUpdate(position_update));
+                        if let Some(hook) = &self.position_event_hook {
+                            hook.0(&PositionEvent::Updated(position_update.clone()));
                         }
+
+                        self.event_tx.send(Event::PositionUpdate(position_update));
                     }
+                }
 
-                    Event::Signal(signal) => {
-                        if let Some(order) = self
-                            .portfolio
-                            .lock()
-                            .generate_order(&signal)
-                            .expect("failed to generate order")
-                        {
-                            self.event_tx.send(Event::OrderNew(order.clone()));
-                            self.event_q.push_back(Event::OrderNew(order));
-                        }
+                Event::Signal(mut signal) => {
+                    // Discard entry decisions whilst the CircuitBreaker is triggered
+                    if self
+                        .circuit_breaker
+                        .is_some_and(|breaker| breaker.triggered)
+                    {
+                        signal.signals.retain(|decision, _| decision.is_exit());
                     }
 
-                    Event::SignalForceExit(signal_force_exit) => {
-                        if let Some(order) = self
-                            .portfolio
-                            .lock()
-                            .generate_exit_order(signal_force_exit)
-                            .expect("failed to generate forced exit order")
-                        {
-                            self.event_tx.send(Event::OrderNew(order.clone()));
-                            self.event_q.push_back(Event::OrderNew(order));
+                    let order = match self.portfolio.lock().generate_order(&signal) {
+                        Ok(order) => order,
+                        Err(PortfolioError::InvalidSignalStrength(strength)) => {
+                            warn!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                strength,
+                                action = "discarding Signal",
+                                "Signal strength outside of [0.0, 1.0]"
+                            );
+                            None
                         }
-                    }
+                        Err(error) => panic!("failed to generate order: {error:?}"),
+                    };
 
-                    Event::OrderNew(order) => {
-                        let fill = self
-                            .execution
-                            .generate_fill(&order)
-                            .expect("failed to generate Fill");
+                    if let Some(order) = order {
+                        self.event_tx.send(Event::OrderNew(order.clone()));
+                        self.event_q.push_back(Event::OrderNew(order));
+                    }
+                }
 
-                        self.event_tx.send(Event::Fill(fill.clone()));
-                        self.event_q.push_back(Event::Fill(fill));
+                Event::SignalForceExit(signal_force_exit) => {
+                    if let Some(order) = self
+                        .portfolio
+                        .lock()
+                        .generate_exit_order(signal_force_exit)
+                        .expect("failed to generate forced exit order")
+                    {
+                        self.event_tx.send(Event::OrderNew(order.clone()));
+                        self.event_q.push_back(Event::OrderNew(order));
                     }
+                }
+
+                Event::OrderNew(order) => {
+                    #[cfg(feature = "metrics")]
+                    let started = std::time::Instant::now();
 
-                    Event::Fill(fill) => {
-                        let fill_side_effect_events = self
-                            .portfolio
-                            .lock()
-                            .update_from_fill(&fill)
-                            .expect("failed to update Portfolio from fill");
+                    let fill = match self.execution.generate_fill(&order) {
+                        Ok(fill) => fill,
+                        Err(ExecutionError::ExchangeRateLimited { retry_after_ms }) => {
+                            warn!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                retry_after_ms,
+                                action = "retrying OrderEvent after backing off",
+                                "exchange rate limited OrderEvent"
+                            );
+                            let retry_at = Instant::now() + Duration::from_millis(retry_after_ms);
+                            self.rate_limited_order = Some((retry_at, order));
+                            return true;
+                        }
+                        Err(ExecutionError::Rejected(reason)) => {
+                            warn!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                tif = ?order.tif,
+                                ?reason,
+                                "execution handler rejected OrderEvent"
+                            );
+                            self.portfolio.lock().on_order_rejected(&order);
+                            let rejected = FillRejected { order, reason };
+                            self.strategy.on_fill_rejected(&rejected);
+                            self.event_tx.send(Event::FillRejected(rejected));
+                            continue;
+                        }
+                        Err(
+                            error @ (ExecutionError::ExchangeUnavailable
+                            | ExecutionError::RequestTimeout { .. }),
+                        ) => return self.stop_trading(&error.to_string()),
+                        Err(error) => panic!("failed to generate Fill: {error:?}"),
+                    };
+
+                    #[cfg(feature = "metrics")]
+                    super::telemetry::record_fill_latency(started.elapsed());
+
+                    self.event_tx.send(Event::Fill(fill.clone()));
+                    self.event_q.push_back(Event::Fill(fill));
+                }
 
-                        self.event_tx.send_many(fill_side_effect_events);
+                Event::Fill(fill) => {
+                    let fill_side_effect_events = self
+                        .portfolio
+                        .lock()
+                        .update_from_fill(&fill)
+                        .expect("failed to update Portfolio from fill");
+
+                    // Notify the Strategy of any Positions entered or exited by the FillEvent
+                    for event in fill_side_effect_events.iter() {
+                        match event {
+                            Event::PositionNew(position) => {
+                                self.strategy.on_position_opened(position);
+
+                                if let Some(hook) = &self.position_event_hook {
+                                    hook.0(&PositionEvent::Opened(Box::new(position.clone())));
+                                }
+                            }
+                            Event::PositionExit(position_exit) => {
+                                self.strategy.on_position_closed(position_exit);
+                                self.update_circuit_breaker(position_exit.realised_profit_loss);
+
+                                if let Some(hook) = &self.position_event_hook {
+                                    hook.0(&PositionEvent::Closed(position_exit.clone()));
+                                }
+                            }
+                            _ => {}
+                        }
                     }
-                    _ => {}
+
+                    self.update_daily_loss_limit(&fill_side_effect_events);
+
+                    #[cfg(feature = "metrics")]
+                    super::telemetry::record_fill(&self.market, &fill_side_effect_events);
+
+                    self.event_tx.send_many(fill_side_effect_events);
                 }
+                _ => {}
+            }
+        }
+
+        if self.daily_loss_limit_breached() {
+            return self.stop_trading("daily loss limit breached");
+        }
+
+        true
+    }
+
+    /// Schedules the next poll of a disconnected [`MarketGenerator`] according to the
+    /// [`ReconnectPolicy`]. Returns false if the [`ReconnectPolicy`] permits no further attempts.
+    fn schedule_reconnect(&mut self) -> bool {
+        self.reconnect_attempt += 1;
+
+        let Some(delay) = self.reconnect_policy.delay(self.reconnect_attempt) else {
+            return false;
+        };
+
+        warn!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            attempt = self.reconnect_attempt,
+            ?delay,
+            action = "attempting to reconnect after delay",
+            "MarketFeed disconnected"
+        );
+        self.reconnect_at = Some(Instant::now() + delay);
+        true
+    }
+
+    /// Determines if the [`Trader`] is still backing off from an exchange rate limit. Once the
+    /// back off has elapsed, the rate limited [`OrderEvent`] is re-queued ahead of any other
+    /// non-fill [`Event`]. Waits at most [`RECONNECT_POLL_INTERVAL`] per call, like
+    /// [`Self::awaiting_reconnect`].
+    fn awaiting_rate_limit(&mut self) -> bool {
+        let Some((retry_at, _)) = &self.rate_limited_order else {
+            return false;
+        };
+
+        let remaining = retry_at.saturating_duration_since(Instant::now());
+        if !remaining.is_zero() {
+            std::thread::sleep(remaining.min(RECONNECT_POLL_INTERVAL));
+            return true;
+        }
+
+        if let Some((_, order)) = self.rate_limited_order.take() {
+            self.event_q.push_front(Event::OrderNew(order));
+        }
+        false
+    }
+
+    /// Determines if the [`Trader`] is still waiting to poll a disconnected [`MarketGenerator`].
+    /// Waits at most [`RECONNECT_POLL_INTERVAL`] so the trading loop does not spin, whilst
+    /// remaining responsive to remote [`Command`]s.
+    fn awaiting_reconnect(&mut self) -> bool {
+        let Some(reconnect_at) = self.reconnect_at else {
+            return false;
+        };
+
+        let remaining = reconnect_at.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            self.reconnect_at = None;
+            return false;
+        }
+
+        std::thread::sleep(remaining.min(RECONNECT_POLL_INTERVAL));
+        true
+    }
+
+    /// Logs the reason the trading loop is transitioning from Consume -> Terminate, returning
+    /// false to signal the loop should stop.
+    fn stop_trading(&self, reason: &str) -> bool {
+        debug!(
+            exchange = %self.market.exchange,
+            symbol = %self.market.instrument,
+            transition = "Consume -> Terminate",
+            reason,
+            "Trader stopping trading loop"
+        );
+        false
+    }
+
+    /// Consumes this [`Trader`] and returns a new [`Trader`] that uses the provided
+    /// `NewStrategy`. Every other component, including the event_q & shared Portfolio state, is
+    /// carried over - useful for hot-swapping strategy parameters without restarting the
+    /// [`Engine`](super::Engine).
+    pub fn with_strategy<NewStrategy>(
+        self,
+        strategy: NewStrategy,
+    ) -> Trader<EventTx, Statistic, Portfolio, Data, NewStrategy, Execution>
+    where
+        NewStrategy: SignalGenerator + Send,
+    {
+        info!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            "Trader swapped Strategy"
+        );
+
+        Trader {
+            engine_id: self.engine_id,
+            market: self.market,
+            command_rx: self.command_rx,
+            event_tx: self.event_tx,
+            event_q: self.event_q,
+            portfolio: self.portfolio,
+            data: self.data,
+            strategy,
+            execution: self.execution,
+            circuit_breaker: self.circuit_breaker,
+            daily_loss_limit: self.daily_loss_limit,
+            market_events_consumed: self.market_events_consumed,
+            pending_market: self.pending_market,
+            reconnect_policy: self.reconnect_policy,
+            reconnect_attempt: self.reconnect_attempt,
+            reconnect_at: self.reconnect_at,
+            rate_limited_order: self.rate_limited_order,
+            position_event_hook: self.position_event_hook,
+            max_queue_depth: self.max_queue_depth,
+            backpressure_policy: self.backpressure_policy,
+            _statistic_marker: PhantomData,
+        }
+    }
+
+    /// Sets a hook that is called with every [`PositionEvent`] this [`Trader`] encounters, ie/
+    /// the entry & exit of a Position by a [`FillEvent`], and every update by a [`MarketEvent`].
+    /// Must be set before the [`Trader`] runs.
+    pub fn with_position_event_hook<Hook>(self, hook: Hook) -> Self
+    where
+        Hook: Fn(&PositionEvent) + Send + Sync + 'static,
+    {
+        Self {
+            position_event_hook: Some(PositionEventHook(Box::new(hook))),
+            ..self
+        }
+    }
+
+    /// Injects a [`MarketEvent`] that is handled by the next [`Trader::step`] in place of the
+    /// next [`Feed`] event, enabling deterministic integration tests without a real data feed.
+    /// Multiple injected [`MarketEvent`]s are handled in the order they were injected.
+    ///
+    /// If the event_q is already at the `max_queue_depth`, the [`BackpressurePolicy`] is applied:
+    ///  - [`BackpressurePolicy::DropOldest`]: the oldest queued non-fill [`Event`] is dropped to
+    ///    make room for the [`MarketEvent`]. Pending [`FillEvent`]s are never dropped, so if the
+    ///    event_q only holds [`FillEvent`]s the [`MarketEvent`] is rejected as per
+    ///    [`BackpressurePolicy::Block`].
+    ///  - [`BackpressurePolicy::Block`]: the [`MarketEvent`] is rejected with a
+    ///    [`PortfolioError::EngineBackpressureError`], and should be re-injected once the event_q
+    ///    has been drained by [`Trader::step`].
+    pub fn inject_market_event(
+        &mut self,
+        market: MarketEvent<Instrument, DataKind>,
+    ) -> Result<(), PortfolioError> {
+        let queue_depth = self.event_q.len();
+        if queue_depth >= self.max_queue_depth {
+            warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                queue_depth,
+                limit = self.max_queue_depth,
+                policy = ?self.backpressure_policy,
+                "Trader event queue exceeds max queue depth"
+            );
+
+            let made_room = match self.backpressure_policy {
+                BackpressurePolicy::DropOldest => self.event_q.drop_oldest().is_some(),
+                BackpressurePolicy::Block => false,
+            };
+
+            if !made_room {
+                return Err(PortfolioError::EngineBackpressureError {
+                    queue_depth,
+                    limit: self.max_queue_depth,
+                });
             }
+        }
+
+        self.event_tx.send(Event::Market(market.clone()));
+        self.event_q.push_back(Event::Market(market));
+        Ok(())
+    }
+
+    /// Injects a [`FillEvent`] into the high-priority fill queue of the [`PriorityEventQueue`],
+    /// to be applied to the Portfolio by the next [`Trader::step`] in place of the next [`Feed`]
+    /// event. Enables testing the Portfolio fill handling without an execution handler.
+    pub fn inject_fill_event(&mut self, fill: FillEvent) {
+        self.event_tx.send(Event::Fill(fill.clone()));
+        self.event_q.push_back(Event::Fill(fill));
+    }
+
+    /// Returns a reference to the [`Trader`]'s [`CircuitBreaker`], if one is configured.
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.circuit_breaker.as_ref()
+    }
+
+    /// Manually resets the [`Trader`]'s [`CircuitBreaker`], if one is configured, allowing new
+    /// [`Position`](crate::portfolio::position::Position)s to be entered.
+    pub fn reset_circuit_breaker(&mut self) {
+        if let Some(breaker) = self.circuit_breaker.as_mut() {
+            breaker.reset();
+        }
+    }
+
+    /// Updates the [`CircuitBreaker`], if one is configured, with the realised profit & loss of
+    /// an exited [`Position`](crate::portfolio::position::Position).
+    fn update_circuit_breaker(&mut self, realised_profit_loss: f64) {
+        let Some(breaker) = self.circuit_breaker.as_mut() else {
+            return;
+        };
 
-            debug!(
-                engine_id = &*self.engine_id.to_string(),
-                market = &*format!("{:?}", self.market),
-                "Trader trading loop stopped"
+        let was_triggered = breaker.triggered;
+        if breaker.update(realised_profit_loss) && !was_triggered {
+            warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                consecutive_losses = breaker.current_consecutive_losses,
+                action = "discarding entry Signals until reset",
+                "CircuitBreaker triggered"
             );
         }
     }
 
+    /// Determines if the [`Trader`]'s [`DailyLossLimit`], if one is configured, has been breached.
+    pub fn daily_loss_limit_breached(&self) -> bool {
+        self.daily_loss_limit.is_some_and(|limit| limit.breached)
+    }
+
+    /// Rolls the [`DailyLossLimit`], if one is configured, over to a new UTC day using the
+    /// [`MarketEvent`] exchange time, sampling the current equity as the start-of-day equity.
+    fn roll_over_daily_loss_limit(&mut self, time: DateTime<Utc>) {
+        if let Some(limit) = self.daily_loss_limit.as_mut() {
+            limit.roll_over(time);
+        }
+    }
+
+    /// Updates the [`DailyLossLimit`], if one is configured, with the latest Portfolio
+    /// [`Balance`](crate::portfolio::Balance) after a fill.
+    fn update_daily_loss_limit(&mut self, fill_side_effect_events: &[Event]) {
+        let Some(limit) = self.daily_loss_limit.as_mut() else {
+            return;
+        };
+
+        let balance = fill_side_effect_events
+            .iter()
+            .find_map(|event| match event {
+                Event::Balance(balance) => Some(balance),
+                _ => None,
+            });
+
+        if let Some(balance) = balance {
+            if limit.update(balance) {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    start_of_day_equity = limit.start_of_day_equity,
+                    current_equity = balance.total,
+                    action = "stopping Trader",
+                    "DailyLossLimit breached"
+                );
+            }
+        }
+    }
+
     /// Returns a [`Command`] if one has been received.
     fn receive_remote_command(&mut self) -> Option<Command> {
         match self.command_rx.try_recv() {
@@ -262,6 +819,125 @@ where
     }
 }
 
+/// Maximum time a [`Trader`] waiting to reconnect a disconnected [`MarketGenerator`] blocks
+/// before checking for remote [`Command`]s again.
+pub const RECONNECT_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Policy determining how a [`Trader`] attempts to reconnect a [`MarketGenerator`] that yields
+/// [`Feed::Disconnected`], before stopping the trading loop.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub enum ReconnectPolicy {
+    /// Stop trading immediately upon disconnection.
+    #[default]
+    NoReconnect,
+    /// Attempt to reconnect up to `attempts` times, waiting `delay_ms` before each attempt.
+    FixedDelay { attempts: u32, delay_ms: u64 },
+    /// Attempt to reconnect up to `max_attempts` times, waiting `initial_delay_ms` before the
+    /// first attempt & multiplying the delay by the `multiplier` before each subsequent attempt.
+    ExponentialBackoff {
+        max_attempts: u32,
+        initial_delay_ms: u64,
+        multiplier: f64,
+    },
+}
+
+impl ReconnectPolicy {
+    /// Returns the delay to wait before the provided reconnection attempt (starting at 1), or
+    /// `None` if the [`ReconnectPolicy`] does not permit the attempt.
+    pub fn delay(&self, attempt: u32) -> Option<Duration> {
+        match *self {
+            Self::NoReconnect => None,
+            Self::FixedDelay { attempts, delay_ms } => {
+                (attempt <= attempts).then(|| Duration::from_millis(delay_ms))
+            }
+            Self::ExponentialBackoff {
+                max_attempts,
+                initial_delay_ms,
+                multiplier,
+            } => (attempt <= max_attempts).then(|| {
+                let delay_ms = initial_delay_ms as f64 * multiplier.powi(attempt as i32 - 1);
+                Duration::from_millis(delay_ms as u64)
+            }),
+        }
+    }
+}
+
+/// Policy determining how a [`Trader`] handles new [`MarketEvent`]s once it's event queue
+/// exceeds the configured `max_queue_depth`, ie/ when events arrive faster than they can be
+/// processed. Also applied by a live
+/// [`BoundedMarketFeed`](crate::data::live::BoundedMarketFeed) once it's channel is full.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub enum BackpressurePolicy {
+    /// Drop the oldest queued non-fill [`Event`] to make room for the new [`MarketEvent`]. If
+    /// only [`FillEvent`]s are queued the new [`MarketEvent`] is rejected.
+    #[default]
+    DropOldest,
+    /// Reject the new [`MarketEvent`], pausing consumption until the queue has been drained. A
+    /// [`BoundedMarketFeed`](crate::data::live::BoundedMarketFeed) producer waits instead.
+    Block,
+}
+
+/// [`Event`] queue used by the [`Trader`] trading loop that holds [`FillEvent`]s in a separate
+/// high-priority queue. Pending [`FillEvent`]s are always popped before any other [`Event`], so
+/// executed trades are applied to the Portfolio before the next [`MarketEvent`] is handled.
+#[derive(Debug, Default)]
+pub struct PriorityEventQueue {
+    fills: VecDeque<FillEvent>,
+    events: VecDeque<Event>,
+}
+
+impl PriorityEventQueue {
+    /// Constructs a new [`PriorityEventQueue`] with the provided capacity for non-fill [`Event`]s.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            fills: VecDeque::new(),
+            events: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Appends an [`Event`] to the back of the queue, routing [`FillEvent`]s to the
+    /// high-priority queue.
+    pub fn push_back(&mut self, event: Event) {
+        match event {
+            Event::Fill(fill) => self.fills.push_back(fill),
+            event => self.events.push_back(event),
+        }
+    }
+
+    /// Prepends an [`Event`] to the front of the queue, routing [`FillEvent`]s to the
+    /// high-priority queue.
+    pub fn push_front(&mut self, event: Event) {
+        match event {
+            Event::Fill(fill) => self.fills.push_front(fill),
+            event => self.events.push_front(event),
+        }
+    }
+
+    /// Removes the next [`Event`] from the queue, draining any pending [`FillEvent`]s first.
+    pub fn pop_front(&mut self) -> Option<Event> {
+        self.fills
+            .pop_front()
+            .map(Event::Fill)
+            .or_else(|| self.events.pop_front())
+    }
+
+    /// Removes the oldest non-fill [`Event`] from the queue. Pending [`FillEvent`]s are never
+    /// dropped, since they represent trades that have already been executed.
+    pub fn drop_oldest(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    /// Returns the total number of queued [`Event`]s.
+    pub fn len(&self) -> usize {
+        self.fills.len() + self.events.len()
+    }
+
+    /// Determines if the queue contains no [`Event`]s.
+    pub fn is_empty(&self) -> bool {
+        self.fills.is_empty() && self.events.is_empty()
+    }
+}
+
 /// Builder to construct [`Trader`] instances.
 #[derive(Debug, Default)]
 pub struct TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -281,6 +957,11 @@ where
     data: Option<Data>,
     strategy: Option<Strategy>,
     execution: Option<Execution>,
+    circuit_breaker: Option<CircuitBreaker>,
+    daily_loss_limit: Option<DailyLossLimit>,
+    reconnect_policy: Option<ReconnectPolicy>,
+    max_queue_depth: Option<usize>,
+    backpressure_policy: Option<BackpressurePolicy>,
     _statistic_marker: Option<PhantomData<Statistic>>,
 }
 
@@ -304,6 +985,11 @@ where
             data: None,
             strategy: None,
             execution: None,
+            circuit_breaker: None,
+            daily_loss_limit: None,
+            reconnect_policy: None,
+            max_queue_depth: None,
+            backpressure_policy: None,
             _statistic_marker: None,
         }
     }
@@ -364,6 +1050,41 @@ where
         }
     }
 
+    pub fn circuit_breaker(self, value: CircuitBreaker) -> Self {
+        Self {
+            circuit_breaker: Some(value),
+            ..self
+        }
+    }
+
+    pub fn daily_loss_limit(self, value: DailyLossLimit) -> Self {
+        Self {
+            daily_loss_limit: Some(value),
+            ..self
+        }
+    }
+
+    pub fn reconnect_policy(self, value: ReconnectPolicy) -> Self {
+        Self {
+            reconnect_policy: Some(value),
+            ..self
+        }
+    }
+
+    pub fn max_queue_depth(self, value: usize) -> Self {
+        Self {
+            max_queue_depth: Some(value),
+            ..self
+        }
+    }
+
+    pub fn backpressure_policy(self, value: BackpressurePolicy) -> Self {
+        Self {
+            backpressure_policy: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(
         self,
     ) -> Result<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
@@ -380,7 +1101,7 @@ where
             event_tx: self
                 .event_tx
                 .ok_or(EngineError::BuilderIncomplete("event_tx"))?,
-            event_q: VecDeque::with_capacity(2),
+            event_q: PriorityEventQueue::with_capacity(2),
             portfolio: self
                 .portfolio
                 .ok_or(EngineError::BuilderIncomplete("portfolio"))?,
@@ -391,7 +1112,1203 @@ where
             execution: self
                 .execution
                 .ok_or(EngineError::BuilderIncomplete("execution"))?,
+            circuit_breaker: self.circuit_breaker,
+            daily_loss_limit: self.daily_loss_limit,
+            market_events_consumed: 0,
+            pending_market: None,
+            reconnect_policy: self.reconnect_policy.unwrap_or_default(),
+            reconnect_attempt: 0,
+            reconnect_at: None,
+            rate_limited_order: None,
+            position_event_hook: None,
+            max_queue_depth: self.max_queue_depth.unwrap_or(usize::MAX),
+            backpressure_policy: self.backpressure_policy.unwrap_or_default(),
             _statistic_marker: PhantomData,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{historical, MarketMeta},
+        event::EventTx,
+        execution::{
+            simulated::{Config as ExecutionConfig, SimulatedExecution},
+            Fees, FillRejectedReason, TimeInForce,
+        },
+        portfolio::{
+            allocator::DefaultAllocator,
+            error::PortfolioError,
+            portfolio::{MetaPortfolio, PortfolioLego},
+            position::{determine_position_id, Position, PositionExit, PositionId},
+            repository::{in_memory::InMemoryRepository, PositionHandler},
+            risk::DefaultRisk,
+            OrderEvent,
+        },
+        statistic::summary::trading::{Config as StatisticConfig, TradingSummary},
+        strategy::{Decision, Signal, SignalStrength},
+        test_util::market_event_trade,
+    };
+    use barter_data::subscription::trade::PublicTrade;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use std::collections::HashMap;
+
+    type TestTrader = Trader<
+        EventTx,
+        TradingSummary,
+        TestPortfolio,
+        historical::MarketFeed<
+            std::vec::IntoIter<MarketEvent<Instrument, DataKind>>,
+            MarketEvent<Instrument, DataKind>,
+        >,
+        MockStrategy,
+        SimulatedExecution,
+    >;
+
+    type TestPortfolio = MetaPortfolio<
+        InMemoryRepository<TradingSummary>,
+        DefaultAllocator,
+        DefaultRisk,
+        TradingSummary,
+    >;
+
+    /// Mock strategy that always advises the same [`Decision`], recording the Positions it is
+    /// notified of.
+    #[derive(Debug)]
+    struct MockStrategy {
+        decision: Decision,
+        opened: Vec<PositionId>,
+        closed: Vec<PositionId>,
+        rejected: Vec<FillRejectedReason>,
+    }
+
+    impl MockStrategy {
+        fn new(decision: Decision) -> Self {
+            Self {
+                decision,
+                opened: vec![],
+                closed: vec![],
+                rejected: vec![],
+            }
+        }
+    }
+
+    impl SignalGenerator for MockStrategy {
+        fn generate_signal(
+            &mut self,
+            market: &MarketEvent<Instrument, DataKind>,
+        ) -> Option<Signal> {
+            let close = match &market.kind {
+                DataKind::Trade(trade) => trade.price,
+                _ => return None,
+            };
+
+            Some(Signal {
+                time: market.exchange_time,
+                exchange: market.exchange.clone(),
+                instrument: market.instrument.clone(),
+                signals: HashMap::from([(self.decision, SignalStrength(1.0))]),
+                market_meta: MarketMeta {
+                    close,
+                    time: market.exchange_time,
+                },
+            })
+        }
+
+        fn on_position_opened(&mut self, position: &Position) {
+            self.opened.push(position.position_id.clone());
+        }
+
+        fn on_position_closed(&mut self, position: &PositionExit) {
+            self.closed.push(position.position_id.clone());
+        }
+
+        fn on_fill_rejected(&mut self, rejected: &FillRejected) {
+            self.rejected.push(rejected.reason);
+        }
+    }
+
+    fn test_market() -> Market {
+        Market::new("binance_spot", ("btc", "usdt", InstrumentKind::Spot))
+    }
+
+    fn test_portfolio(engine_id: Uuid) -> Arc<Mutex<TestPortfolio>> {
+        let statistic_config = StatisticConfig {
+            starting_equity: 10_000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        };
+
+        let portfolio = MetaPortfolio::init(PortfolioLego {
+            engine_id,
+            markets: vec![test_market()],
+            repository: InMemoryRepository::new(),
+            allocator: DefaultAllocator {
+                default_order_value: 100.0,
+            },
+            risk: DefaultRisk {},
+            starting_cash: 10_000.0,
+            statistic_config,
+            _statistic_marker: PhantomData,
+        })
+        .unwrap();
+
+        Arc::new(Mutex::new(portfolio))
+    }
+
+    fn test_trader(
+        engine_id: Uuid,
+        portfolio: &Arc<Mutex<TestPortfolio>>,
+        command_rx: mpsc::Receiver<Command>,
+        event_tx: mpsc::UnboundedSender<Event>,
+        markets: Vec<MarketEvent<Instrument, DataKind>>,
+    ) -> TestTrader {
+        Trader::builder()
+            .engine_id(engine_id)
+            .market(test_market())
+            .command_rx(command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(Arc::clone(portfolio))
+            .data(historical::MarketFeed::new(markets))
+            .strategy(MockStrategy::new(Decision::Long))
+            .execution(SimulatedExecution::new(ExecutionConfig {
+                simulated_fees_pct: Fees::default(),
+            }))
+            .build()
+            .unwrap()
+    }
+
+    fn market_events_at(times: &[DateTime<Utc>]) -> Vec<MarketEvent<Instrument, DataKind>> {
+        times
+            .iter()
+            .map(|&time| {
+                let mut market = market_event_trade(Side::Buy);
+                market.exchange_time = time;
+                market
+            })
+            .collect()
+    }
+
+    fn count_market_events(event_rx: &mut mpsc::UnboundedReceiver<Event>) -> usize {
+        std::iter::from_fn(|| event_rx.try_recv().ok())
+            .filter(|event| matches!(event, Event::Market(_)))
+            .count()
+    }
+
+    #[test]
+    fn should_use_swapped_strategy_for_subsequent_events_and_preserve_portfolio_state() {
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+
+        let mut trader = test_trader(
+            engine_id,
+            &portfolio,
+            command_rx,
+            event_tx,
+            vec![market_event_trade(Side::Buy), market_event_trade(Side::Buy)],
+        );
+
+        // First MarketEvent is handled by the original Long Strategy, opening a Position
+        assert!(trader.step());
+        let open_positions = portfolio
+            .lock()
+            .get_open_positions(engine_id, [test_market()].iter())
+            .unwrap();
+        assert_eq!(open_positions.len(), 1);
+
+        // Swap to a CloseLong Strategy and handle the second MarketEvent
+        let mut trader = trader.with_strategy(MockStrategy::new(Decision::CloseLong));
+        assert!(trader.step());
+
+        // Position opened before the swap is exited by the new Strategy
+        let open_positions = portfolio
+            .lock()
+            .get_open_positions(engine_id, [test_market()].iter())
+            .unwrap();
+        assert!(open_positions.is_empty());
+
+        let exited_positions = portfolio.lock().get_exited_positions(engine_id).unwrap();
+        assert_eq!(exited_positions.len(), 1);
+
+        let signal_decisions = std::iter::from_fn(|| event_rx.try_recv().ok())
+            .filter_map(|event| match event {
+                Event::Signal(signal) => signal.signals.keys().next().copied(),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(signal_decisions, vec![Decision::Long, Decision::CloseLong]);
+
+        // Feed is now exhausted
+        assert!(!trader.step());
+    }
+
+    #[test]
+    fn run_for_n_events_stops_after_n_events() {
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let now = Utc::now();
+
+        let trader = test_trader(
+            engine_id,
+            &portfolio,
+            command_rx,
+            event_tx,
+            market_events_at(&[now, now, now]),
+        );
+
+        let mut trader = trader.run_for_n_events(2);
+        assert_eq!(count_market_events(&mut event_rx), 2);
+
+        // Remaining MarketEvent is still available to the returned Trader
+        assert!(trader.step());
+        assert_eq!(count_market_events(&mut event_rx), 1);
+        assert!(!trader.step());
+    }
+
+    #[test]
+    fn run_for_n_events_does_not_count_unhealthy_feed_polls() {
+        /// Mock feed that yields the scripted Feed events, before finishing.
+        struct ScriptedFeed(std::vec::IntoIter<Feed<MarketEvent<Instrument, DataKind>>>);
+
+        impl MarketGenerator<MarketEvent<Instrument, DataKind>> for ScriptedFeed {
+            fn next(&mut self) -> Feed<MarketEvent<Instrument, DataKind>> {
+                self.0.next().unwrap_or(Feed::Finished)
+            }
+        }
+
+        let engine_id = Uuid::new_v4();
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+
+        let trader = Trader::<_, TradingSummary, _, _, _, _>::builder()
+            .engine_id(engine_id)
+            .market(test_market())
+            .command_rx(command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(test_portfolio(engine_id))
+            .data(ScriptedFeed(
+                vec![
+                    Feed::Unhealthy,
+                    Feed::Next(market_event_trade(Side::Buy)),
+                    Feed::Unhealthy,
+                    Feed::Unhealthy,
+                    Feed::Next(market_event_trade(Side::Buy)),
+                    Feed::Next(market_event_trade(Side::Buy)),
+                ]
+                .into_iter(),
+            ))
+            .strategy(MockStrategy::new(Decision::Long))
+            .execution(SimulatedExecution::new(ExecutionConfig {
+                simulated_fees_pct: Fees::default(),
+            }))
+            .build()
+            .unwrap();
+
+        let mut trader = trader.run_for_n_events(2);
+        assert_eq!(count_market_events(&mut event_rx), 2);
+        assert_eq!(trader.market_events_consumed, 2);
+
+        // Final MarketEvent is still available to the returned Trader
+        assert!(trader.step());
+        assert_eq!(count_market_events(&mut event_rx), 1);
+    }
+
+    #[test]
+    fn run_for_n_events_returns_when_feed_is_shorter_than_n() {
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let now = Utc::now();
+
+        let trader = test_trader(
+            engine_id,
+            &portfolio,
+            command_rx,
+            event_tx,
+            market_events_at(&[now, now]),
+        );
+
+        let mut trader = trader.run_for_n_events(1000);
+        assert_eq!(count_market_events(&mut event_rx), 2);
+        assert!(!trader.step());
+    }
+
+    #[test]
+    fn run_for_duration_stops_once_simulated_time_exceeds_limit() {
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let start = Utc::now();
+
+        let trader = test_trader(
+            engine_id,
+            &portfolio,
+            command_rx,
+            event_tx,
+            market_events_at(&[
+                start,
+                start + chrono::Duration::minutes(1),
+                start + chrono::Duration::minutes(10),
+                start + chrono::Duration::minutes(11),
+            ]),
+        );
+
+        let mut trader = trader.run_for_duration(chrono::Duration::minutes(5), start);
+        assert_eq!(count_market_events(&mut event_rx), 2);
+
+        // MarketEvent that exceeded the limit is retained for the next step
+        assert!(trader.step());
+        assert!(trader.step());
+        assert_eq!(count_market_events(&mut event_rx), 2);
+        assert_eq!(trader.market_events_consumed, 4);
+        assert!(!trader.step());
+    }
+
+    #[test]
+    fn run_for_duration_returns_when_feed_is_shorter_than_duration() {
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let start = Utc::now();
+
+        let trader = test_trader(
+            engine_id,
+            &portfolio,
+            command_rx,
+            event_tx,
+            market_events_at(&[start, start + chrono::Duration::minutes(1)]),
+        );
+
+        let mut trader = trader.run_for_duration(chrono::Duration::days(1), start);
+        assert_eq!(count_market_events(&mut event_rx), 2);
+        assert!(!trader.step());
+    }
+
+    #[test]
+    fn should_notify_strategy_of_opened_and_closed_positions() {
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+
+        let mut trader = test_trader(
+            engine_id,
+            &portfolio,
+            command_rx,
+            event_tx,
+            vec![market_event_trade(Side::Buy), market_event_trade(Side::Buy)],
+        );
+
+        assert!(trader.step());
+        assert_eq!(trader.strategy.opened.len(), 1);
+        assert!(trader.strategy.closed.is_empty());
+
+        let mut trader = trader.with_strategy(MockStrategy::new(Decision::CloseLong));
+        assert!(trader.step());
+        assert!(trader.strategy.opened.is_empty());
+        assert_eq!(trader.strategy.closed.len(), 1);
+    }
+
+    #[test]
+    fn should_handle_injected_market_events_in_place_of_the_feed() {
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+
+        let mut trader = test_trader(engine_id, &portfolio, command_rx, event_tx, vec![]);
+
+        // Enter 1.0 BTC @ 100.0 (default_order_value), then update with each subsequent price
+        for price in [100.0, 120.0, 150.0] {
+            let mut market = market_event_trade(Side::Buy);
+            market.kind = DataKind::Trade(PublicTrade {
+                id: price.to_string(),
+                price,
+                amount: 1.0,
+                side: Side::Buy,
+            });
+
+            trader.inject_market_event(market).unwrap();
+            assert!(trader.step());
+        }
+
+        let market = test_market();
+        let position_id = determine_position_id(engine_id, &market.exchange, &market.instrument);
+        let position = portfolio
+            .lock()
+            .get_open_position(&position_id)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(position.quantity, 1.0);
+        assert_eq!(position.current_symbol_price, 150.0);
+        assert_eq!(position.unrealised_profit_loss, 150.0 - 100.0);
+
+        // The empty Feed is only polled once every injected MarketEvent has been handled
+        assert!(!trader.step());
+    }
+
+    #[test]
+    fn should_enforce_backpressure_policy_when_events_arrive_faster_than_processed() {
+        let priced_market = |price: f64| {
+            let mut market = market_event_trade(Side::Buy);
+            market.kind = DataKind::Trade(PublicTrade {
+                id: price.to_string(),
+                price,
+                amount: 1.0,
+                side: Side::Buy,
+            });
+            market
+        };
+
+        // DropOldest: only the most recent max_queue_depth MarketEvents are handled
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+        let mut trader = test_trader(engine_id, &portfolio, command_rx, event_tx, vec![])
+            .with_strategy(MockStrategy::new(Decision::CloseLong));
+        trader.max_queue_depth = 3;
+        trader.backpressure_policy = BackpressurePolicy::DropOldest;
+
+        for price in 1..=10 {
+            trader
+                .inject_market_event(priced_market(price as f64))
+                .unwrap();
+        }
+
+        let queued_prices = trader
+            .event_q
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Market(MarketEvent {
+                    kind: DataKind::Trade(trade),
+                    ..
+                }) => Some(trade.price),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(queued_prices, vec![8.0, 9.0, 10.0]);
+
+        assert!(trader.step());
+        assert_eq!(trader.market_events_consumed, 3);
+        assert!(trader.event_q.is_empty());
+
+        // DropOldest: pending FillEvents are never dropped, so the MarketEvent is rejected
+        for _ in 0..3 {
+            trader
+                .event_q
+                .push_back(Event::Fill(crate::test_util::fill_event()));
+        }
+        assert!(matches!(
+            trader.inject_market_event(priced_market(11.0)),
+            Err(PortfolioError::EngineBackpressureError {
+                queue_depth: 3,
+                limit: 3
+            })
+        ));
+        assert_eq!(trader.event_q.len(), 3);
+
+        // Block: MarketEvents are rejected until the event_q has been drained
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let mut trader = test_trader(engine_id, &portfolio, command_rx, event_tx, vec![])
+            .with_strategy(MockStrategy::new(Decision::CloseLong));
+        trader.max_queue_depth = 3;
+        trader.backpressure_policy = BackpressurePolicy::Block;
+
+        let results = (1..=5)
+            .map(|price| trader.inject_market_event(priced_market(price as f64)))
+            .collect::<Vec<_>>();
+        assert!(results[..3].iter().all(Result::is_ok));
+        for result in &results[3..] {
+            assert!(matches!(
+                result,
+                Err(PortfolioError::EngineBackpressureError {
+                    queue_depth: 3,
+                    limit: 3
+                })
+            ));
+        }
+        assert_eq!(count_market_events(&mut event_rx), 3);
+
+        assert!(trader.step());
+        assert_eq!(trader.market_events_consumed, 3);
+        assert!(trader.inject_market_event(priced_market(4.0)).is_ok());
+    }
+
+    #[test]
+    fn should_apply_injected_fill_events_to_the_portfolio() {
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+
+        let mut trader = test_trader(engine_id, &portfolio, command_rx, event_tx, vec![]);
+
+        let market = test_market();
+        let position_id = determine_position_id(engine_id, &market.exchange, &market.instrument);
+        let fill = |decision, quantity: f64, price: f64, time: DateTime<Utc>| FillEvent {
+            time,
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            market_meta: MarketMeta { close: price, time },
+            decision,
+            quantity,
+            fill_value_gross: quantity.abs() * price,
+            fees: Fees::default(),
+            is_maker: false,
+            tif: TimeInForce::default(),
+            trace_id: Uuid::new_v4(),
+        };
+        let enter_time = Utc::now() - chrono::Duration::minutes(1);
+
+        // Inject entry fill, step, assert Position opened
+        trader.inject_fill_event(fill(Decision::Long, 1.0, 100.0, enter_time));
+        assert!(trader.step());
+        let position = portfolio
+            .lock()
+            .get_open_position(&position_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(position.quantity, 1.0);
+        assert_eq!(position.enter_avg_price_gross, 100.0);
+
+        // Inject exit fill, step, assert Position closed & P&L computed
+        trader.inject_fill_event(fill(Decision::CloseLong, -1.0, 150.0, Utc::now()));
+        assert!(trader.step());
+        assert!(portfolio
+            .lock()
+            .get_open_position(&position_id)
+            .unwrap()
+            .is_none());
+        let exited = portfolio.lock().get_exited_positions(engine_id).unwrap();
+        assert_eq!(exited.len(), 1);
+        assert_eq!(exited[0].realised_profit_loss, 150.0 - 100.0);
+    }
+
+    #[test]
+    fn should_call_position_event_hook_with_every_position_lifecycle_event() {
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+
+        let received = Arc::new(Mutex::new(Vec::<PositionEvent>::new()));
+        let hook_received = Arc::clone(&received);
+
+        let mut trader = test_trader(
+            engine_id,
+            &portfolio,
+            command_rx,
+            event_tx,
+            vec![market_event_trade(Side::Buy), market_event_trade(Side::Buy)],
+        )
+        .with_position_event_hook(move |event| hook_received.lock().push(event.clone()));
+
+        // Long entry on the first MarketEvent, then update & exit the Position on the second
+        assert!(trader.step());
+        let mut trader = trader.with_strategy(MockStrategy::new(Decision::CloseLong));
+        assert!(trader.step());
+
+        let received = received.lock();
+        assert_eq!(received.len(), 3);
+        let PositionEvent::Opened(position) = &received[0] else {
+            panic!("expected PositionEvent::Opened, got: {:?}", received[0]);
+        };
+        assert!(matches!(
+            &received[1],
+            PositionEvent::Updated(update) if update.position_id == position.position_id
+        ));
+        assert!(matches!(
+            &received[2],
+            PositionEvent::Closed(exit) if exit.position_id == position.position_id
+        ));
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn should_emit_engine_metrics() {
+        use super::super::telemetry::{
+            FILL_EVENTS, FILL_LATENCY_US, MARKET_EVENTS, OPEN_POSITIONS, UNREALISED_PNL,
+        };
+        use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+
+        let mut trader = test_trader(
+            engine_id,
+            &portfolio,
+            command_rx,
+            event_tx,
+            vec![market_event_trade(Side::Buy), market_event_trade(Side::Buy)],
+        );
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+
+        // First step enters a Position, second step updates it's unrealised P&L
+        metrics::with_local_recorder(&recorder, || {
+            assert!(trader.step());
+            assert!(trader.step());
+        });
+
+        let metrics = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .map(|(key, _, _, value)| (key.key().name().to_owned(), value))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(metrics[MARKET_EVENTS], DebugValue::Counter(2));
+        assert_eq!(metrics[FILL_EVENTS], DebugValue::Counter(1));
+        assert!(matches!(&metrics[OPEN_POSITIONS], DebugValue::Gauge(open) if open.0 == 1.0));
+        assert!(matches!(&metrics[UNREALISED_PNL], DebugValue::Gauge(_)));
+        assert!(
+            matches!(&metrics[FILL_LATENCY_US], DebugValue::Histogram(samples) if samples.len() == 1)
+        );
+    }
+
+    #[test]
+    fn should_discard_entry_signals_once_circuit_breaker_is_triggered() {
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+
+        // Fees guarantee a loss when entering & exiting at the same price
+        let mut trader: TestTrader = Trader::builder()
+            .engine_id(engine_id)
+            .market(test_market())
+            .command_rx(command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(Arc::clone(&portfolio))
+            .data(historical::MarketFeed::new(vec![
+                market_event_trade(Side::Buy),
+                market_event_trade(Side::Buy),
+                market_event_trade(Side::Buy),
+            ]))
+            .strategy(MockStrategy::new(Decision::Long))
+            .execution(SimulatedExecution::new(ExecutionConfig {
+                simulated_fees_pct: Fees {
+                    exchange: 0.01,
+                    slippage: 0.0,
+                    network: 0.0,
+                },
+            }))
+            .circuit_breaker(CircuitBreaker::new(1))
+            .build()
+            .unwrap();
+
+        // Enter a Position, then exit it at a loss
+        assert!(trader.step());
+        let mut trader = trader.with_strategy(MockStrategy::new(Decision::CloseLong));
+        assert!(trader.step());
+        assert!(trader.circuit_breaker().unwrap().triggered);
+
+        // Long Signal is discarded whilst the CircuitBreaker is triggered
+        let mut trader = trader.with_strategy(MockStrategy::new(Decision::Long));
+        assert!(trader.step());
+        assert!(trader.strategy.opened.is_empty());
+        let open_positions = portfolio
+            .lock()
+            .get_open_positions(engine_id, [test_market()].iter())
+            .unwrap();
+        assert!(open_positions.is_empty());
+
+        trader.reset_circuit_breaker();
+        assert!(!trader.circuit_breaker().unwrap().triggered);
+    }
+
+    #[test]
+    fn priority_event_queue_pops_fills_before_other_events() {
+        let mut event_q = PriorityEventQueue::with_capacity(2);
+        assert!(event_q.is_empty());
+
+        event_q.push_back(Event::Market(market_event_trade(Side::Buy)));
+        event_q.push_back(Event::OrderUpdate);
+        event_q.push_back(Event::Fill(crate::test_util::fill_event()));
+        assert_eq!(event_q.len(), 3);
+
+        assert!(matches!(event_q.pop_front(), Some(Event::Fill(_))));
+        assert!(matches!(event_q.pop_front(), Some(Event::Market(_))));
+        assert!(matches!(event_q.pop_front(), Some(Event::OrderUpdate)));
+        assert!(event_q.pop_front().is_none());
+        assert!(event_q.is_empty());
+    }
+
+    #[test]
+    fn should_discard_signals_generated_during_strategy_warmup() {
+        /// Always advises a Long Decision, but requires 5 MarketEvents to warm up.
+        struct WarmupStrategy {
+            seen: usize,
+        }
+
+        impl SignalGenerator for WarmupStrategy {
+            fn generate_signal(
+                &mut self,
+                market: &MarketEvent<Instrument, DataKind>,
+            ) -> Option<Signal> {
+                self.seen += 1;
+                MockStrategy::new(Decision::Long).generate_signal(market)
+            }
+
+            fn warmup_bars(&self) -> usize {
+                5
+            }
+        }
+
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+
+        let mut trader = test_trader(
+            engine_id,
+            &portfolio,
+            command_rx,
+            event_tx,
+            (0..6).map(|_| market_event_trade(Side::Buy)).collect(),
+        )
+        .with_strategy(WarmupStrategy { seen: 0 });
+
+        let count_signals = |event_rx: &mut mpsc::UnboundedReceiver<Event>| {
+            std::iter::from_fn(|| event_rx.try_recv().ok())
+                .filter(|event| matches!(event, Event::Signal(_)))
+                .count()
+        };
+
+        // Signals generated from the first 5 MarketEvents are discarded
+        for _ in 0..5 {
+            assert!(trader.step());
+        }
+        assert_eq!(trader.strategy.seen, 5);
+        assert_eq!(count_signals(&mut event_rx), 0);
+        assert!(portfolio
+            .lock()
+            .get_open_positions(engine_id, [test_market()].iter())
+            .unwrap()
+            .is_empty());
+
+        // Signal processing resumes once the Strategy is warmed up
+        assert!(trader.step());
+        assert_eq!(count_signals(&mut event_rx), 1);
+        assert_eq!(
+            portfolio
+                .lock()
+                .get_open_positions(engine_id, [test_market()].iter())
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn reconnect_policy_delays() {
+        use std::time::Duration;
+
+        assert_eq!(ReconnectPolicy::NoReconnect.delay(1), None);
+
+        let fixed = ReconnectPolicy::FixedDelay {
+            attempts: 2,
+            delay_ms: 100,
+        };
+        assert_eq!(fixed.delay(1), Some(Duration::from_millis(100)));
+        assert_eq!(fixed.delay(2), Some(Duration::from_millis(100)));
+        assert_eq!(fixed.delay(3), None);
+
+        let backoff = ReconnectPolicy::ExponentialBackoff {
+            max_attempts: 3,
+            initial_delay_ms: 100,
+            multiplier: 2.0,
+        };
+        assert_eq!(backoff.delay(1), Some(Duration::from_millis(100)));
+        assert_eq!(backoff.delay(2), Some(Duration::from_millis(200)));
+        assert_eq!(backoff.delay(3), Some(Duration::from_millis(400)));
+        assert_eq!(backoff.delay(4), None);
+    }
+
+    /// Mock feed that is disconnected for the first `disconnects` polls, before yielding the
+    /// remaining MarketEvents.
+    struct FlakyFeed {
+        disconnects: usize,
+        markets: std::vec::IntoIter<MarketEvent<Instrument, DataKind>>,
+    }
+
+    impl MarketGenerator<MarketEvent<Instrument, DataKind>> for FlakyFeed {
+        fn next(&mut self) -> Feed<MarketEvent<Instrument, DataKind>> {
+            if self.disconnects > 0 {
+                self.disconnects -= 1;
+                return Feed::Disconnected;
+            }
+
+            self.markets.next().map_or(Feed::Finished, Feed::Next)
+        }
+    }
+
+    fn flaky_trader(
+        disconnects: usize,
+        reconnect_policy: ReconnectPolicy,
+        command_rx: mpsc::Receiver<Command>,
+        event_tx: mpsc::UnboundedSender<Event>,
+    ) -> Trader<EventTx, TradingSummary, TestPortfolio, FlakyFeed, MockStrategy, SimulatedExecution>
+    {
+        let engine_id = Uuid::new_v4();
+
+        Trader::builder()
+            .engine_id(engine_id)
+            .market(test_market())
+            .command_rx(command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(test_portfolio(engine_id))
+            .data(FlakyFeed {
+                disconnects,
+                markets: vec![market_event_trade(Side::Buy)].into_iter(),
+            })
+            .strategy(MockStrategy::new(Decision::Long))
+            .execution(SimulatedExecution::new(ExecutionConfig {
+                simulated_fees_pct: Fees::default(),
+            }))
+            .reconnect_policy(reconnect_policy)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn should_reconnect_disconnected_feed_according_to_reconnect_policy() {
+        let policy = ReconnectPolicy::FixedDelay {
+            attempts: 2,
+            delay_ms: 1,
+        };
+
+        let (_command_tx, command_rx) = mpsc::channel(10);
+
+        // Feed fails on the first poll & succeeds on the reconnection attempt
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let mut trader = flaky_trader(1, policy, command_rx, event_tx);
+        while count_market_events(&mut event_rx) == 0 {
+            assert!(trader.step());
+        }
+
+        // Feed remains disconnected beyond the permitted reconnection attempts
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let mut trader = flaky_trader(3, policy, command_rx, event_tx);
+        while trader.step() {}
+        assert_eq!(count_market_events(&mut event_rx), 0);
+
+        // Default NoReconnect policy stops trading immediately
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+        let mut trader = flaky_trader(1, ReconnectPolicy::default(), command_rx, event_tx);
+        assert!(!trader.step());
+    }
+
+    #[test]
+    fn should_terminate_whilst_waiting_to_reconnect_disconnected_feed() {
+        let policy = ReconnectPolicy::FixedDelay {
+            attempts: 1,
+            delay_ms: 60_000,
+        };
+
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+        let mut trader = flaky_trader(1, policy, command_rx, event_tx);
+
+        // Disconnected Feed schedules a reconnection attempt without blocking for the delay
+        let started = std::time::Instant::now();
+        assert!(trader.step());
+        assert!(trader.step());
+
+        command_tx
+            .try_send(Command::Terminate("test".to_owned()))
+            .unwrap();
+        assert!(!trader.step());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn should_stop_trading_once_exchange_is_unavailable() {
+        struct UnavailableExecution;
+
+        impl ExecutionClient for UnavailableExecution {
+            fn generate_fill(&self, _: &OrderEvent) -> Result<FillEvent, ExecutionError> {
+                Err(ExecutionError::ExchangeUnavailable)
+            }
+        }
+
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+
+        let mut trader = Trader::<_, TradingSummary, _, _, _, _>::builder()
+            .engine_id(engine_id)
+            .market(test_market())
+            .command_rx(command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(Arc::clone(&portfolio))
+            .data(historical::MarketFeed::new(vec![
+                market_event_trade(Side::Buy),
+                market_event_trade(Side::Buy),
+            ]))
+            .strategy(MockStrategy::new(Decision::Long))
+            .execution(UnavailableExecution)
+            .build()
+            .unwrap();
+
+        // Trader stops trading once the OrderEvent cannot be executed
+        assert!(!trader.step());
+
+        let fills = std::iter::from_fn(|| event_rx.try_recv().ok())
+            .filter(|event| matches!(event, Event::Fill(_)))
+            .count();
+        assert_eq!(fills, 0);
+        assert!(portfolio
+            .lock()
+            .get_open_positions(engine_id, [test_market()].iter())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn should_retry_rate_limited_order_after_backing_off() {
+        /// Rate limits the first OrderEvent, then fills every OrderEvent.
+        struct RateLimitedExecution {
+            attempts: std::cell::Cell<usize>,
+            inner: SimulatedExecution,
+        }
+
+        impl ExecutionClient for RateLimitedExecution {
+            fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
+                self.attempts.set(self.attempts.get() + 1);
+                match self.attempts.get() {
+                    1 => Err(ExecutionError::ExchangeRateLimited { retry_after_ms: 20 }),
+                    _ => self.inner.generate_fill(order),
+                }
+            }
+        }
+
+        let engine_id = Uuid::new_v4();
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+
+        let mut trader = Trader::<_, TradingSummary, _, _, _, _>::builder()
+            .engine_id(engine_id)
+            .market(test_market())
+            .command_rx(command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(test_portfolio(engine_id))
+            .data(historical::MarketFeed::new(vec![market_event_trade(
+                Side::Buy,
+            )]))
+            .strategy(MockStrategy::new(Decision::Long))
+            .execution(RateLimitedExecution {
+                attempts: std::cell::Cell::new(0),
+                inner: SimulatedExecution::new(ExecutionConfig {
+                    simulated_fees_pct: Fees::default(),
+                }),
+            })
+            .build()
+            .unwrap();
+
+        // Rate limited OrderEvent does not stop trading, & is retried once the back off elapses
+        let started = std::time::Instant::now();
+        assert!(trader.step());
+        while trader.execution.attempts.get() < 2 {
+            assert!(trader.step());
+        }
+        assert!(started.elapsed() >= Duration::from_millis(20));
+
+        let fills = std::iter::from_fn(|| event_rx.try_recv().ok())
+            .filter(|event| matches!(event, Event::Fill(_)))
+            .count();
+        assert_eq!(fills, 1);
+    }
+
+    #[test]
+    fn should_notify_strategy_of_orders_rejected_by_execution() {
+        /// Rejects every OrderEvent with the provided reason, or fills it if there is none.
+        struct RejectingExecution {
+            reason: Option<FillRejectedReason>,
+            inner: SimulatedExecution,
+        }
+
+        impl ExecutionClient for RejectingExecution {
+            fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
+                match self.reason {
+                    Some(reason) => Err(ExecutionError::Rejected(reason)),
+                    None => self.inner.generate_fill(order),
+                }
+            }
+        }
+
+        let cases = [
+            Some(FillRejectedReason::TifExpired),
+            Some(FillRejectedReason::FillOrKillIncomplete),
+            None,
+        ];
+
+        for reason in cases {
+            let engine_id = Uuid::new_v4();
+            let portfolio = test_portfolio(engine_id);
+            let (_command_tx, command_rx) = mpsc::channel(10);
+            let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+
+            let mut trader = Trader::<_, TradingSummary, _, _, _, _>::builder()
+                .engine_id(engine_id)
+                .market(test_market())
+                .command_rx(command_rx)
+                .event_tx(EventTx::new(event_tx))
+                .portfolio(Arc::clone(&portfolio))
+                .data(historical::MarketFeed::new(vec![market_event_trade(
+                    Side::Buy,
+                )]))
+                .strategy(MockStrategy::new(Decision::Long))
+                .execution(RejectingExecution {
+                    reason,
+                    inner: SimulatedExecution::new(ExecutionConfig {
+                        simulated_fees_pct: Fees::default(),
+                    }),
+                })
+                .build()
+                .unwrap();
+
+            // Rejected OrderEvents do not stop trading
+            assert!(trader.step());
+
+            let rejected = std::iter::from_fn(|| event_rx.try_recv().ok())
+                .filter_map(|event| match event {
+                    Event::FillRejected(rejected) => Some(rejected),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            let open_positions = portfolio
+                .lock()
+                .get_open_positions(engine_id, [test_market()].iter())
+                .unwrap();
+
+            assert_eq!(
+                rejected
+                    .iter()
+                    .map(|rejected| rejected.reason)
+                    .collect::<Vec<_>>(),
+                Vec::from_iter(reason),
+                "{reason:?}"
+            );
+            assert!(rejected
+                .iter()
+                .all(|rejected| rejected.order.decision == Decision::Long));
+            assert_eq!(
+                trader.strategy.rejected,
+                Vec::from_iter(reason),
+                "{reason:?}"
+            );
+            assert_eq!(open_positions.len(), usize::from(reason.is_none()));
+        }
+    }
+
+    #[test]
+    fn should_stop_trading_once_daily_loss_limit_is_breached() {
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+
+        // Fees guarantee a loss when entering & exiting at the same price
+        let mut trader: TestTrader = Trader::builder()
+            .engine_id(engine_id)
+            .market(test_market())
+            .command_rx(command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(Arc::clone(&portfolio))
+            .data(historical::MarketFeed::new(vec![
+                market_event_trade(Side::Buy),
+                market_event_trade(Side::Buy),
+                market_event_trade(Side::Buy),
+            ]))
+            .strategy(MockStrategy::new(Decision::Long))
+            .execution(SimulatedExecution::new(ExecutionConfig {
+                simulated_fees_pct: Fees {
+                    exchange: 0.01,
+                    slippage: 0.0,
+                    network: 0.0,
+                },
+            }))
+            .daily_loss_limit(DailyLossLimit::new(0.0001, 10_000.0))
+            .build()
+            .unwrap();
+
+        // Entering a Position does not breach the DailyLossLimit
+        assert!(trader.step());
+        assert!(!trader.daily_loss_limit_breached());
+
+        // Exiting the Position at a loss breaches the DailyLossLimit & stops the Trader
+        let mut trader = trader.with_strategy(MockStrategy::new(Decision::CloseLong));
+        assert!(!trader.step());
+        assert!(trader.daily_loss_limit_breached());
+
+        // Trader refuses to continue despite the Feed having remaining MarketEvents
+        assert!(!trader.step());
+    }
+
+    #[test]
+    fn should_roll_daily_loss_limit_over_on_first_market_event_of_new_day() {
+        let engine_id = Uuid::new_v4();
+        let portfolio = test_portfolio(engine_id);
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+
+        let today = market_event_trade(Side::Buy);
+        let mut next_day = market_event_trade(Side::Buy);
+        next_day.exchange_time += chrono::Duration::days(1);
+
+        let mut trader: TestTrader = Trader::builder()
+            .engine_id(engine_id)
+            .market(test_market())
+            .command_rx(command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(Arc::clone(&portfolio))
+            .data(historical::MarketFeed::new(vec![
+                today.clone(),
+                next_day.clone(),
+            ]))
+            .strategy(MockStrategy::new(Decision::CloseLong))
+            .execution(SimulatedExecution::new(ExecutionConfig {
+                simulated_fees_pct: Fees {
+                    exchange: 0.0,
+                    slippage: 0.0,
+                    network: 0.0,
+                },
+            }))
+            .daily_loss_limit(DailyLossLimit::new(0.05, 10_000.0))
+            .build()
+            .unwrap();
+
+        assert!(trader.step());
+        let limit = trader.daily_loss_limit.unwrap();
+        assert_eq!(limit.day, Some(today.exchange_time.date_naive()));
+
+        // Start-of-day equity is sampled at the roll over, without waiting for a fill
+        trader.daily_loss_limit.as_mut().unwrap().last_equity = 9_800.0;
+        assert!(trader.step());
+        let limit = trader.daily_loss_limit.unwrap();
+        assert_eq!(limit.day, Some(next_day.exchange_time.date_naive()));
+        assert_eq!(limit.start_of_day_equity, 9_800.0);
+    }
+}
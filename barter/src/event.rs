@@ -1,5 +1,5 @@
 use crate::{
-    execution::FillEvent,
+    execution::{FillEvent, FillRejected},
     portfolio::{
         position::{Position, PositionExit, PositionUpdate},
         Balance, OrderEvent,
@@ -18,6 +18,7 @@ use tracing::warn;
 /// the trading sequence. The [`PositionExit`] Event is a representation of work done by the
 /// system, and is useful for analysing performance & reconciliations.
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[allow(clippy::large_enum_variant)]
 pub enum Event {
     Market(MarketEvent<Instrument, DataKind>),
     Signal(Signal),
@@ -25,12 +26,32 @@ pub enum Event {
     OrderNew(OrderEvent),
     OrderUpdate,
     Fill(FillEvent),
+    FillRejected(FillRejected),
     PositionNew(Position),
     PositionUpdate(PositionUpdate),
     PositionExit(PositionExit),
     Balance(Balance),
 }
 
+impl Event {
+    /// Returns the name of the [`Event`] variant. Useful as a structured field when logging.
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            Event::Market(_) => "market",
+            Event::Signal(_) => "signal",
+            Event::SignalForceExit(_) => "signal_force_exit",
+            Event::OrderNew(_) => "order_new",
+            Event::OrderUpdate => "order_update",
+            Event::Fill(_) => "fill",
+            Event::FillRejected(_) => "fill_rejected",
+            Event::PositionNew(_) => "position_new",
+            Event::PositionUpdate(_) => "position_update",
+            Event::PositionExit(_) => "position_exit",
+            Event::Balance(_) => "balance",
+        }
+    }
+}
+
 /// Message transmitter for sending Barter messages to downstream consumers.
 pub trait MessageTransmitter<Message> {
     /// Attempts to send a message to an external message subscriber.
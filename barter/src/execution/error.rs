@@ -1,3 +1,4 @@
+use super::FillRejectedReason;
 use thiserror::Error;
 
 /// All errors generated in the barter::execution module.
@@ -5,4 +6,22 @@ use thiserror::Error;
 pub enum ExecutionError {
     #[error("Failed to build struct due to missing attributes: {0}")]
     BuilderIncomplete(&'static str),
+
+    /// Exchange cannot currently execute orders (eg/ connection refused or maintenance).
+    #[error("Exchange unavailable whilst executing order")]
+    ExchangeUnavailable,
+
+    /// Exchange rejected the order due to a rate limit, and it may be retried after
+    /// `retry_after_ms`.
+    #[error("Exchange rate limited whilst executing order, retry after {retry_after_ms}ms")]
+    ExchangeRateLimited { retry_after_ms: u64 },
+
+    /// Exchange did not respond to the order request within `elapsed_ms`.
+    #[error("Exchange order request timed out after {elapsed_ms}ms")]
+    RequestTimeout { elapsed_ms: u64 },
+
+    /// Order was not executed because it could not be filled within it's
+    /// [`TimeInForce`](super::TimeInForce).
+    #[error("Order rejected: {0:?}")]
+    Rejected(FillRejectedReason),
 }
@@ -1,8 +1,13 @@
-use crate::{data::MarketMeta, portfolio::OrderEvent, strategy::Decision};
+use crate::{
+    data::MarketMeta,
+    portfolio::{error::PortfolioError, OrderEvent},
+    strategy::Decision,
+};
 use barter_integration::model::{instrument::Instrument, Exchange};
 use chrono::{DateTime, Utc};
 use error::ExecutionError;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// Barter execution module specific errors.
 pub mod error;
@@ -33,6 +38,16 @@ pub struct FillEvent {
     pub fill_value_gross: f64,
     /// All fee types incurred when executing an [`OrderEvent`], and their associated [`FeeAmount`].
     pub fees: Fees,
+    /// True if the fill provided liquidity (maker), false if it took liquidity (taker).
+    #[serde(default)]
+    pub is_maker: bool,
+    /// [`TimeInForce`] of the order that generated this fill.
+    #[serde(default)]
+    pub tif: TimeInForce,
+    /// Trace UUID of this fill, propagated to the [`Position`](crate::portfolio::position::Position)
+    /// state changes it triggers.
+    #[serde(default)]
+    pub trace_id: Uuid,
 }
 
 impl FillEvent {
@@ -42,9 +57,159 @@ impl FillEvent {
     pub fn builder() -> FillEventBuilder {
         FillEventBuilder::new()
     }
+
+    /// Sets the [`Fees`] of this [`FillEvent`] by computing them from the provided
+    /// [`FeeSchedule`], using the `fill_value_gross` & `is_maker` already set.
+    pub fn with_fees_from_schedule(mut self, schedule: &dyn FeeSchedule) -> Self {
+        self.fees = schedule.compute(self.fill_value_gross, self.is_maker);
+        self
+    }
+
+    /// Returns the per unit price improvement captured by a maker fill that provided liquidity,
+    /// ie/ half of the provided bid-ask `market_spread`. Returns `None` for a taker fill, which
+    /// pays the spread rather than capturing it.
+    pub fn effective_spread_captured(&self, market_spread: f64) -> Option<f64> {
+        self.is_maker.then_some(market_spread / 2.0)
+    }
+
+    /// Calculates the all-in cost of this [`FillEvent`] for Transaction Cost Analysis (TCA): the
+    /// total [`Fees`] (including modelled slippage), less the value of any spread captured by a
+    /// maker fill. A negative cost is a net credit.
+    pub fn total_transaction_cost(&self, market_spread: f64) -> f64 {
+        let spread_captured_value = self
+            .effective_spread_captured(market_spread)
+            .map_or(0.0, |per_unit| per_unit * self.quantity.abs());
+
+        self.fees.calculate_total_fees() - spread_captured_value
+    }
+
+    /// Determines if a [`TimeInForce::GoodTillDate`] [`FillEvent`] has expired at the provided
+    /// time. Every other [`TimeInForce`] never expires.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.tif.is_expired(now)
+    }
+}
+
+/// Time-in-force of an order, determining how long it remains active before it is cancelled.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub enum TimeInForce {
+    /// Remains active until filled or explicitly cancelled.
+    #[default]
+    GoodTillCancelled,
+    /// Fills as much as possible immediately, cancelling any unfilled remainder.
+    ImmediateOrCancel,
+    /// Fills the entire quantity immediately, or is cancelled.
+    FillOrKill,
+    /// Remains active until filled, cancelled, or the provided expiry time.
+    GoodTillDate(DateTime<Utc>),
+}
+
+impl TimeInForce {
+    /// Determines if a [`TimeInForce::GoodTillDate`] has expired at the provided time. Every
+    /// other [`TimeInForce`] never expires.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        match self {
+            TimeInForce::GoodTillDate(expiry) => now > *expiry,
+            _ => false,
+        }
+    }
+
+    /// Determines if filling `fill_quantity` of an [`OrderEvent`] at the provided time would
+    /// violate this [`TimeInForce`], returning the [`FillRejectedReason`] if so. Used by
+    /// [`ExecutionClient`]s before executing an order:
+    ///  - [`TimeInForce::GoodTillDate`]: the fill would occur after the expiry.
+    ///  - [`TimeInForce::FillOrKill`]: the fill quantity is less than the order quantity.
+    pub fn fill_rejection(
+        &self,
+        order: &OrderEvent,
+        time: DateTime<Utc>,
+        fill_quantity: f64,
+    ) -> Option<FillRejectedReason> {
+        match self {
+            TimeInForce::GoodTillDate(_) if self.is_expired(time) => {
+                Some(FillRejectedReason::TifExpired)
+            }
+            TimeInForce::FillOrKill if fill_quantity.abs() < order.quantity.abs() => {
+                Some(FillRejectedReason::FillOrKillIncomplete)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// [`OrderEvent`] the [`ExecutionClient`] rejected rather than filling, returned via
+/// [`ExecutionError::Rejected`]. No trade was executed, so the Portfolio is left unchanged.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FillRejected {
+    pub order: OrderEvent,
+    pub reason: FillRejectedReason,
+}
+
+/// Reason an [`OrderEvent`] could not be filled. See [`TimeInForce::fill_rejection`].
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum FillRejectedReason {
+    /// The [`TimeInForce::GoodTillDate`] expired before the order was filled.
+    TifExpired,
+    /// The [`TimeInForce::FillOrKill`] order was only partially filled.
+    FillOrKillIncomplete,
+}
+
+/// Computes the [`Fees`] incurred by a fill.
+pub trait FeeSchedule {
+    /// Return the [`Fees`] incurred by a fill of the provided gross value, depending on whether
+    /// the fill provided (maker) or took (taker) liquidity.
+    fn compute(&self, fill_value_gross: f64, is_maker: bool) -> Fees;
+}
+
+/// Interprets each [`FeeAmount`] as a percentage of the gross fill value in decimal form
+/// (eg/ 0.01 for 1%), as used by the [`SimulatedExecution`](simulated::SimulatedExecution)
+/// [`Config`](simulated::Config). Maker & taker fills incur the same [`Fees`].
+impl FeeSchedule for Fees {
+    fn compute(&self, fill_value_gross: f64, _is_maker: bool) -> Fees {
+        Fees {
+            exchange: self.exchange * fill_value_gross,
+            slippage: self.slippage * fill_value_gross,
+            network: self.network * fill_value_gross,
+        }
+    }
+}
+
+/// [`FeeSchedule`] charging an exchange fee percentage of the gross fill value, where the
+/// percentage depends on whether the fill was a maker or taker.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct MakerTakerFeeSchedule {
+    /// Maker fee percentage in decimal form (eg/ 0.001 for 0.1%).
+    pub maker_pct: f64,
+    /// Taker fee percentage in decimal form (eg/ 0.001 for 0.1%).
+    pub taker_pct: f64,
+}
+
+impl FeeSchedule for MakerTakerFeeSchedule {
+    fn compute(&self, fill_value_gross: f64, is_maker: bool) -> Fees {
+        let fee_pct = if is_maker {
+            self.maker_pct
+        } else {
+            self.taker_pct
+        };
+
+        Fees {
+            exchange: fee_pct * fill_value_gross,
+            ..Fees::default()
+        }
+    }
 }
 
 /// All potential fees incurred by a [`FillEvent`].
+///
+/// The components have different semantics:
+///  - `exchange` & `network` fees are fixed by the venue's fee schedule & network conditions,
+///    and are largely independent of how the order interacts with the market.
+///  - `slippage` is variable, depending on the order size relative to the available liquidity &
+///    the market conditions at the time of the fill.
+///
+/// Slippage is therefore not always the largest component: small orders in liquid markets are
+/// often dominated by the exchange fee. See [`Fees::max_fee_component`] &
+/// [`Fees::categorize_fees`].
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 pub struct Fees {
     /// Fee taken by the exchange/broker (eg/ commission).
@@ -55,16 +220,147 @@ pub struct Fees {
     pub network: FeeAmount,
 }
 
+/// Breakdown of [`Fees`] into fixed (exchange & network) and variable (slippage) costs.
+/// Generated via [`Fees::categorize_fees`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct FeeCategoryBreakdown {
+    /// Fees fixed by the venue's fee schedule & network conditions (exchange + network).
+    pub fixed: FeeAmount,
+    /// Fees that vary with order size & market conditions (slippage).
+    pub variable: FeeAmount,
+    /// Sum of the fixed & variable fees.
+    pub total: FeeAmount,
+}
+
 impl Fees {
+    /// Constructs [`Fees`] where every [`FeeAmount`] is zero.
+    pub fn zero() -> Fees {
+        Fees {
+            exchange: 0.0,
+            slippage: 0.0,
+            network: 0.0,
+        }
+    }
+
+    /// Constructs typical spot market [`Fees`] for a fill of the provided gross value: a 10 bps
+    /// exchange taker fee & 5 bps of slippage (Binance-like).
+    pub fn typical_spot(fill_value_gross: f64) -> Fees {
+        Fees::from_bps(fill_value_gross, 10.0, 5.0, 0.0)
+    }
+
+    /// Constructs typical futures market [`Fees`] for a fill of the provided gross value: a 5 bps
+    /// exchange taker fee & 5 bps of slippage (Binance-like). Funding payments are not a
+    /// component of [`Fees`], and so are not included.
+    pub fn typical_futures(fill_value_gross: f64) -> Fees {
+        Fees::from_bps(fill_value_gross, 5.0, 5.0, 0.0)
+    }
+
+    /// Constructs [`Fees`] for a fill of the provided gross value, using fee rates quoted in
+    /// basis points (eg/ 10.0 for Binance spot taker = 0.1%).
+    pub fn from_bps(
+        fill_value_gross: f64,
+        exchange_bps: f64,
+        slippage_bps: f64,
+        network_bps: f64,
+    ) -> Fees {
+        let from_bps = |bps: f64| fill_value_gross * bps / BASIS_POINTS_PER_UNIT;
+
+        Fees {
+            exchange: from_bps(exchange_bps),
+            slippage: from_bps(slippage_bps),
+            network: from_bps(network_bps),
+        }
+    }
+
     /// Calculates the sum of every [FeeAmount] in [Fees].
     pub fn calculate_total_fees(&self) -> f64 {
         self.exchange + self.network + self.slippage
     }
+
+    /// Calculates the total fees as a basis point rate of the provided gross fill value. Returns
+    /// 0.0 if the gross fill value is zero.
+    pub fn total_bps(&self, fill_value_gross: f64) -> f64 {
+        if fill_value_gross == 0.0 {
+            return 0.0;
+        }
+
+        self.calculate_total_fees() / fill_value_gross * BASIS_POINTS_PER_UNIT
+    }
+
+    /// Splits every [`FeeAmount`] in [`Fees`] proportionally, returning `(self * fraction,
+    /// self * (1.0 - fraction))`. Useful for attributing entry fees to the exited portion of a
+    /// partially closed position.
+    ///
+    /// Returns [`PortfolioError::InvalidQuantity`] if the fraction is not within (0.0, 1.0).
+    pub fn proportional_split(&self, fraction: f64) -> Result<(Fees, Fees), PortfolioError> {
+        if !(fraction > 0.0 && fraction < 1.0) {
+            return Err(PortfolioError::InvalidQuantity(fraction));
+        }
+
+        let remainder = 1.0 - fraction;
+        Ok((
+            Fees {
+                exchange: self.exchange * fraction,
+                slippage: self.slippage * fraction,
+                network: self.network * fraction,
+            },
+            Fees {
+                exchange: self.exchange * remainder,
+                slippage: self.slippage * remainder,
+                network: self.network * remainder,
+            },
+        ))
+    }
+
+    /// Returns the name & [`FeeAmount`] of every fee component, sorted descending by amount.
+    /// Ties retain the order exchange, slippage, network.
+    pub fn as_sorted_components(&self) -> [(&'static str, FeeAmount); 3] {
+        let mut components = [
+            ("exchange", self.exchange),
+            ("slippage", self.slippage),
+            ("network", self.network),
+        ];
+        components.sort_by(|a, b| b.1.total_cmp(&a.1));
+        components
+    }
+
+    /// Returns the name & [`FeeAmount`] of the largest fee component, identifying the dominant
+    /// execution cost. See [`Fees::as_sorted_components`] for tie-breaking.
+    pub fn max_fee_component(&self) -> (&'static str, FeeAmount) {
+        self.as_sorted_components()[0]
+    }
+
+    /// Returns the name of the fee component with the largest impact. See
+    /// [`Fees::max_fee_component`].
+    pub fn maximum_impact_fee_type(&self) -> &'static str {
+        self.max_fee_component().0
+    }
+
+    /// Classifies the exchange & network fees as fixed, and the slippage as variable.
+    pub fn categorize_fees(&self) -> FeeCategoryBreakdown {
+        let fixed = self.exchange + self.network;
+        let variable = self.slippage;
+
+        FeeCategoryBreakdown {
+            fixed,
+            variable,
+            total: fixed + variable,
+        }
+    }
+
+    /// Returns the name & [`FeeAmount`] of the smallest fee component. See
+    /// [`Fees::as_sorted_components`] for tie-breaking.
+    pub fn min_fee_component(&self) -> (&'static str, FeeAmount) {
+        self.as_sorted_components()[2]
+    }
 }
 
 /// Communicative type alias for Fee amount as f64.
 pub type FeeAmount = f64;
 
+/// Number of basis points in a unit rate (ie/ 100%).
+const BASIS_POINTS_PER_UNIT: f64 = 10_000.0;
+
 /// Builder to construct [FillEvent] instances.
 #[derive(Debug, Default)]
 pub struct FillEventBuilder {
@@ -76,6 +372,9 @@ pub struct FillEventBuilder {
     pub quantity: Option<f64>,
     pub fill_value_gross: Option<f64>,
     pub fees: Option<Fees>,
+    pub is_maker: Option<bool>,
+    pub tif: Option<TimeInForce>,
+    pub trace_id: Option<Uuid>,
 }
 
 impl FillEventBuilder {
@@ -139,6 +438,27 @@ impl FillEventBuilder {
         }
     }
 
+    pub fn is_maker(self, value: bool) -> Self {
+        Self {
+            is_maker: Some(value),
+            ..self
+        }
+    }
+
+    pub fn tif(self, value: TimeInForce) -> Self {
+        Self {
+            tif: Some(value),
+            ..self
+        }
+    }
+
+    pub fn trace_id(self, value: Uuid) -> Self {
+        Self {
+            trace_id: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Result<FillEvent, ExecutionError> {
         Ok(FillEvent {
             time: self.time.ok_or(ExecutionError::BuilderIncomplete("time"))?,
@@ -161,6 +481,222 @@ impl FillEventBuilder {
                 .fill_value_gross
                 .ok_or(ExecutionError::BuilderIncomplete("fill_value_gross"))?,
             fees: self.fees.ok_or(ExecutionError::BuilderIncomplete("fees"))?,
+            is_maker: self.is_maker.unwrap_or_default(),
+            tif: self.tif.unwrap_or_default(),
+            trace_id: self.trace_id.unwrap_or_else(Uuid::new_v4),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::fill_event;
+
+    #[test]
+    fn proportional_split_halves_sum_to_original_fees() {
+        let fees = Fees {
+            exchange: 10.0,
+            slippage: 3.0,
+            network: 0.5,
+        };
+
+        for fraction in [0.1, 0.25, 0.5, 0.9] {
+            let (exited, remaining) = fees.proportional_split(fraction).unwrap();
+
+            assert!((exited.exchange + remaining.exchange - fees.exchange).abs() < 1e-12);
+            assert!((exited.slippage + remaining.slippage - fees.slippage).abs() < 1e-12);
+            assert!((exited.network + remaining.network - fees.network).abs() < 1e-12);
+            assert!((exited.exchange - fees.exchange * fraction).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn with_fees_from_schedule_uses_maker_or_taker_fee_pct() {
+        let schedule = MakerTakerFeeSchedule {
+            maker_pct: 0.001,
+            taker_pct: 0.002,
+        };
+
+        let mut fill = fill_event();
+        fill.fill_value_gross = 1000.0;
+
+        let taker = fill.clone().with_fees_from_schedule(&schedule);
+        assert_eq!(
+            taker.fees,
+            Fees {
+                exchange: 2.0,
+                slippage: 0.0,
+                network: 0.0,
+            }
+        );
+
+        fill.is_maker = true;
+        let maker = fill.with_fees_from_schedule(&schedule);
+        assert_eq!(maker.fees.calculate_total_fees(), 1.0);
+    }
+
+    #[test]
+    fn effective_spread_captured_and_total_transaction_cost() {
+        let mut fill = fill_event();
+        fill.quantity = -2.0;
+        fill.fees = Fees {
+            exchange: 1.0,
+            slippage: 0.5,
+            network: 0.0,
+        };
+
+        // Taker fills pay the spread
+        fill.is_maker = false;
+        assert_eq!(fill.effective_spread_captured(0.4), None);
+        assert_eq!(fill.total_transaction_cost(0.4), 1.5);
+
+        // Maker fills capture half the spread on every unit filled
+        fill.is_maker = true;
+        assert_eq!(fill.effective_spread_captured(0.4), Some(0.2));
+        assert!((fill.total_transaction_cost(0.4) - 1.1).abs() < 1e-12);
+        assert!((fill.total_transaction_cost(2.0) + 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn time_in_force_expiry_and_fill_rejection() {
+        let order = crate::test_util::order_event();
+        let mut fill = fill_event();
+        assert_eq!(fill.tif, TimeInForce::GoodTillCancelled);
+        assert!(!fill.is_expired(fill.time + chrono::Duration::days(365)));
+        assert_eq!(
+            fill.tif.fill_rejection(&order, fill.time, order.quantity),
+            None
+        );
+
+        // GoodTillDate expires strictly after the expiry time
+        let expiry = fill.time;
+        let after_expiry = expiry + chrono::Duration::seconds(1);
+        fill.tif = TimeInForce::GoodTillDate(expiry);
+        assert!(!fill.is_expired(expiry));
+        assert!(fill.is_expired(after_expiry));
+        assert_eq!(
+            fill.tif.fill_rejection(&order, expiry, order.quantity),
+            None
+        );
+        assert_eq!(
+            fill.tif
+                .fill_rejection(&order, after_expiry, order.quantity),
+            Some(FillRejectedReason::TifExpired)
+        );
+
+        // FillOrKill rejects partial fills, whereas ImmediateOrCancel accepts them
+        let partial = order.quantity / 2.0;
+        assert_eq!(
+            TimeInForce::FillOrKill.fill_rejection(&order, expiry, partial),
+            Some(FillRejectedReason::FillOrKillIncomplete)
+        );
+        assert_eq!(
+            TimeInForce::ImmediateOrCancel.fill_rejection(&order, expiry, partial),
+            None
+        );
+        assert_eq!(
+            TimeInForce::FillOrKill.fill_rejection(&order, expiry, order.quantity),
+            None
+        );
+    }
+
+    #[test]
+    fn fees_from_bps_and_total_bps() {
+        // Binance spot taker = 10 bps
+        let fees = Fees::from_bps(2000.0, 10.0, 5.0, 0.0);
+
+        assert_eq!(
+            fees,
+            Fees {
+                exchange: 2.0,
+                slippage: 1.0,
+                network: 0.0,
+            }
+        );
+        assert!((fees.total_bps(2000.0) - 15.0).abs() < 1e-9);
+        assert_eq!(fees.total_bps(0.0), 0.0);
+    }
+
+    #[test]
+    fn fees_named_constructors() {
+        assert_eq!(Fees::zero().calculate_total_fees(), 0.0);
+        assert_eq!(Fees::zero(), Fees::default());
+
+        let spot = Fees::typical_spot(2000.0);
+        assert_eq!(spot, Fees::from_bps(2000.0, 10.0, 5.0, 0.0));
+        assert!((spot.total_bps(2000.0) - 15.0).abs() < 1e-9);
+
+        let futures = Fees::typical_futures(2000.0);
+        assert!(futures.exchange < spot.exchange);
+        assert!((futures.total_bps(2000.0) - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn proportional_split_rejects_fraction_outside_open_unit_interval() {
+        let fees = Fees::default();
+
+        for fraction in [0.0, 1.0, -0.5, 1.5, f64::NAN] {
+            assert!(matches!(
+                fees.proportional_split(fraction),
+                Err(PortfolioError::InvalidQuantity(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn fee_components_sorted_by_amount() {
+        let fees = Fees {
+            exchange: 1.0,
+            slippage: 5.0,
+            network: 0.5,
+        };
+
+        assert_eq!(fees.max_fee_component(), ("slippage", 5.0));
+        assert_eq!(fees.min_fee_component(), ("network", 0.5));
+        assert_eq!(
+            fees.as_sorted_components(),
+            [("slippage", 5.0), ("exchange", 1.0), ("network", 0.5)]
+        );
+    }
+
+    #[test]
+    fn fee_components_with_equal_amounts_are_deterministic() {
+        let fees = Fees {
+            exchange: 2.0,
+            slippage: 2.0,
+            network: 2.0,
+        };
+
+        assert_eq!(fees.max_fee_component(), ("exchange", 2.0));
+        assert_eq!(fees.min_fee_component(), ("network", 2.0));
+        assert_eq!(
+            fees.as_sorted_components(),
+            [("exchange", 2.0), ("slippage", 2.0), ("network", 2.0)]
+        );
+    }
+
+    #[test]
+    fn categorize_fees_sums_to_total_fees() {
+        let fees = Fees {
+            exchange: 1.5,
+            slippage: 0.25,
+            network: 0.75,
+        };
+
+        let breakdown = fees.categorize_fees();
+        assert_eq!(
+            breakdown,
+            FeeCategoryBreakdown {
+                fixed: 2.25,
+                variable: 0.25,
+                total: 2.5,
+            }
+        );
+        assert_eq!(breakdown.fixed + breakdown.variable, breakdown.total);
+        assert_eq!(breakdown.total, fees.calculate_total_fees());
+
+        // Slippage is not the largest component of this small fill
+        assert_eq!(fees.maximum_impact_fee_type(), "exchange");
+    }
+}
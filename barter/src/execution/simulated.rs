@@ -1,8 +1,9 @@
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::{
-    execution::{error::ExecutionError, ExecutionClient, Fees, FillEvent},
+    execution::{error::ExecutionError, ExecutionClient, FeeSchedule, Fees, FillEvent},
     portfolio::OrderEvent,
 };
 
@@ -15,18 +16,33 @@ pub struct Config {
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 /// Simulated execution handler that executes [`OrderEvent`]s to generate [`FillEvent`]s via a
-/// simulated broker interaction.
-pub struct SimulatedExecution {
-    fees_pct: Fees,
+/// simulated broker interaction. Fill [`Fees`] are computed using the provided [`FeeSchedule`],
+/// which defaults to simulated [`Fees`] percentages. Fills are priced at the market price, moved
+/// against the order by the configured slippage percentage.
+pub struct SimulatedExecution<Schedule = Fees>
+where
+    Schedule: FeeSchedule,
+{
+    fee_schedule: Schedule,
+    #[serde(default)]
+    slippage_pct: f64,
 }
 
-impl ExecutionClient for SimulatedExecution {
+impl<Schedule> ExecutionClient for SimulatedExecution<Schedule>
+where
+    Schedule: FeeSchedule,
+{
     fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
-        // Assume (for now) that all orders are filled at the market price
-        let fill_value_gross = SimulatedExecution::calculate_fill_value_gross(order);
+        // Assume (for now) that all orders are fully filled at the market price
+        let time = Utc::now();
+        if let Some(reason) = order.tif.fill_rejection(order, time, order.quantity) {
+            return Err(ExecutionError::Rejected(reason));
+        }
+
+        let fill_value_gross = self.calculate_fill_value_gross(order);
 
         Ok(FillEvent {
-            time: Utc::now(),
+            time,
             exchange: order.exchange.clone(),
             instrument: order.instrument.clone(),
             market_meta: order.market_meta,
@@ -34,6 +50,9 @@ impl ExecutionClient for SimulatedExecution {
             quantity: order.quantity,
             fill_value_gross,
             fees: self.calculate_fees(&fill_value_gross),
+            is_maker: false,
+            tif: order.tif,
+            trace_id: Uuid::new_v4(),
         })
     }
 }
@@ -42,29 +61,56 @@ impl SimulatedExecution {
     /// Constructs a new [`SimulatedExecution`] component.
     pub fn new(cfg: Config) -> Self {
         Self {
-            fees_pct: cfg.simulated_fees_pct,
+            fee_schedule: cfg.simulated_fees_pct,
+            slippage_pct: 0.0,
+        }
+    }
+}
+
+impl<Schedule> SimulatedExecution<Schedule>
+where
+    Schedule: FeeSchedule,
+{
+    /// Constructs a new [`SimulatedExecution`] component that computes [`Fees`] using the
+    /// provided [`FeeSchedule`].
+    pub fn with_fee_schedule(fee_schedule: Schedule) -> Self {
+        Self {
+            fee_schedule,
+            slippage_pct: 0.0,
+        }
+    }
+
+    /// Sets the slippage percentage in decimal form (eg/ 0.001 for 0.1%) that moves the fill
+    /// price against every order - buys fill above, & sells fill below, the market price.
+    pub fn slippage_pct(self, value: f64) -> Self {
+        Self {
+            slippage_pct: value,
+            ..self
         }
     }
 
-    /// Calculates the simulated gross fill value (excluding TotalFees) based on the input [`OrderEvent`].
-    fn calculate_fill_value_gross(order: &OrderEvent) -> f64 {
-        order.quantity.abs() * order.market_meta.close
+    /// Calculates the simulated gross fill value (excluding TotalFees) based on the input
+    /// [`OrderEvent`], including any slippage.
+    fn calculate_fill_value_gross(&self, order: &OrderEvent) -> f64 {
+        let fill_price =
+            order.market_meta.close * (1.0 + self.slippage_pct * order.quantity.signum());
+        order.quantity.abs() * fill_price
     }
 
     /// Calculates the simulated [`Fees`] a [`FillEvent`] will incur, based on the input [`OrderEvent`].
     fn calculate_fees(&self, fill_value_gross: &f64) -> Fees {
-        Fees {
-            exchange: self.fees_pct.exchange * fill_value_gross,
-            slippage: self.fees_pct.slippage * fill_value_gross,
-            network: self.fees_pct.network * fill_value_gross,
-        }
+        // Simulated fills are assumed to take liquidity at the market price
+        self.fee_schedule.compute(*fill_value_gross, false)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_util::order_event;
+    use crate::{
+        execution::{FillRejectedReason, TimeInForce},
+        test_util::order_event,
+    };
 
     #[test]
     fn should_generate_ok_fill_event_with_valid_order_event_provided() {
@@ -95,13 +141,31 @@ mod tests {
         assert_eq!(actual_result.fees, expected_fees);
     }
 
+    #[test]
+    fn should_reject_order_event_with_expired_time_in_force() {
+        let simulated_execution = SimulatedExecution::new(Config::default());
+
+        let mut input_order = order_event();
+        input_order.tif = TimeInForce::GoodTillDate(Utc::now() - chrono::Duration::days(1));
+        assert!(matches!(
+            simulated_execution.generate_fill(&input_order),
+            Err(ExecutionError::Rejected(FillRejectedReason::TifExpired))
+        ));
+
+        // Unexpired OrderEvents are filled, propagating the TimeInForce to the FillEvent
+        input_order.tif = TimeInForce::GoodTillDate(Utc::now() + chrono::Duration::days(1));
+        let fill = simulated_execution.generate_fill(&input_order).unwrap();
+        assert_eq!(fill.tif, input_order.tif);
+    }
+
     #[test]
     fn should_calculate_fill_value_gross_correctly() {
         let mut input_order = order_event();
         input_order.quantity = 100.0;
         input_order.market_meta.close = 10.0;
 
-        let actual = SimulatedExecution::calculate_fill_value_gross(&input_order);
+        let actual =
+            SimulatedExecution::new(Config::default()).calculate_fill_value_gross(&input_order);
 
         let expected = 100.0 * 10.0;
 
@@ -114,13 +178,31 @@ mod tests {
         input_order.quantity = -(100.0);
         input_order.market_meta.close = 10.0;
 
-        let actual = SimulatedExecution::calculate_fill_value_gross(&input_order);
+        let actual =
+            SimulatedExecution::new(Config::default()).calculate_fill_value_gross(&input_order);
 
         let expected = (100.0 * 10.0) as f64;
 
         assert_eq!(actual, expected)
     }
 
+    #[test]
+    fn should_move_fill_price_against_the_order_by_slippage() {
+        let simulated_execution = SimulatedExecution::new(Config::default()).slippage_pct(0.01);
+
+        // Buy fills above the market price
+        let mut input_order = order_event();
+        input_order.quantity = 10.0;
+        input_order.market_meta.close = 100.0;
+        let actual = simulated_execution.calculate_fill_value_gross(&input_order);
+        assert!((actual - 10.0 * 101.0).abs() < 1e-9);
+
+        // Sell fills below the market price
+        input_order.quantity = -10.0;
+        let actual = simulated_execution.calculate_fill_value_gross(&input_order);
+        assert!((actual - 10.0 * 99.0).abs() < 1e-9);
+    }
+
     #[test]
     fn should_calculate_simulated_fees_correctly() {
         let simulated_execution = SimulatedExecution::new(Config {
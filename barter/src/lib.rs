@@ -53,7 +53,7 @@
 //!     let market_event = match data.next() {
 //!         Feed::Next(market_event) => market_event,
 //!         Feed::Finished => break,
-//!         Feed::Unhealthy => continue,
+//!         Feed::Unhealthy | Feed::Disconnected => continue,
 //!     };
 //! }
 //! ```
@@ -248,7 +248,7 @@ extern crate prettytable;
 pub mod test_util {
     use crate::{
         data::MarketMeta,
-        execution::{Fees, FillEvent},
+        execution::{Fees, FillEvent, TimeInForce},
         portfolio::{position::Position, OrderEvent, OrderType},
         strategy::{Decision, Signal},
     };
@@ -263,6 +263,7 @@ pub mod test_util {
     };
     use chrono::Utc;
     use std::ops::Add;
+    use uuid::Uuid;
 
     /// Build a [`MarketEvent`] of [`DataKind::PublicTrade`](DataKind) with the provided [`Side`].
     pub fn market_event_trade(side: Side) -> MarketEvent<Instrument, DataKind> {
@@ -321,6 +322,7 @@ pub mod test_util {
             decision: Decision::default(),
             quantity: 1.0,
             order_type: OrderType::default(),
+            tif: TimeInForce::default(),
         }
     }
 
@@ -335,6 +337,9 @@ pub mod test_util {
             quantity: 1.0,
             fill_value_gross: 100.0,
             fees: Fees::default(),
+            is_maker: false,
+            tif: TimeInForce::default(),
+            trace_id: Uuid::new_v4(),
         }
     }
 
@@ -359,6 +364,13 @@ pub mod test_util {
             current_value_gross: 100.0,
             unrealised_profit_loss: 0.0,
             realised_profit_loss: 0.0,
+            stop_loss_price: None,
+            partially_exited_quantity: 0.0,
+            total_realised_profit_loss: 0.0,
+            annual_holding_rate: 0.0,
+            received_dividends: 0.0,
+            #[cfg(feature = "funding-history")]
+            funding_rate_series: Vec::new(),
         }
     }
 }
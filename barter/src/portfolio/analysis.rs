@@ -0,0 +1,806 @@
+use crate::{
+    portfolio::position::{Position, PositionId, LONG_TERM_HOLD_PERIOD_DAYS},
+    statistic::{
+        algorithm::RunningStats,
+        metric::{
+            returns::{cagr, total_return},
+            EquityPoint,
+        },
+    },
+};
+use barter_integration::model::Side;
+use chrono::{DateTime, Duration, Utc};
+use prettytable::{format::consts::FORMAT_BOX_CHARS, Table};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Normalised trade log entry describing a closed [`Position`]. Generated via
+/// [`to_trade_record`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct TradeRecord {
+    pub position_id: PositionId,
+    pub exchange: String,
+    /// Base [`Symbol`](barter_integration::model::instrument::symbol::Symbol) of the traded
+    /// [`Instrument`](barter_integration::model::instrument::Instrument).
+    pub symbol: String,
+    pub direction: Side,
+    pub entry_time: DateTime<Utc>,
+    pub exit_time: DateTime<Utc>,
+    pub entry_price: f64,
+    pub exit_price: f64,
+    /// Absolute quantity traded.
+    pub quantity: f64,
+    /// Profit & loss before fees.
+    pub gross_pnl: f64,
+    /// Total entry & exit fees.
+    pub fees: f64,
+    /// Profit & loss after fees, equal to [`Position::realised_profit_loss`].
+    pub net_pnl: f64,
+    pub hold_duration_secs: i64,
+}
+
+impl TradeRecord {
+    /// Classifies the holding period of the trade for tax purposes. Trades entered & exited on
+    /// the same (UTC) calendar date are [`HoldingPeriodCategory::Intraday`], & trades held for
+    /// longer than [`LONG_TERM_HOLD_PERIOD_DAYS`] are [`HoldingPeriodCategory::LongTerm`].
+    pub fn holding_period_category(&self) -> HoldingPeriodCategory {
+        if self.entry_time.date_naive() == self.exit_time.date_naive() {
+            HoldingPeriodCategory::Intraday
+        } else if Duration::seconds(self.hold_duration_secs)
+            > Duration::days(LONG_TERM_HOLD_PERIOD_DAYS)
+        {
+            HoldingPeriodCategory::LongTerm
+        } else {
+            HoldingPeriodCategory::ShortTerm
+        }
+    }
+}
+
+/// Holding period classification of a [`TradeRecord`], eg/ for capital gains tax treatment.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub enum HoldingPeriodCategory {
+    Intraday,
+    ShortTerm,
+    LongTerm,
+}
+
+/// Groups the [`TradeRecord`]s provided by their [`HoldingPeriodCategory`].
+pub fn categorize_trades(
+    records: &[TradeRecord],
+) -> HashMap<HoldingPeriodCategory, Vec<&TradeRecord>> {
+    records
+        .iter()
+        .fold(HashMap::new(), |mut categories, record| {
+            categories
+                .entry(record.holding_period_category())
+                .or_insert_with(Vec::new)
+                .push(record);
+            categories
+        })
+}
+
+/// Sums the [`TradeRecord::net_pnl`] of the [`TradeRecord`]s provided within each
+/// [`HoldingPeriodCategory`].
+pub fn net_pnl_by_holding_period(records: &[TradeRecord]) -> HashMap<HoldingPeriodCategory, f64> {
+    categorize_trades(records)
+        .into_iter()
+        .map(|(category, records)| {
+            let net_pnl = records.iter().map(|record| record.net_pnl).sum();
+            (category, net_pnl)
+        })
+        .collect()
+}
+
+/// Converts a closed [`Position`] into a [`TradeRecord`]. Returns `None` if the [`Position`] is
+/// still open.
+pub fn to_trade_record(position: &Position) -> Option<TradeRecord> {
+    let hold_period = position.hold_period()?;
+    let fees = position.enter_fees_total + position.exit_fees_total;
+
+    Some(TradeRecord {
+        position_id: position.position_id.clone(),
+        exchange: position.exchange.to_string(),
+        symbol: position.instrument.base.to_string(),
+        direction: position.side,
+        entry_time: position.meta.enter_time,
+        exit_time: position.meta.update_time,
+        entry_price: position.enter_avg_price_gross,
+        exit_price: position.exit_avg_price_gross,
+        quantity: position.quantity.abs(),
+        gross_pnl: position.realised_profit_loss + fees,
+        fees,
+        net_pnl: position.realised_profit_loss,
+        hold_duration_secs: hold_period.num_seconds(),
+    })
+}
+
+/// Returns the [`Position::hold_period`] of every closed [`Position`] in the input slice.
+fn closed_trade_durations(positions: &[Position]) -> Vec<Duration> {
+    positions.iter().filter_map(Position::hold_period).collect()
+}
+
+/// Calculates the mean hold [`Duration`] of the closed [`Position`]s provided. Returns `None` if
+/// there are no closed [`Position`]s.
+pub fn average_trade_duration(positions: &[Position]) -> Option<Duration> {
+    let durations = closed_trade_durations(positions);
+
+    if durations.is_empty() {
+        return None;
+    }
+
+    let total_millis = durations
+        .iter()
+        .map(Duration::num_milliseconds)
+        .sum::<i64>();
+
+    Some(Duration::milliseconds(
+        total_millis / durations.len() as i64,
+    ))
+}
+
+/// Calculates the median hold [`Duration`] of the closed [`Position`]s provided. Returns `None`
+/// if there are no closed [`Position`]s.
+pub fn median_trade_duration(positions: &[Position]) -> Option<Duration> {
+    let mut durations = closed_trade_durations(positions);
+
+    if durations.is_empty() {
+        return None;
+    }
+
+    durations.sort();
+
+    let middle = durations.len() / 2;
+    match durations.len() % 2 {
+        0 => Some((durations[middle - 1] + durations[middle]) / 2),
+        _ => Some(durations[middle]),
+    }
+}
+
+/// Groups the [`Position`]s provided by the [`Exchange`](barter_integration::model::Exchange)
+/// they are held on.
+pub fn positions_by_exchange(positions: &[Position]) -> HashMap<&str, Vec<&Position>> {
+    group_positions_by(positions, |position| position.exchange.as_ref())
+}
+
+/// Groups the [`Position`]s provided by the base [`Symbol`](barter_integration::model::instrument::symbol::Symbol)
+/// of their [`Instrument`](barter_integration::model::instrument::Instrument).
+pub fn positions_by_symbol(positions: &[Position]) -> HashMap<&str, Vec<&Position>> {
+    group_positions_by(positions, |position| position.instrument.base.as_ref())
+}
+
+/// Finds the open [`Position`] held on the provided exchange with the provided base
+/// [`Symbol`](barter_integration::model::instrument::symbol::Symbol), if any.
+pub fn open_position_for<'a>(
+    positions: &'a [Position],
+    exchange: &str,
+    symbol: &str,
+) -> Option<&'a Position> {
+    positions.iter().find(|position| {
+        position.meta.exit_balance.is_none()
+            && position.exchange.as_ref() == exchange
+            && position.instrument.base.as_ref() == symbol
+    })
+}
+
+/// Determines if there is an open [`Position`] held on the provided exchange with the provided
+/// base [`Symbol`](barter_integration::model::instrument::symbol::Symbol).
+pub fn has_open_position(positions: &[Position], exchange: &str, symbol: &str) -> bool {
+    open_position_for(positions, exchange, symbol).is_some()
+}
+
+/// Partitions the [`Position`]s provided by direction, returning `(longs, shorts)`.
+pub fn positions_by_direction(positions: &[Position]) -> (Vec<&Position>, Vec<&Position>) {
+    positions
+        .iter()
+        .partition(|position| position.side == Side::Buy)
+}
+
+/// Sorts the [`Position`]s chronologically by the time they were entered. Positions entered at
+/// the same time retain their relative order.
+pub fn sort_by_entry(positions: &mut [Position]) {
+    positions.sort_by_key(|position| position.meta.enter_time);
+}
+
+/// Sorts the [`Position`]s chronologically by the time they were exited, placing any open
+/// [`Position`]s at the end. Positions exited at the same time, and open [`Position`]s, retain
+/// their relative order.
+pub fn sort_by_exit(positions: &mut [Position]) {
+    positions.sort_by_key(|position| (position.exit_time().is_none(), position.exit_time()));
+}
+
+/// Calculates the gross exposure of the [`Position`]s provided, ie/ the sum of every
+/// [`Position::current_value_gross`] regardless of direction. Returns 0.0 if there are none.
+pub fn gross_exposure(positions: &[&Position]) -> f64 {
+    positions
+        .iter()
+        .map(|position| position.current_value_gross)
+        .sum()
+}
+
+/// Calculates the net exposure of the [`Position`]s provided, ie/ long exposure minus short
+/// exposure. Returns 0.0 if there are none.
+pub fn net_exposure(positions: &[&Position]) -> f64 {
+    positions
+        .iter()
+        .map(|position| position.net_exposure())
+        .sum()
+}
+
+/// Calculates the leverage ratio of the [`Position`]s provided, ie/ [`gross_exposure`] divided by
+/// the total equity. Returns 0.0 if there are no [`Position`]s.
+pub fn leverage_ratio(positions: &[&Position], total_equity: f64) -> f64 {
+    if positions.is_empty() {
+        return 0.0;
+    }
+
+    gross_exposure(positions) / total_equity
+}
+
+/// Calculates the Kelly criterion fraction of capital to risk per trade from the closed
+/// [`Position`]s provided - `win_rate - (1.0 - win_rate) / avg_win_loss_ratio`, clamped to
+/// `[0.0, 1.0]`. Returns 0.0 if there are no closed [`Position`]s, or none of them are winners.
+///
+/// Note that the Kelly criterion is backward-looking, and assumes the historical win rate &
+/// win/loss ratio are stationary.
+///
+/// See documentation: <https://www.investopedia.com/terms/k/kellycriterion.asp>
+pub fn kelly_fraction(positions: &[Position]) -> f64 {
+    let closed_pnls = closed_realised_pnls(positions);
+
+    let wins = closed_pnls
+        .iter()
+        .filter(|pnl| **pnl > 0.0)
+        .collect::<Vec<_>>();
+    let losses = closed_pnls
+        .iter()
+        .filter(|pnl| **pnl < 0.0)
+        .collect::<Vec<_>>();
+
+    if wins.is_empty() {
+        return 0.0;
+    }
+
+    let win_rate = wins.len() as f64 / closed_pnls.len() as f64;
+    let avg_win = wins.iter().copied().sum::<f64>() / wins.len() as f64;
+    let avg_loss = match losses.is_empty() {
+        true => 0.0,
+        false => losses.iter().map(|loss| loss.abs()).sum::<f64>() / losses.len() as f64,
+    };
+
+    // No losses yields an infinite win/loss ratio, so the Kelly fraction is the win rate
+    let avg_win_loss_ratio = avg_win / avg_loss;
+
+    (win_rate - (1.0 - win_rate) / avg_win_loss_ratio).clamp(0.0, 1.0)
+}
+
+/// Calculates half of the [`kelly_fraction`], a commonly recommended safer allocation that
+/// reduces volatility at the expense of some expected growth.
+pub fn half_kelly_fraction(positions: &[Position]) -> f64 {
+    kelly_fraction(positions) / 2.0
+}
+
+/// Calculates the capital efficiency of the closed [`Position`]s provided, ie/ how well the
+/// deployed capital worked whilst it was deployed -
+/// `total_realised_pnl / sum(enter_value_gross * hold_fraction)`, where the hold_fraction is the
+/// fraction of the backtest duration each [`Position`] was open for.
+///
+/// Returns `None` if there are no closed [`Position`]s, the backtest duration is not positive, or
+/// no capital was deployed.
+pub fn capital_efficiency(
+    closed_positions: &[Position],
+    backtest_duration: Duration,
+) -> Option<f64> {
+    if backtest_duration <= Duration::zero() {
+        return None;
+    }
+
+    let (total_pnl, time_weighted_capital) = closed_positions
+        .iter()
+        .filter_map(|position| {
+            let hold_fraction = position.hold_period()?.num_milliseconds() as f64
+                / backtest_duration.num_milliseconds() as f64;
+
+            Some((
+                position.realised_profit_loss,
+                position.enter_value_gross * hold_fraction,
+            ))
+        })
+        .fold((0.0, 0.0), |(pnl, capital), (next_pnl, next_capital)| {
+            (pnl + next_pnl, capital + next_capital)
+        });
+
+    (time_weighted_capital != 0.0).then(|| total_pnl / time_weighted_capital)
+}
+
+/// Calculates the fraction of closed [`Position`]s provided with a positive realised P&L.
+/// Returns `None` if there are no closed [`Position`]s.
+pub fn win_rate(positions: &[Position]) -> Option<f64> {
+    let closed_pnls = closed_realised_pnls(positions);
+
+    if closed_pnls.is_empty() {
+        return None;
+    }
+
+    let wins = closed_pnls.iter().filter(|pnl| **pnl > 0.0).count();
+
+    Some(wins as f64 / closed_pnls.len() as f64)
+}
+
+/// Calculates the profit factor of the closed [`Position`]s provided, ie/ the gross profit of
+/// winning trades divided by the absolute gross loss of losing trades. Returns `None` if there
+/// are no losing closed [`Position`]s.
+pub fn profit_factor(positions: &[Position]) -> Option<f64> {
+    let (gross_profit, gross_loss) = closed_realised_pnls(positions).into_iter().fold(
+        (0.0, 0.0),
+        |(profit, loss), pnl| match pnl > 0.0 {
+            true => (profit + pnl, loss),
+            false => (profit, loss - pnl),
+        },
+    );
+
+    (gross_loss > 0.0).then(|| gross_profit / gross_loss)
+}
+
+/// Builds a [`Table`] of the headline performance metrics of a trading session, given every
+/// [`Position`] & the Portfolio equity curve:
+///  - Total return & max drawdown of the equity curve relative to the initial equity.
+///  - Sharpe & Sortino ratios of the equity curve period returns (not annualised, zero
+///    risk-free return).
+///  - Calmar ratio, ie/ the CAGR divided by the max drawdown.
+///  - [`win_rate`], [`profit_factor`] & number of closed [`Position`]s.
+///
+/// Metrics that cannot be calculated (eg/ a Sortino ratio without any negative returns) are
+/// displayed as "n/a".
+pub fn report_table(
+    positions: &[Position],
+    equity_curve: &[EquityPoint],
+    initial_equity: f64,
+) -> Table {
+    let returns = EquityPoint::to_return_series(equity_curve)
+        .into_iter()
+        .collect::<RunningStats>();
+
+    let sharpe = (returns.std_dev() > 0.0).then(|| returns.mean / returns.std_dev());
+    let sortino = EquityPoint::semi_deviation(equity_curve)
+        .filter(|semi_deviation| *semi_deviation > 0.0)
+        .map(|semi_deviation| returns.mean / semi_deviation);
+
+    let max_drawdown = EquityPoint::peak_to_trough_series(equity_curve)
+        .into_iter()
+        .fold(0.0, f64::max);
+    let calmar = cagr(equity_curve, initial_equity)
+        .filter(|_| max_drawdown > 0.0)
+        .map(|cagr| cagr / max_drawdown);
+
+    let percent = |value: Option<f64>| match value {
+        Some(value) => format!("{:.2}%", value * 100.0),
+        None => "n/a".to_owned(),
+    };
+    let ratio = |value: Option<f64>| match value {
+        Some(value) => format!("{value:.3}"),
+        None => "n/a".to_owned(),
+    };
+
+    let mut table = Table::new();
+    table.set_format(*FORMAT_BOX_CHARS);
+    table.set_titles(row!["Metric", "Value"]);
+    table.add_row(row![
+        "Total Return",
+        percent(total_return(equity_curve, initial_equity))
+    ]);
+    table.add_row(row!["Sharpe Ratio", ratio(sharpe)]);
+    table.add_row(row!["Sortino Ratio", ratio(sortino)]);
+    table.add_row(row!["Max Drawdown", percent(Some(max_drawdown))]);
+    table.add_row(row!["Calmar Ratio", ratio(calmar)]);
+    table.add_row(row!["Win Rate", percent(win_rate(positions))]);
+    table.add_row(row!["Profit Factor", ratio(profit_factor(positions))]);
+    table.add_row(row!["Trades", closed_realised_pnls(positions).len()]);
+    table
+}
+
+/// Prints the [`report_table`] of a trading session to stdout, eg/ for quick inspection in a REPL
+/// or notebook.
+pub fn print_report(positions: &[Position], equity_curve: &[EquityPoint], initial_equity: f64) {
+    report_table(positions, equity_curve, initial_equity).printstd();
+}
+
+/// Returns the [`Position::realised_profit_loss`] of every closed [`Position`] in the input slice.
+fn closed_realised_pnls(positions: &[Position]) -> Vec<f64> {
+    positions
+        .iter()
+        .filter(|position| position.meta.exit_balance.is_some())
+        .map(|position| position.realised_profit_loss)
+        .collect()
+}
+
+/// Groups the [`Position`]s provided by the `&str` key returned from the key function.
+fn group_positions_by<'a, F>(
+    positions: &'a [Position],
+    key: F,
+) -> HashMap<&'a str, Vec<&'a Position>>
+where
+    F: Fn(&'a Position) -> &'a str,
+{
+    positions
+        .iter()
+        .fold(HashMap::new(), |mut groups, position| {
+            groups.entry(key(position)).or_default().push(position);
+            groups
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{portfolio::Balance, test_util::position};
+    use barter_integration::model::{instrument::kind::InstrumentKind, Exchange};
+    use chrono::{TimeZone, Utc};
+
+    fn closed_position(hold: Duration) -> Position {
+        let mut position = position();
+        let enter_time = Utc::now();
+        position.meta.enter_time = enter_time;
+        position.meta.update_time = enter_time + hold;
+        position.meta.exit_balance = Some(Balance::default());
+        position
+    }
+
+    #[test]
+    fn average_and_median_trade_duration_of_no_closed_positions_is_none() {
+        assert_eq!(average_trade_duration(&[]), None);
+        assert_eq!(median_trade_duration(&[]), None);
+
+        // Open Position is excluded
+        let open = [position()];
+        assert_eq!(average_trade_duration(&open), None);
+        assert_eq!(median_trade_duration(&open), None);
+    }
+
+    #[test]
+    fn average_and_median_trade_duration_of_mixed_hold_times() {
+        let positions = vec![
+            closed_position(Duration::seconds(30)),
+            closed_position(Duration::seconds(90)),
+            closed_position(Duration::days(2)),
+            position(),
+        ];
+
+        // (30s + 90s + 172800s) / 3
+        assert_eq!(
+            average_trade_duration(&positions),
+            Some(Duration::seconds(57640))
+        );
+        assert_eq!(
+            median_trade_duration(&positions),
+            Some(Duration::seconds(90))
+        );
+    }
+
+    #[test]
+    fn median_trade_duration_of_even_number_of_positions_averages_middle_pair() {
+        let positions = vec![
+            closed_position(Duration::seconds(10)),
+            closed_position(Duration::days(1)),
+            closed_position(Duration::seconds(20)),
+            closed_position(Duration::days(3)),
+        ];
+
+        // (20s + 86400s) / 2
+        assert_eq!(
+            median_trade_duration(&positions),
+            Some(Duration::seconds(43210))
+        );
+    }
+
+    fn position_on(exchange: &str, base: &str, side: Side) -> Position {
+        let mut position = position();
+        position.exchange = Exchange::from(exchange.to_owned());
+        position.instrument = (base, "usdt", InstrumentKind::Spot).into();
+        position.side = side;
+        position
+    }
+
+    #[test]
+    fn sort_positions_by_entry_and_exit_time() {
+        let start = Utc::now();
+        let position_at = |id: &str, enter_days: i64, exit_days: Option<i64>| {
+            let mut position = position();
+            position.position_id = id.to_owned();
+            position.meta.enter_time = start + Duration::days(enter_days);
+            if let Some(exit_days) = exit_days {
+                position.meta.update_time = start + Duration::days(exit_days);
+                position.meta.exit_balance = Some(Balance::default());
+            }
+            position
+        };
+        let ids = |positions: &[Position]| {
+            positions
+                .iter()
+                .map(|position| position.position_id.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let mut positions = vec![
+            position_at("open", 1, None),
+            position_at("late_exit", 0, Some(5)),
+            position_at("early_exit", 2, Some(3)),
+        ];
+
+        sort_by_entry(&mut positions);
+        assert_eq!(ids(&positions), ["late_exit", "open", "early_exit"]);
+
+        sort_by_exit(&mut positions);
+        assert_eq!(ids(&positions), ["early_exit", "late_exit", "open"]);
+    }
+
+    #[test]
+    fn positions_grouped_by_exchange_symbol_and_direction() {
+        let positions = vec![
+            position_on("binance_spot", "btc", Side::Buy),
+            position_on("binance_spot", "eth", Side::Sell),
+            position_on("kraken", "btc", Side::Buy),
+        ];
+
+        let by_exchange = positions_by_exchange(&positions);
+        assert_eq!(by_exchange.len(), 2);
+        assert_eq!(by_exchange["binance_spot"].len(), 2);
+        assert_eq!(by_exchange["kraken"], vec![&positions[2]]);
+
+        let by_symbol = positions_by_symbol(&positions);
+        assert_eq!(by_symbol.len(), 2);
+        assert_eq!(by_symbol["btc"], vec![&positions[0], &positions[2]]);
+        assert_eq!(by_symbol["eth"], vec![&positions[1]]);
+
+        let (longs, shorts) = positions_by_direction(&positions);
+        assert_eq!(longs, vec![&positions[0], &positions[2]]);
+        assert_eq!(shorts, vec![&positions[1]]);
+
+        assert!(positions_by_exchange(&[]).is_empty());
+        assert_eq!(positions_by_direction(&[]), (vec![], vec![]));
+    }
+
+    #[test]
+    fn open_position_for_finds_open_position_on_exchange_with_symbol() {
+        let mut closed = position_on("binance_spot", "eth", Side::Buy);
+        closed.meta.exit_balance = Some(Balance {
+            time: Utc::now(),
+            total: 100.0,
+            available: 100.0,
+        });
+        let positions = vec![
+            position_on("binance_spot", "btc", Side::Buy),
+            position_on("kraken", "btc", Side::Sell),
+            closed,
+        ];
+
+        assert_eq!(
+            open_position_for(&positions, "kraken", "btc"),
+            Some(&positions[1])
+        );
+        assert!(has_open_position(&positions, "binance_spot", "btc"));
+
+        // Not found: closed Position, unknown symbol & unknown exchange
+        assert_eq!(open_position_for(&positions, "binance_spot", "eth"), None);
+        assert!(!has_open_position(&positions, "binance_spot", "sol"));
+        assert!(!has_open_position(&positions, "coinbase", "btc"));
+    }
+
+    #[test]
+    fn to_trade_record_of_closed_position() {
+        assert_eq!(to_trade_record(&position()), None);
+
+        let mut closed = closed_position(Duration::hours(2));
+        closed.side = Side::Sell;
+        closed.quantity = -2.0;
+        closed.enter_avg_price_gross = 100.0;
+        closed.exit_avg_price_gross = 90.0;
+        closed.enter_fees_total = 1.0;
+        closed.exit_fees_total = 0.5;
+        closed.realised_profit_loss = 18.5;
+
+        let record = to_trade_record(&closed).unwrap();
+        assert_eq!(record.exchange, "binance");
+        assert_eq!(record.symbol, "eth");
+        assert_eq!(record.direction, Side::Sell);
+        assert_eq!(record.entry_time, closed.meta.enter_time);
+        assert_eq!(record.exit_time, closed.meta.update_time);
+        assert_eq!(record.quantity, 2.0);
+        assert_eq!(record.gross_pnl, 20.0);
+        assert_eq!(record.fees, 1.5);
+        assert_eq!(record.net_pnl, 18.5);
+        assert_eq!(record.hold_duration_secs, 7200);
+    }
+
+    #[test]
+    fn holding_period_category_boundaries() {
+        let enter_time = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let record = |hold: Duration, net_pnl: f64| {
+            let mut position = closed_position(hold);
+            position.meta.enter_time = enter_time;
+            position.meta.update_time = enter_time + hold;
+            position.realised_profit_loss = net_pnl;
+            to_trade_record(&position).unwrap()
+        };
+
+        let records = vec![
+            record(Duration::zero(), 1.0),
+            record(Duration::hours(11), 2.0),
+            record(Duration::hours(13), 4.0),
+            record(Duration::days(LONG_TERM_HOLD_PERIOD_DAYS), 8.0),
+            record(
+                Duration::days(LONG_TERM_HOLD_PERIOD_DAYS) + Duration::seconds(1),
+                16.0,
+            ),
+        ];
+
+        let categories = records
+            .iter()
+            .map(TradeRecord::holding_period_category)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            categories,
+            vec![
+                HoldingPeriodCategory::Intraday,
+                HoldingPeriodCategory::Intraday,
+                HoldingPeriodCategory::ShortTerm,
+                HoldingPeriodCategory::ShortTerm,
+                HoldingPeriodCategory::LongTerm,
+            ]
+        );
+
+        let grouped = categorize_trades(&records);
+        assert_eq!(grouped[&HoldingPeriodCategory::Intraday].len(), 2);
+        assert_eq!(grouped[&HoldingPeriodCategory::ShortTerm].len(), 2);
+        assert_eq!(grouped[&HoldingPeriodCategory::LongTerm].len(), 1);
+
+        let net_pnl = net_pnl_by_holding_period(&records);
+        assert_eq!(net_pnl[&HoldingPeriodCategory::Intraday], 3.0);
+        assert_eq!(net_pnl[&HoldingPeriodCategory::ShortTerm], 12.0);
+        assert_eq!(net_pnl[&HoldingPeriodCategory::LongTerm], 16.0);
+
+        assert!(categorize_trades(&[]).is_empty());
+    }
+
+    #[test]
+    fn gross_net_exposure_and_leverage_ratio() {
+        let mut long = position_on("binance_spot", "btc", Side::Buy);
+        long.current_value_gross = 3000.0;
+        let mut short = position_on("binance_spot", "eth", Side::Sell);
+        short.current_value_gross = 1000.0;
+        let positions = [&long, &short];
+
+        assert_eq!(gross_exposure(&positions), 4000.0);
+        assert_eq!(net_exposure(&positions), 2000.0);
+        assert_eq!(leverage_ratio(&positions, 2000.0), 2.0);
+
+        assert_eq!(gross_exposure(&[]), 0.0);
+        assert_eq!(net_exposure(&[]), 0.0);
+        assert_eq!(leverage_ratio(&[], 0.0), 0.0);
+    }
+
+    #[test]
+    fn kelly_fraction_of_known_win_rate_and_win_loss_ratio() {
+        let closed_with_pnl = |pnl: f64| {
+            let mut position = closed_position(Duration::hours(1));
+            position.realised_profit_loss = pnl;
+            position
+        };
+
+        // 60% win rate with a 1.5 average win/loss ratio => 0.6 - 0.4 / 1.5
+        let mut positions = vec![
+            closed_with_pnl(15.0),
+            closed_with_pnl(15.0),
+            closed_with_pnl(15.0),
+            closed_with_pnl(-10.0),
+            closed_with_pnl(-10.0),
+        ];
+        let expected = 0.6 - 0.4 / 1.5;
+        assert!((kelly_fraction(&positions) - expected).abs() < 1e-12);
+        assert!((half_kelly_fraction(&positions) - expected / 2.0).abs() < 1e-12);
+
+        // Open Positions are ignored
+        positions.push(position());
+        assert!((kelly_fraction(&positions) - expected).abs() < 1e-12);
+
+        // Negative edge is clamped to zero
+        let losing = vec![closed_with_pnl(1.0), closed_with_pnl(-10.0)];
+        assert_eq!(kelly_fraction(&losing), 0.0);
+
+        // No losses
+        assert_eq!(kelly_fraction(&[closed_with_pnl(5.0)]), 1.0);
+
+        // Empty or no-win histories
+        assert_eq!(kelly_fraction(&[]), 0.0);
+        assert_eq!(kelly_fraction(&[closed_with_pnl(-5.0)]), 0.0);
+    }
+
+    #[test]
+    fn win_rate_and_profit_factor_of_closed_positions() {
+        let closed_with_pnl = |pnl: f64| {
+            let mut position = closed_position(Duration::hours(1));
+            position.realised_profit_loss = pnl;
+            position
+        };
+
+        let positions = vec![
+            closed_with_pnl(30.0),
+            closed_with_pnl(10.0),
+            closed_with_pnl(-20.0),
+            closed_with_pnl(-5.0),
+            position(),
+        ];
+        assert_eq!(win_rate(&positions), Some(0.5));
+        assert_eq!(profit_factor(&positions), Some(1.6));
+
+        assert_eq!(win_rate(&[position()]), None);
+        assert_eq!(profit_factor(&[closed_with_pnl(5.0)]), None);
+    }
+
+    #[test]
+    fn report_table_of_trading_session() {
+        let start = Utc::now();
+        let equity_curve = [100.0, 110.0, 99.0, 121.0]
+            .into_iter()
+            .enumerate()
+            .map(|(day, total)| EquityPoint {
+                time: start + Duration::days(day as i64),
+                total,
+            })
+            .collect::<Vec<_>>();
+
+        let mut winner = closed_position(Duration::days(1));
+        winner.realised_profit_loss = 21.0;
+        let positions = [winner, position()];
+
+        let table = report_table(&positions, &equity_curve, 100.0);
+        let value = |row: usize| {
+            table
+                .get_row(row)
+                .and_then(|row| row.get_cell(1))
+                .unwrap()
+                .get_content()
+        };
+
+        assert_eq!(table.len(), 8);
+        assert_eq!(value(0), "21.00%");
+        assert_ne!(value(1), "n/a");
+        // Single negative period return has zero semi-deviation
+        assert_eq!(value(2), "n/a");
+        assert_eq!(value(3), "10.00%");
+        assert_ne!(value(4), "n/a");
+        assert_eq!(value(5), "100.00%");
+        assert_eq!(value(6), "n/a");
+        assert_eq!(value(7), "1");
+    }
+
+    #[test]
+    fn capital_efficiency_of_position_held_for_half_the_backtest() {
+        // Exits at 2x the entry value after half of the backtest duration
+        let mut position = closed_position(Duration::days(5));
+        position.enter_value_gross = 1000.0;
+        position.exit_value_gross = 2000.0;
+        position.realised_profit_loss = 1000.0;
+
+        assert_eq!(
+            capital_efficiency(&[position.clone()], Duration::days(10)),
+            Some(2.0)
+        );
+
+        // Open Positions are ignored
+        let mut open = position.clone();
+        open.meta.exit_balance = None;
+        assert_eq!(
+            capital_efficiency(&[position, open], Duration::days(10)),
+            Some(2.0)
+        );
+    }
+
+    #[test]
+    fn capital_efficiency_of_no_closed_positions_is_none() {
+        assert_eq!(capital_efficiency(&[], Duration::days(10)), None);
+        assert_eq!(
+            capital_efficiency(&[closed_position(Duration::days(1))], Duration::zero()),
+            None
+        );
+    }
+}
@@ -0,0 +1,277 @@
+use super::{
+    error::PortfolioError,
+    position::{Position, PositionEvent, PositionId, PositionMeta},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    collections::HashMap,
+    fs::{File, OpenOptions},
+    io::Write,
+    path::Path,
+};
+
+/// Number of bytes in the little-endian length prefix of every [`PositionEventLog`] frame.
+const FRAME_LENGTH_BYTES: usize = 4;
+
+/// Serialises the value into compact binary CBOR.
+///
+/// CBOR is self-describing, so unlike bincode or postcard it supports the
+/// `#[serde(skip_serializing_if)]` & `#[serde(flatten)]` attributes used by the
+/// [`Instrument`](barter_integration::model::instrument::Instrument) model.
+fn to_bytes<T>(value: &T) -> Vec<u8>
+where
+    T: Serialize,
+{
+    let mut bytes = Vec::new();
+    ciborium::into_writer(value, &mut bytes).expect("value is always serialisable to CBOR");
+    bytes
+}
+
+/// Deserialises a value from the compact binary CBOR produced by [`to_bytes`].
+fn from_bytes<T>(bytes: &[u8]) -> Result<T, PortfolioError>
+where
+    T: DeserializeOwned,
+{
+    ciborium::from_reader(bytes).map_err(|error| PortfolioError::BinarySerialize(error.to_string()))
+}
+
+impl PositionMeta {
+    /// Serialises the [`PositionMeta`] into compact binary (CBOR).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        to_bytes(self)
+    }
+
+    /// Deserialises a [`PositionMeta`] from the compact binary produced by
+    /// [`PositionMeta::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<PositionMeta, PortfolioError> {
+        from_bytes(bytes)
+    }
+}
+
+impl Position {
+    /// Serialises the [`Position`] into compact binary (CBOR).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        to_bytes(self)
+    }
+
+    /// Deserialises a [`Position`] from the compact binary produced by [`Position::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Position, PortfolioError> {
+        from_bytes(bytes)
+    }
+}
+
+/// Append-only write-ahead log of [`PositionEvent`]s, enabling the [`Position`]s of a crashed
+/// Portfolio to be recovered via [`PositionEventLog::recover`].
+///
+/// Every [`PositionEvent`] is appended as a frame of a 4 byte little-endian length prefix
+/// followed by the CBOR encoded [`PositionEvent`], and synced to disk before returning.
+#[derive(Debug)]
+pub struct PositionEventLog {
+    file: File,
+}
+
+impl PositionEventLog {
+    /// Opens the [`PositionEventLog`] at the provided path, creating it if it does not exist.
+    /// New [`PositionEvent`]s are appended to any existing log.
+    pub fn open<P>(path: P) -> Result<Self, PortfolioError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self { file })
+    }
+
+    /// Appends the [`PositionEvent`] to the log, syncing it to disk.
+    pub fn append(&mut self, event: &PositionEvent) -> Result<(), PortfolioError> {
+        let bytes = to_bytes(event);
+        let length = u32::try_from(bytes.len()).expect("PositionEvent is smaller than 4GiB");
+
+        let mut frame = Vec::with_capacity(FRAME_LENGTH_BYTES + bytes.len());
+        frame.extend_from_slice(&length.to_le_bytes());
+        frame.extend_from_slice(&bytes);
+
+        self.file.write_all(&frame)?;
+        self.file.sync_data()?;
+        Ok(())
+    }
+
+    /// Reads every [`PositionEvent`] in the log at the provided path, in the order they were
+    /// appended. A truncated final frame (eg/ from a crash mid-append) is ignored.
+    pub fn read<P>(path: P) -> Result<Vec<PositionEvent>, PortfolioError>
+    where
+        P: AsRef<Path>,
+    {
+        let bytes = std::fs::read(path)?;
+
+        let mut events = Vec::new();
+        let mut remaining = bytes.as_slice();
+        while remaining.len() >= FRAME_LENGTH_BYTES {
+            let (length, rest) = remaining.split_at(FRAME_LENGTH_BYTES);
+            let length = u32::from_le_bytes(length.try_into().expect("4 byte length prefix"));
+
+            let length = length as usize;
+            if rest.len() < length {
+                break;
+            }
+            let (frame, rest) = rest.split_at(length);
+
+            events.push(from_bytes(frame)?);
+            remaining = rest;
+        }
+
+        Ok(events)
+    }
+
+    /// Recovers every [`Position`] in the log at the provided path by replaying it's
+    /// [`PositionEvent`]s via [`Position::replay`]. [`Position`]s are returned in the order they
+    /// were opened, and include both open & exited [`Position`]s.
+    ///
+    /// Returns [`PortfolioError::PositionReplay`] if a [`PositionEvent`] does not follow a
+    /// [`PositionEvent::Opened`] for the same [`Position`].
+    pub fn recover<P>(path: P) -> Result<Vec<Position>, PortfolioError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut histories: Vec<Vec<PositionEvent>> = Vec::new();
+        let mut open: HashMap<PositionId, usize> = HashMap::new();
+
+        for event in Self::read(path)? {
+            let position_id = match &event {
+                PositionEvent::Opened(position) => {
+                    open.insert(position.position_id.clone(), histories.len());
+                    histories.push(vec![event]);
+                    continue;
+                }
+                PositionEvent::Updated(update) => &update.position_id,
+                PositionEvent::Closed(exit) => &exit.position_id,
+            };
+
+            let history = match &event {
+                PositionEvent::Closed(_) => open.remove(position_id),
+                _ => open.get(position_id).copied(),
+            }
+            .ok_or(PortfolioError::PositionReplay(
+                "PositionEvent does not follow a PositionEvent::Opened",
+            ))?;
+
+            histories[history].push(event);
+        }
+
+        histories
+            .iter()
+            .map(|history| Position::replay(history))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        portfolio::{
+            position::{PositionExit, PositionUpdate},
+            Balance,
+        },
+        test_util::position,
+    };
+    use chrono::Utc;
+    use std::path::PathBuf;
+    use uuid::Uuid;
+
+    fn log_path() -> PathBuf {
+        std::env::temp_dir().join(format!("position_events_{}.log", Uuid::new_v4()))
+    }
+
+    #[test]
+    fn position_and_meta_round_trip_via_bytes() {
+        let mut position = position();
+        position.stop_loss_price = Some(90.0);
+
+        assert_eq!(
+            Position::from_bytes(&position.to_bytes()).unwrap(),
+            position
+        );
+        assert_eq!(
+            PositionMeta::from_bytes(&position.meta.to_bytes()).unwrap(),
+            position.meta
+        );
+
+        assert!(matches!(
+            Position::from_bytes(&[0xff, 0x00]),
+            Err(PortfolioError::BinarySerialize(_))
+        ));
+    }
+
+    #[test]
+    fn position_event_log_recovers_positions_by_replaying_events() {
+        let path = log_path();
+
+        let mut open = position();
+        open.position_id = "open".to_owned();
+        let mut closed = position();
+        closed.position_id = "closed".to_owned();
+
+        let mut log = PositionEventLog::open(&path).unwrap();
+        log.append(&PositionEvent::Opened(Box::new(closed.clone())))
+            .unwrap();
+        log.append(&PositionEvent::Opened(Box::new(open.clone())))
+            .unwrap();
+
+        open.update_with_price(120.0, Utc::now());
+        log.append(&PositionEvent::Updated(PositionUpdate::from(&mut open)))
+            .unwrap();
+
+        let exit = PositionExit {
+            position_id: closed.position_id.clone(),
+            exit_time: Utc::now(),
+            exit_balance: Balance {
+                time: Utc::now(),
+                total: 110.0,
+                available: 110.0,
+            },
+            exit_fees: Default::default(),
+            exit_fees_total: 0.0,
+            exit_avg_price_gross: 110.0,
+            exit_value_gross: 110.0,
+            realised_profit_loss: 10.0,
+            trace_id: Uuid::new_v4(),
+        };
+        log.append(&PositionEvent::Closed(exit)).unwrap();
+        drop(log);
+
+        // Simulate a crash mid-append leaving a truncated final frame
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(&[10, 0, 0, 0, 1]).unwrap();
+
+        let events = PositionEventLog::read(&path).unwrap();
+        let recovered = PositionEventLog::recover(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(events.len(), 4);
+        let recovered = recovered.unwrap();
+        assert_eq!(recovered.len(), 2);
+
+        assert_eq!(recovered[0].position_id, "closed");
+        assert_eq!(recovered[0].realised_profit_loss, 10.0);
+        assert!(recovered[0].meta.exit_balance.is_some());
+
+        assert_eq!(recovered[1].position_id, "open");
+        assert_eq!(recovered[1].current_symbol_price, 120.0);
+        assert!(recovered[1].meta.exit_balance.is_none());
+    }
+
+    #[test]
+    fn position_event_log_recover_rejects_event_without_opened() {
+        let path = log_path();
+
+        let mut position = position();
+        let mut log = PositionEventLog::open(&path).unwrap();
+        log.append(&PositionEvent::Updated(PositionUpdate::from(&mut position)))
+            .unwrap();
+
+        let recovered = PositionEventLog::recover(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(recovered, Err(PortfolioError::PositionReplay(_))));
+    }
+}
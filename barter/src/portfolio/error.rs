@@ -1,4 +1,7 @@
 use crate::portfolio::repository::error::RepositoryError;
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use std::time::Duration;
 use thiserror::Error;
 
 /// All errors generated in the barter::portfolio module.
@@ -19,6 +22,579 @@ pub enum PortfolioError {
     #[error("Cannot generate PositionExit from Position that has not been exited")]
     PositionExit,
 
+    #[error("Cannot scale into an exited Position, or with a FillEvent of the opposite Side")]
+    CannotScaleInPosition,
+
+    #[error(
+        "Position {field} is inconsistent with it's state: expected {expected}, actual {actual}"
+    )]
+    InconsistentPositionState {
+        field: &'static str,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("Invalid FillEvent quantity or value: {0}")]
+    InvalidQuantity(f64),
+
+    #[error("Invalid FillEvent fee amount: {0}")]
+    InvalidFeeAmount(f64),
+
+    #[error("Invalid symbol price {0}, expected a finite positive value")]
+    InvalidPrice(f64),
+
+    #[error("Invalid Signal strength {0}, expected a value within [0.0, 1.0]")]
+    InvalidSignalStrength(f64),
+
+    #[error("Invalid corporate action ratio or amount: {0}")]
+    InvalidCorporateAction(f64),
+
+    #[error("Invalid price adjustment factor {0}, expected a finite positive value")]
+    InvalidPriceAdjustment(f64),
+
+    #[error("Failed to replay PositionEvents: {0}")]
+    PositionReplay(&'static str),
+
+    #[error("Value {0} cannot be represented as a fixed-point integer")]
+    FixedPointOverflow(f64),
+
+    #[error("Invalid FillEvent timestamp {0} is in the future")]
+    FillTimeInFuture(DateTime<Utc>),
+
+    #[error("Invalid stop loss price {stop_price} relative to current price {current_price}")]
+    InvalidStopLoss { stop_price: f64, current_price: f64 },
+
+    #[error("Rejected stale MarketEvent at {event_ts}, Position last updated at {last_update_ts}")]
+    StaleMarketEvent {
+        event_ts: DateTime<Utc>,
+        last_update_ts: DateTime<Utc>,
+    },
+
+    #[error(
+        "Failed to liquidate {position_count} open Position(s) before timing out after {elapsed:?}"
+    )]
+    LiquidationTimeout {
+        position_count: usize,
+        elapsed: Duration,
+    },
+
+    #[error("Partially liquidated open Positions before timing out: {closed} closed, {remaining} remaining")]
+    PartialLiquidation { closed: usize, remaining: usize },
+
+    #[error("Exchange {exchange} unavailable: {reason}")]
+    ExchangeUnavailable { exchange: String, reason: String },
+
+    #[error("Exchange {exchange} rate limited, retry after {retry_after_ms}ms")]
+    ExchangeRateLimited {
+        exchange: String,
+        retry_after_ms: u64,
+    },
+
+    #[error(
+        "Network request to {endpoint} failed{}{}",
+        status_code.map(|code| format!(" with status code {code}")).unwrap_or_default(),
+        body.as_ref().map(|body| format!(": {body}")).unwrap_or_default()
+    )]
+    NetworkError {
+        endpoint: String,
+        /// HTTP status code of the response, or `None` if no response was received.
+        status_code: Option<u16>,
+        body: Option<String>,
+    },
+
+    #[error(
+        "Network request to {endpoint} timed out{}",
+        elapsed_ms.map(|elapsed_ms| format!(" after {elapsed_ms}ms")).unwrap_or_default()
+    )]
+    RequestTimeout {
+        endpoint: String,
+        /// Time elapsed before the request timed out, or `None` if unknown.
+        elapsed_ms: Option<u64>,
+    },
+
+    #[error("Failed to (de)serialise binary: {0}")]
+    BinarySerialize(String),
+
+    #[error("Engine event queue depth {queue_depth} exceeds the back-pressure limit {limit}")]
+    EngineBackpressureError { queue_depth: usize, limit: usize },
+
+    #[error("Failed to parse CSV: {0}")]
+    CsvParse(String),
+
+    #[error("{} errors: {}", .0.len(), display_errors(.0))]
+    Multiple(Vec<PortfolioError>),
+
     #[error("Failed to interact with repository")]
     RepositoryInteraction(#[from] RepositoryError),
+
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Failed to (de)serialise: {0}")]
+    SerializeError(#[from] serde_json::Error),
+}
+
+impl PortfolioError {
+    /// Returns a stable numeric code identifying the [`PortfolioError`] variant, suitable for
+    /// referencing in runbooks & alerting rules. Codes are never reused, and new variants are
+    /// assigned the next unused code.
+    ///
+    /// | Code | Variant                                             |
+    /// |------|-----------------------------------------------------|
+    /// | 1001 | [`PortfolioError::BuilderIncomplete`]               |
+    /// | 1002 | [`PortfolioError::ParseEntrySide`]                  |
+    /// | 1003 | [`PortfolioError::CannotEnterPositionWithExitFill`] |
+    /// | 1004 | [`PortfolioError::CannotExitPositionWithEntryFill`] |
+    /// | 1005 | [`PortfolioError::PositionExit`]                    |
+    /// | 1006 | [`PortfolioError::InconsistentPositionState`]       |
+    /// | 1007 | [`PortfolioError::InvalidQuantity`]                 |
+    /// | 1008 | [`PortfolioError::InvalidFeeAmount`]                |
+    /// | 1009 | [`PortfolioError::InvalidPrice`]                    |
+    /// | 1010 | [`PortfolioError::InvalidSignalStrength`]           |
+    /// | 1011 | [`PortfolioError::InvalidCorporateAction`]          |
+    /// | 1012 | [`PortfolioError::PositionReplay`]                  |
+    /// | 1013 | [`PortfolioError::FixedPointOverflow`]              |
+    /// | 1014 | [`PortfolioError::FillTimeInFuture`]                |
+    /// | 1015 | [`PortfolioError::InvalidStopLoss`]                 |
+    /// | 1016 | [`PortfolioError::StaleMarketEvent`]                |
+    /// | 1017 | [`PortfolioError::LiquidationTimeout`]              |
+    /// | 1018 | [`PortfolioError::PartialLiquidation`]              |
+    /// | 1019 | [`PortfolioError::ExchangeUnavailable`]             |
+    /// | 1020 | [`PortfolioError::ExchangeRateLimited`]             |
+    /// | 1021 | [`PortfolioError::RepositoryInteraction`]           |
+    /// | 1022 | [`PortfolioError::IoError`]                         |
+    /// | 1023 | [`PortfolioError::SerializeError`]                  |
+    /// | 1024 | [`PortfolioError::CannotScaleInPosition`]           |
+    /// | 1025 | [`PortfolioError::NetworkError`]                    |
+    /// | 1026 | [`PortfolioError::RequestTimeout`]                  |
+    /// | 1027 | [`PortfolioError::Multiple`]                        |
+    /// | 1028 | [`PortfolioError::BinarySerialize`]                 |
+    /// | 1029 | [`PortfolioError::EngineBackpressureError`]         |
+    /// | 1030 | [`PortfolioError::InvalidPriceAdjustment`]          |
+    /// | 1031 | [`PortfolioError::CsvParse`]                        |
+    pub fn error_code(&self) -> u32 {
+        match self {
+            Self::BuilderIncomplete(_) => 1001,
+            Self::ParseEntrySide => 1002,
+            Self::CannotEnterPositionWithExitFill => 1003,
+            Self::CannotExitPositionWithEntryFill => 1004,
+            Self::PositionExit => 1005,
+            Self::InconsistentPositionState { .. } => 1006,
+            Self::InvalidQuantity(_) => 1007,
+            Self::InvalidFeeAmount(_) => 1008,
+            Self::InvalidPrice(_) => 1009,
+            Self::InvalidSignalStrength(_) => 1010,
+            Self::InvalidCorporateAction(_) => 1011,
+            Self::PositionReplay(_) => 1012,
+            Self::FixedPointOverflow(_) => 1013,
+            Self::FillTimeInFuture(_) => 1014,
+            Self::InvalidStopLoss { .. } => 1015,
+            Self::StaleMarketEvent { .. } => 1016,
+            Self::LiquidationTimeout { .. } => 1017,
+            Self::PartialLiquidation { .. } => 1018,
+            Self::ExchangeUnavailable { .. } => 1019,
+            Self::ExchangeRateLimited { .. } => 1020,
+            Self::RepositoryInteraction(_) => 1021,
+            Self::IoError(_) => 1022,
+            Self::SerializeError(_) => 1023,
+            Self::CannotScaleInPosition => 1024,
+            Self::NetworkError { .. } => 1025,
+            Self::RequestTimeout { .. } => 1026,
+            Self::Multiple(_) => 1027,
+            Self::BinarySerialize(_) => 1028,
+            Self::EngineBackpressureError { .. } => 1029,
+            Self::InvalidPriceAdjustment(_) => 1030,
+            Self::CsvParse(_) => 1031,
+        }
+    }
+
+    /// Serialises the [`PortfolioError`] into a structured [`serde_json::Value`] for machine
+    /// processing (eg/ log aggregators), containing the variant "type", the stable
+    /// [`PortfolioError::error_code`], the human-readable "message", and any variant fields.
+    ///
+    /// eg/ `{ "type": "BuilderIncomplete", "code": 1001, "message": "...", "field": "meta" }`
+    pub fn to_json(&self) -> serde_json::Value {
+        let (kind, fields) = match self {
+            Self::BuilderIncomplete(field) => ("BuilderIncomplete", json!({ "field": field })),
+            Self::ParseEntrySide => ("ParseEntrySide", json!({})),
+            Self::CannotEnterPositionWithExitFill => ("CannotEnterPositionWithExitFill", json!({})),
+            Self::CannotExitPositionWithEntryFill => ("CannotExitPositionWithEntryFill", json!({})),
+            Self::PositionExit => ("PositionExit", json!({})),
+            Self::CannotScaleInPosition => ("CannotScaleInPosition", json!({})),
+            Self::InconsistentPositionState {
+                field,
+                expected,
+                actual,
+            } => (
+                "InconsistentPositionState",
+                json!({ "field": field, "expected": expected, "actual": actual }),
+            ),
+            Self::InvalidQuantity(value) => ("InvalidQuantity", json!({ "value": value })),
+            Self::InvalidFeeAmount(value) => ("InvalidFeeAmount", json!({ "value": value })),
+            Self::InvalidPrice(value) => ("InvalidPrice", json!({ "value": value })),
+            Self::InvalidSignalStrength(value) => {
+                ("InvalidSignalStrength", json!({ "value": value }))
+            }
+            Self::InvalidCorporateAction(value) => {
+                ("InvalidCorporateAction", json!({ "value": value }))
+            }
+            Self::InvalidPriceAdjustment(value) => {
+                ("InvalidPriceAdjustment", json!({ "value": value }))
+            }
+            Self::PositionReplay(reason) => ("PositionReplay", json!({ "reason": reason })),
+            Self::FixedPointOverflow(value) => ("FixedPointOverflow", json!({ "value": value })),
+            Self::FillTimeInFuture(time) => ("FillTimeInFuture", json!({ "time": time })),
+            Self::InvalidStopLoss {
+                stop_price,
+                current_price,
+            } => (
+                "InvalidStopLoss",
+                json!({ "stop_price": stop_price, "current_price": current_price }),
+            ),
+            Self::StaleMarketEvent {
+                event_ts,
+                last_update_ts,
+            } => (
+                "StaleMarketEvent",
+                json!({ "event_ts": event_ts, "last_update_ts": last_update_ts }),
+            ),
+            Self::LiquidationTimeout {
+                position_count,
+                elapsed,
+            } => (
+                "LiquidationTimeout",
+                json!({ "position_count": position_count, "elapsed_ms": elapsed.as_millis() }),
+            ),
+            Self::PartialLiquidation { closed, remaining } => (
+                "PartialLiquidation",
+                json!({ "closed": closed, "remaining": remaining }),
+            ),
+            Self::ExchangeUnavailable { exchange, reason } => (
+                "ExchangeUnavailable",
+                json!({ "exchange": exchange, "reason": reason }),
+            ),
+            Self::ExchangeRateLimited {
+                exchange,
+                retry_after_ms,
+            } => (
+                "ExchangeRateLimited",
+                json!({ "exchange": exchange, "retry_after_ms": retry_after_ms }),
+            ),
+            Self::NetworkError {
+                endpoint,
+                status_code,
+                body,
+            } => (
+                "NetworkError",
+                json!({ "endpoint": endpoint, "status_code": status_code, "body": body }),
+            ),
+            Self::RequestTimeout {
+                endpoint,
+                elapsed_ms,
+            } => (
+                "RequestTimeout",
+                json!({ "endpoint": endpoint, "elapsed_ms": elapsed_ms }),
+            ),
+            Self::BinarySerialize(reason) => ("BinarySerialize", json!({ "reason": reason })),
+            Self::CsvParse(reason) => ("CsvParse", json!({ "reason": reason })),
+            Self::EngineBackpressureError { queue_depth, limit } => (
+                "EngineBackpressureError",
+                json!({ "queue_depth": queue_depth, "limit": limit }),
+            ),
+            Self::Multiple(errors) => (
+                "Multiple",
+                json!({ "errors": errors.iter().map(Self::to_json).collect::<Vec<_>>() }),
+            ),
+            Self::RepositoryInteraction(error) => (
+                "RepositoryInteraction",
+                json!({ "source": error.to_string() }),
+            ),
+            Self::IoError(error) => ("IoError", json!({ "source": error.to_string() })),
+            Self::SerializeError(error) => {
+                ("SerializeError", json!({ "source": error.to_string() }))
+            }
+        };
+
+        let mut value = json!({
+            "type": kind,
+            "code": self.error_code(),
+            "message": self.to_string(),
+        });
+        if let (Some(value), serde_json::Value::Object(fields)) = (value.as_object_mut(), fields) {
+            value.extend(fields);
+        }
+
+        value
+    }
+}
+
+/// Enumerates the provided [`PortfolioError`]s, eg/ "1) first error; 2) second error".
+fn display_errors(errors: &[PortfolioError]) -> String {
+    errors
+        .iter()
+        .enumerate()
+        .map(|(index, error)| format!("{}) {error}", index + 1))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Maps a [`reqwest::Error`] to a [`PortfolioError::RequestTimeout`] if the request timed out, or
+/// a [`PortfolioError::NetworkError`] otherwise. Errors without an HTTP response (eg/ a failed
+/// connection) have no `status_code`, with the error description as the `body`.
+///
+/// [`reqwest::Error`] does not expose the time elapsed before a timeout, so `elapsed_ms` is
+/// `None`.
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Error> for PortfolioError {
+    fn from(error: reqwest::Error) -> Self {
+        let endpoint = error
+            .url()
+            .map(|url| url.to_string())
+            .unwrap_or_else(|| "unknown".to_owned());
+
+        if error.is_timeout() {
+            return Self::RequestTimeout {
+                endpoint,
+                elapsed_ms: None,
+            };
+        }
+
+        match error.status() {
+            Some(status) => Self::NetworkError {
+                endpoint,
+                status_code: Some(status.as_u16()),
+                body: None,
+            },
+            None => Self::NetworkError {
+                endpoint,
+                status_code: None,
+                body: Some(error.to_string()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn liquidation_errors_display_position_counts_and_elapsed() {
+        let timeout = PortfolioError::LiquidationTimeout {
+            position_count: 3,
+            elapsed: Duration::from_secs(30),
+        };
+        assert_eq!(
+            timeout.to_string(),
+            "Failed to liquidate 3 open Position(s) before timing out after 30s"
+        );
+
+        let partial = PortfolioError::PartialLiquidation {
+            closed: 2,
+            remaining: 1,
+        };
+        assert_eq!(
+            partial.to_string(),
+            "Partially liquidated open Positions before timing out: 2 closed, 1 remaining"
+        );
+    }
+
+    #[test]
+    fn io_and_serde_json_errors_convert_via_question_mark_with_source_chain() {
+        use std::error::Error;
+
+        fn read_missing_file() -> Result<String, PortfolioError> {
+            Ok(std::fs::read_to_string("/this/path/does/not/exist")?)
+        }
+
+        fn parse_invalid_json() -> Result<serde_json::Value, PortfolioError> {
+            Ok(serde_json::from_str("{ invalid json")?)
+        }
+
+        let io_error = read_missing_file().unwrap_err();
+        assert!(matches!(io_error, PortfolioError::IoError(_)));
+        assert!(io_error
+            .source()
+            .is_some_and(|source| source.is::<std::io::Error>()));
+
+        let serde_error = parse_invalid_json().unwrap_err();
+        assert!(matches!(serde_error, PortfolioError::SerializeError(_)));
+        assert!(serde_error
+            .source()
+            .is_some_and(|source| source.is::<serde_json::Error>()));
+    }
+
+    #[test]
+    fn to_json_contains_type_code_message_and_variant_fields() {
+        let error = PortfolioError::BuilderIncomplete("meta");
+        assert_eq!(
+            error.to_json(),
+            json!({
+                "type": "BuilderIncomplete",
+                "code": 1001,
+                "message": "Failed to build struct due to missing attributes: meta",
+                "field": "meta",
+            })
+        );
+
+        let error = PortfolioError::ExchangeRateLimited {
+            exchange: "binance".to_owned(),
+            retry_after_ms: 500,
+        };
+        let value = error.to_json();
+        assert_eq!(value["type"], "ExchangeRateLimited");
+        assert_eq!(value["code"], 1020);
+        assert_eq!(value["exchange"], "binance");
+        assert_eq!(value["retry_after_ms"], 500);
+
+        assert_eq!(PortfolioError::ParseEntrySide.to_json()["code"], 1002);
+    }
+
+    #[test]
+    fn error_codes_are_unique() {
+        let errors = [
+            PortfolioError::BuilderIncomplete("meta"),
+            PortfolioError::ParseEntrySide,
+            PortfolioError::CannotEnterPositionWithExitFill,
+            PortfolioError::CannotExitPositionWithEntryFill,
+            PortfolioError::PositionExit,
+            PortfolioError::CannotScaleInPosition,
+            PortfolioError::InconsistentPositionState {
+                field: "quantity",
+                expected: "1".to_owned(),
+                actual: "2".to_owned(),
+            },
+            PortfolioError::InvalidQuantity(0.0),
+            PortfolioError::InvalidFeeAmount(-1.0),
+            PortfolioError::InvalidPrice(0.0),
+            PortfolioError::InvalidSignalStrength(2.0),
+            PortfolioError::InvalidCorporateAction(0.0),
+            PortfolioError::InvalidPriceAdjustment(0.0),
+            PortfolioError::PositionReplay("no PositionEvents to replay"),
+            PortfolioError::FixedPointOverflow(f64::MAX),
+            PortfolioError::FillTimeInFuture(Utc::now()),
+            PortfolioError::InvalidStopLoss {
+                stop_price: 1.0,
+                current_price: 1.0,
+            },
+            PortfolioError::StaleMarketEvent {
+                event_ts: Utc::now(),
+                last_update_ts: Utc::now(),
+            },
+            PortfolioError::LiquidationTimeout {
+                position_count: 1,
+                elapsed: Duration::from_secs(1),
+            },
+            PortfolioError::PartialLiquidation {
+                closed: 1,
+                remaining: 1,
+            },
+            PortfolioError::ExchangeUnavailable {
+                exchange: "binance".to_owned(),
+                reason: "maintenance".to_owned(),
+            },
+            PortfolioError::ExchangeRateLimited {
+                exchange: "binance".to_owned(),
+                retry_after_ms: 500,
+            },
+            PortfolioError::NetworkError {
+                endpoint: "https://api.binance.com/api/v3/order".to_owned(),
+                status_code: Some(503),
+                body: None,
+            },
+            PortfolioError::RequestTimeout {
+                endpoint: "https://api.binance.com/api/v3/order".to_owned(),
+                elapsed_ms: Some(5000),
+            },
+            PortfolioError::BinarySerialize("invalid CBOR".to_owned()),
+            PortfolioError::CsvParse("invalid timestamp".to_owned()),
+            PortfolioError::EngineBackpressureError {
+                queue_depth: 10,
+                limit: 10,
+            },
+            PortfolioError::Multiple(vec![]),
+            PortfolioError::RepositoryInteraction(RepositoryError::ReadError),
+            PortfolioError::IoError(std::io::Error::other("io")),
+            PortfolioError::SerializeError(serde_json::from_str::<u8>("x").unwrap_err()),
+        ];
+
+        let codes = errors
+            .iter()
+            .map(PortfolioError::error_code)
+            .collect::<std::collections::HashSet<_>>();
+
+        assert_eq!(codes.len(), errors.len());
+        assert!(errors
+            .iter()
+            .all(|error| error.to_json()["code"] == error.error_code()));
+    }
+
+    #[test]
+    fn network_errors_display_endpoint_and_status_code() {
+        let error = PortfolioError::NetworkError {
+            endpoint: "https://api.binance.com/api/v3/order".to_owned(),
+            status_code: Some(429),
+            body: Some("Too many requests".to_owned()),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Network request to https://api.binance.com/api/v3/order failed with status code 429: \
+             Too many requests"
+        );
+
+        let error = PortfolioError::NetworkError {
+            endpoint: "https://api.binance.com/api/v3/order".to_owned(),
+            status_code: Some(503),
+            body: None,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Network request to https://api.binance.com/api/v3/order failed with status code 503"
+        );
+
+        let error = PortfolioError::NetworkError {
+            endpoint: "https://api.binance.com/api/v3/order".to_owned(),
+            status_code: None,
+            body: Some("connection refused".to_owned()),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Network request to https://api.binance.com/api/v3/order failed: connection refused"
+        );
+
+        let error = PortfolioError::RequestTimeout {
+            endpoint: "https://api.binance.com/api/v3/order".to_owned(),
+            elapsed_ms: Some(5000),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Network request to https://api.binance.com/api/v3/order timed out after 5000ms"
+        );
+
+        let error = PortfolioError::RequestTimeout {
+            endpoint: "https://api.binance.com/api/v3/order".to_owned(),
+            elapsed_ms: None,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Network request to https://api.binance.com/api/v3/order timed out"
+        );
+        assert_eq!(error.to_json().get("elapsed_ms"), Some(&json!(null)));
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn reqwest_error_without_response_converts_to_network_error() {
+        let error = reqwest::Client::new().get("not a url").build().unwrap_err();
+
+        match PortfolioError::from(error) {
+            PortfolioError::NetworkError {
+                status_code, body, ..
+            } => {
+                assert_eq!(status_code, None);
+                assert!(body.is_some());
+            }
+            other => panic!("expected NetworkError, got: {other:?}"),
+        }
+    }
 }
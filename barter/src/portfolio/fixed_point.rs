@@ -0,0 +1,229 @@
+use crate::{
+    execution::Fees,
+    portfolio::{
+        error::PortfolioError,
+        position::{Position, PositionId, PositionMeta},
+    },
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use serde::{Deserialize, Serialize};
+
+/// Scale applied to `f64` values when converting to fixed-point, providing 8 decimal places of
+/// precision.
+pub const FIXED_POINT_SCALE: f64 = 100_000_000.0;
+
+/// Converts an `f64` into a fixed-point `i64` scaled by [`FIXED_POINT_SCALE`], rounding to the
+/// nearest representable value. Returns [`PortfolioError::FixedPointOverflow`] if the value is
+/// not finite or cannot be represented as an `i64`.
+pub fn to_fixed(value: f64) -> Result<i64, PortfolioError> {
+    let scaled = (value * FIXED_POINT_SCALE).round();
+
+    // i64::MAX as f64 rounds up to 2^63, so the upper bound is exclusive
+    if !scaled.is_finite() || scaled < i64::MIN as f64 || scaled >= i64::MAX as f64 {
+        return Err(PortfolioError::FixedPointOverflow(value));
+    }
+
+    Ok(scaled as i64)
+}
+
+/// Converts a fixed-point `i64` scaled by [`FIXED_POINT_SCALE`] back into an `f64`.
+pub fn from_fixed(value: i64) -> f64 {
+    value as f64 / FIXED_POINT_SCALE
+}
+
+/// Fixed-point representation of [`Fees`], where each fee is scaled by [`FIXED_POINT_SCALE`].
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct FeesFixed {
+    pub exchange: i64,
+    pub slippage: i64,
+    pub network: i64,
+}
+
+impl TryFrom<&Fees> for FeesFixed {
+    type Error = PortfolioError;
+
+    fn try_from(fees: &Fees) -> Result<Self, Self::Error> {
+        Ok(Self {
+            exchange: to_fixed(fees.exchange)?,
+            slippage: to_fixed(fees.slippage)?,
+            network: to_fixed(fees.network)?,
+        })
+    }
+}
+
+impl From<FeesFixed> for Fees {
+    fn from(fees: FeesFixed) -> Self {
+        Self {
+            exchange: from_fixed(fees.exchange),
+            slippage: from_fixed(fees.slippage),
+            network: from_fixed(fees.network),
+        }
+    }
+}
+
+/// Fixed-point representation of a [`Position`], where every arithmetic field is scaled by
+/// [`FIXED_POINT_SCALE`]. Intended for environments where deterministic integer arithmetic is
+/// preferable to `f64`.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct PositionFixed {
+    pub position_id: PositionId,
+    pub meta: PositionMeta,
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    pub side: Side,
+    pub quantity: i64,
+    pub enter_fees: FeesFixed,
+    pub enter_fees_total: i64,
+    pub enter_avg_price_gross: i64,
+    pub enter_value_gross: i64,
+    pub exit_fees: FeesFixed,
+    pub exit_fees_total: i64,
+    pub exit_avg_price_gross: i64,
+    pub exit_value_gross: i64,
+    pub current_symbol_price: i64,
+    pub current_value_gross: i64,
+    pub unrealised_profit_loss: i64,
+    pub realised_profit_loss: i64,
+    pub stop_loss_price: Option<i64>,
+    pub partially_exited_quantity: i64,
+    pub total_realised_profit_loss: i64,
+    pub annual_holding_rate: i64,
+    pub received_dividends: i64,
+    #[cfg(feature = "funding-history")]
+    pub funding_rate_series: Vec<(chrono::DateTime<chrono::Utc>, i64)>,
+}
+
+impl TryFrom<&Position> for PositionFixed {
+    type Error = PortfolioError;
+
+    fn try_from(position: &Position) -> Result<Self, Self::Error> {
+        Ok(Self {
+            position_id: position.position_id.clone(),
+            meta: position.meta,
+            exchange: position.exchange.clone(),
+            instrument: position.instrument.clone(),
+            side: position.side,
+            quantity: to_fixed(position.quantity)?,
+            enter_fees: FeesFixed::try_from(&position.enter_fees)?,
+            enter_fees_total: to_fixed(position.enter_fees_total)?,
+            enter_avg_price_gross: to_fixed(position.enter_avg_price_gross)?,
+            enter_value_gross: to_fixed(position.enter_value_gross)?,
+            exit_fees: FeesFixed::try_from(&position.exit_fees)?,
+            exit_fees_total: to_fixed(position.exit_fees_total)?,
+            exit_avg_price_gross: to_fixed(position.exit_avg_price_gross)?,
+            exit_value_gross: to_fixed(position.exit_value_gross)?,
+            current_symbol_price: to_fixed(position.current_symbol_price)?,
+            current_value_gross: to_fixed(position.current_value_gross)?,
+            unrealised_profit_loss: to_fixed(position.unrealised_profit_loss)?,
+            realised_profit_loss: to_fixed(position.realised_profit_loss)?,
+            stop_loss_price: position.stop_loss_price.map(to_fixed).transpose()?,
+            partially_exited_quantity: to_fixed(position.partially_exited_quantity)?,
+            total_realised_profit_loss: to_fixed(position.total_realised_profit_loss)?,
+            annual_holding_rate: to_fixed(position.annual_holding_rate)?,
+            received_dividends: to_fixed(position.received_dividends)?,
+            #[cfg(feature = "funding-history")]
+            funding_rate_series: position
+                .funding_rate_series
+                .iter()
+                .map(|&(timestamp, payment)| Ok((timestamp, to_fixed(payment)?)))
+                .collect::<Result<_, PortfolioError>>()?,
+        })
+    }
+}
+
+impl From<PositionFixed> for Position {
+    fn from(position: PositionFixed) -> Self {
+        Self {
+            position_id: position.position_id,
+            meta: position.meta,
+            exchange: position.exchange,
+            instrument: position.instrument,
+            side: position.side,
+            quantity: from_fixed(position.quantity),
+            enter_fees: Fees::from(position.enter_fees),
+            enter_fees_total: from_fixed(position.enter_fees_total),
+            enter_avg_price_gross: from_fixed(position.enter_avg_price_gross),
+            enter_value_gross: from_fixed(position.enter_value_gross),
+            exit_fees: Fees::from(position.exit_fees),
+            exit_fees_total: from_fixed(position.exit_fees_total),
+            exit_avg_price_gross: from_fixed(position.exit_avg_price_gross),
+            exit_value_gross: from_fixed(position.exit_value_gross),
+            current_symbol_price: from_fixed(position.current_symbol_price),
+            current_value_gross: from_fixed(position.current_value_gross),
+            unrealised_profit_loss: from_fixed(position.unrealised_profit_loss),
+            realised_profit_loss: from_fixed(position.realised_profit_loss),
+            stop_loss_price: position.stop_loss_price.map(from_fixed),
+            partially_exited_quantity: from_fixed(position.partially_exited_quantity),
+            total_realised_profit_loss: from_fixed(position.total_realised_profit_loss),
+            annual_holding_rate: from_fixed(position.annual_holding_rate),
+            received_dividends: from_fixed(position.received_dividends),
+            #[cfg(feature = "funding-history")]
+            funding_rate_series: position
+                .funding_rate_series
+                .into_iter()
+                .map(|(timestamp, payment)| (timestamp, from_fixed(payment)))
+                .collect(),
+        }
+    }
+}
+
+impl Position {
+    /// Converts the [`Position`] into it's [`PositionFixed`] fixed-point representation.
+    pub fn to_fixed_repr(&self) -> Result<PositionFixed, PortfolioError> {
+        PositionFixed::try_from(self)
+    }
+
+    /// Constructs a [`Position`] from it's [`PositionFixed`] fixed-point representation.
+    pub fn from_fixed_repr(position: PositionFixed) -> Position {
+        Position::from(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::position;
+
+    #[test]
+    fn fixed_point_conversion_preserves_eight_decimal_places() {
+        assert_eq!(to_fixed(1.5).unwrap(), 150_000_000);
+        assert_eq!(to_fixed(-0.00000001).unwrap(), -1);
+        assert_eq!(to_fixed(0.000000004).unwrap(), 0);
+        assert_eq!(from_fixed(to_fixed(123.45678901).unwrap()), 123.45678901);
+
+        assert!(matches!(
+            to_fixed(f64::NAN),
+            Err(PortfolioError::FixedPointOverflow(_))
+        ));
+        assert!(matches!(
+            to_fixed(1e12),
+            Err(PortfolioError::FixedPointOverflow(_))
+        ));
+    }
+
+    #[test]
+    fn position_fixed_repr_round_trip() {
+        let mut input = position();
+        input.quantity = -2.5;
+        input.enter_fees = Fees {
+            exchange: 0.25,
+            slippage: 0.125,
+            network: 0.0,
+        };
+        input.enter_fees_total = 0.375;
+        input.enter_avg_price_gross = 101.12345678;
+        input.stop_loss_price = Some(110.5);
+
+        let fixed = input.to_fixed_repr().unwrap();
+        assert_eq!(fixed.quantity, -250_000_000);
+        assert_eq!(fixed.enter_fees.slippage, 12_500_000);
+        assert_eq!(fixed.stop_loss_price, Some(11_050_000_000));
+
+        assert_eq!(Position::from_fixed_repr(fixed), input);
+
+        input.current_value_gross = f64::INFINITY;
+        assert!(input.to_fixed_repr().is_err());
+    }
+}
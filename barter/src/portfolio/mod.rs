@@ -1,7 +1,7 @@
 use crate::{
     data::MarketMeta,
     event::Event,
-    execution::FillEvent,
+    execution::{FillEvent, TimeInForce},
     portfolio::{error::PortfolioError, position::PositionUpdate},
     strategy::{Decision, Signal, SignalForceExit},
 };
@@ -14,9 +14,27 @@ use uuid::Uuid;
 /// Logic for [`OrderEvent`] quantity allocation.
 pub mod allocator;
 
+/// Analysis of collections of [`Position`](position::Position)s, such as average trade
+/// duration.
+pub mod analysis;
+
+/// Compact binary serialisation of [`Position`](position::Position)s, and a write-ahead log of
+/// [`PositionEvent`](position::PositionEvent)s for crash-tolerant Position tracking.
+#[cfg(feature = "serde-binary")]
+pub mod binary;
+
 /// Barter portfolio module specific errors.
 pub mod error;
 
+/// Fixed-point representations of a [`Position`](position::Position) & it's
+/// [`Fees`](crate::execution::Fees).
+#[cfg(feature = "fixed-point")]
+pub mod fixed_point;
+
+/// Multi-leg spread trades (eg/ pairs trades) composed of several
+/// [`Position`](position::Position)s.
+pub mod multi_leg;
+
 /// Core Portfolio logic containing an implementation of [`MarketUpdater`],
 /// [`OrderGenerator`] and [`FillUpdater`]. Utilises the risk and allocator logic to optimise
 /// [`OrderEvent`] generation.
@@ -32,6 +50,11 @@ pub mod repository;
 /// Logic for evaluating the risk associated with a proposed [`OrderEvent`].
 pub mod risk;
 
+/// Reactive observation of [`Position`](position::Position) state changes via a tokio `watch`
+/// channel.
+#[cfg(feature = "watch")]
+pub mod watch;
+
 /// Updates the Portfolio from an input [`MarketEvent`].
 pub trait MarketUpdater {
     /// Determines if the Portfolio has an open Position relating to the input [`MarketEvent`]. If
@@ -54,6 +77,10 @@ pub trait OrderGenerator {
         &mut self,
         signal: SignalForceExit,
     ) -> Result<Option<OrderEvent>, PortfolioError>;
+
+    /// Notifies the Portfolio that a generated [`OrderEvent`] was rejected by the execution
+    /// handler & will never be filled (eg/ it's [`TimeInForce`] expired).
+    fn on_order_rejected(&mut self, _order: &OrderEvent) {}
 }
 
 /// Updates the Portfolio from an input [`FillEvent`].
@@ -79,6 +106,9 @@ pub struct OrderEvent {
     pub quantity: f64,
     /// MARKET, LIMIT etc
     pub order_type: OrderType,
+    /// [`TimeInForce`] the execution handler should honour when executing the order.
+    #[serde(default)]
+    pub tif: TimeInForce,
 }
 
 impl OrderEvent {
@@ -115,6 +145,7 @@ pub struct OrderEventBuilder {
     pub decision: Option<Decision>,
     pub quantity: Option<f64>,
     pub order_type: Option<OrderType>,
+    pub tif: Option<TimeInForce>,
 }
 
 impl OrderEventBuilder {
@@ -171,6 +202,13 @@ impl OrderEventBuilder {
         }
     }
 
+    pub fn tif(self, value: TimeInForce) -> Self {
+        Self {
+            tif: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Result<OrderEvent, PortfolioError> {
         Ok(OrderEvent {
             time: self.time.ok_or(PortfolioError::BuilderIncomplete("time"))?,
@@ -192,6 +230,7 @@ impl OrderEventBuilder {
             order_type: self
                 .order_type
                 .ok_or(PortfolioError::BuilderIncomplete("order_type"))?,
+            tif: self.tif.unwrap_or_default(),
         })
     }
 }
@@ -0,0 +1,164 @@
+use super::position::{Position, PositionUpdate, PositionUpdater};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+
+/// Multiple [`Position`] legs of a spread trade (eg/ a pairs trade), analysed as a single entity.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MultiLegPosition {
+    /// [`Position`] legs making up the spread trade.
+    pub legs: Vec<Position>,
+    /// Tag identifying the strategy the spread trade belongs to (eg/ "btc_eth_pair").
+    pub strategy_tag: String,
+}
+
+impl MultiLegPosition {
+    /// Constructs a new [`MultiLegPosition`] from the provided [`Position`] legs.
+    pub fn new<S>(legs: Vec<Position>, strategy_tag: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            legs,
+            strategy_tag: strategy_tag.into(),
+        }
+    }
+
+    /// Calculates the sum of every open leg's [`Position::unrealised_profit_loss`]. Closed legs
+    /// are excluded, since their profit & loss has already been realised.
+    pub fn total_unrealised_pnl(&self) -> f64 {
+        self.legs
+            .iter()
+            .filter(|leg| leg.meta.exit_balance.is_none())
+            .map(|leg| leg.unrealised_profit_loss)
+            .sum()
+    }
+
+    /// Calculates the sum of every leg's [`Position::realised_profit_loss`].
+    pub fn total_realised_pnl(&self) -> f64 {
+        self.legs.iter().map(|leg| leg.realised_profit_loss).sum()
+    }
+
+    /// Calculates the net of every leg's signed [`Position::quantity`]. A market-neutral spread
+    /// has a net delta of approximately zero.
+    pub fn net_delta(&self) -> f64 {
+        self.legs.iter().map(|leg| leg.quantity).sum()
+    }
+
+    /// Determines if every leg of the [`MultiLegPosition`] has been exited.
+    pub fn is_fully_closed(&self) -> bool {
+        self.legs.iter().all(|leg| leg.meta.exit_balance.is_some())
+    }
+}
+
+impl PositionUpdater for MultiLegPosition {
+    /// Routes the [`MarketEvent`] to the open leg with a matching exchange & [`Instrument`],
+    /// returning that leg's [`PositionUpdate`]. Returns `None` if no open leg matches.
+    fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<PositionUpdate> {
+        self.legs
+            .iter_mut()
+            .find(|leg| {
+                leg.meta.exit_balance.is_none()
+                    && leg.exchange == market.exchange
+                    && leg.instrument == market.instrument
+            })?
+            .update(market)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        portfolio::Balance,
+        test_util::{market_event_trade, position},
+    };
+    use barter_data::subscription::trade::PublicTrade;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Exchange, Side};
+
+    fn market_event(base: &str, price: f64) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.exchange = Exchange::from("binance");
+        market.instrument = Instrument::from((base, "usdt", InstrumentKind::Spot));
+        market.kind = DataKind::Trade(PublicTrade {
+            id: "trade_id".to_string(),
+            price,
+            amount: 1.0,
+            side: Side::Buy,
+        });
+        market
+    }
+
+    /// Long 10 ETH @ 100.0 & short 1 BTC @ 1000.0, with equal notional on each leg.
+    fn eth_btc_pair() -> MultiLegPosition {
+        let mut long = position();
+        long.quantity = 10.0;
+        long.enter_avg_price_gross = 100.0;
+        long.enter_value_gross = 1000.0;
+        long.current_value_gross = 1000.0;
+
+        let mut short = position();
+        short.instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        short.side = Side::Sell;
+        short.quantity = -1.0;
+        short.enter_avg_price_gross = 1000.0;
+        short.enter_value_gross = 1000.0;
+        short.current_symbol_price = 1000.0;
+        short.current_value_gross = 1000.0;
+
+        MultiLegPosition::new(vec![long, short], "eth_btc_pair")
+    }
+
+    #[test]
+    fn market_neutral_pair_routes_market_events_to_matching_leg() {
+        let mut pair = eth_btc_pair();
+
+        let net_notional = pair.legs.iter().map(Position::net_exposure).sum::<f64>();
+        assert!(net_notional.abs() < 1e-9);
+        // Net delta is measured in contracts, so is only zero for legs of the same price
+        assert_eq!(pair.net_delta(), 9.0);
+
+        // BTC falls 10%, so the short leg profits
+        let update = pair.update(&market_event("btc", 900.0)).unwrap();
+        assert_eq!(update.current_symbol_price, 900.0);
+        assert_eq!(pair.legs[0].current_symbol_price, 100.0);
+        assert!((pair.total_unrealised_pnl() - 100.0).abs() < 1e-9);
+
+        // ETH falls 10%, so the long leg loses & the pair is flat overall
+        pair.update(&market_event("eth", 90.0)).unwrap();
+        assert!(pair.total_unrealised_pnl().abs() < 1e-9);
+
+        // Unrelated symbols are ignored
+        assert!(pair.update(&market_event("sol", 10.0)).is_none());
+    }
+
+    #[test]
+    fn realised_pnl_and_fully_closed_across_legs() {
+        let mut pair = eth_btc_pair();
+        assert!(!pair.is_fully_closed());
+        assert_eq!(pair.total_realised_pnl(), 0.0);
+
+        pair.legs[0].meta.exit_balance = Some(Balance::default());
+        pair.legs[0].realised_profit_loss = 50.0;
+        assert!(!pair.is_fully_closed());
+
+        pair.legs[1].meta.exit_balance = Some(Balance::default());
+        pair.legs[1].realised_profit_loss = -20.0;
+        assert!(pair.is_fully_closed());
+        assert_eq!(pair.total_realised_pnl(), 30.0);
+    }
+
+    #[test]
+    fn unrealised_pnl_excludes_closed_legs() {
+        let mut pair = eth_btc_pair();
+        pair.update(&market_event("btc", 900.0)).unwrap();
+        pair.update(&market_event("eth", 95.0)).unwrap();
+        assert!((pair.total_unrealised_pnl() - 50.0).abs() < 1e-9);
+
+        // Exiting the short leg realises it's profit, leaving only the open long leg unrealised
+        pair.legs[1].meta.exit_balance = Some(Balance::default());
+        pair.legs[1].realised_profit_loss = pair.legs[1].unrealised_profit_loss;
+        assert!((pair.total_unrealised_pnl() + 50.0).abs() < 1e-9);
+        assert!((pair.total_realised_pnl() - 100.0).abs() < 1e-9);
+    }
+}
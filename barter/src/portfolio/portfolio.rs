@@ -1,27 +1,32 @@
 use super::{
     allocator::OrderAllocator,
+    analysis::{to_trade_record, TradeRecord},
     error::PortfolioError,
     position::{
-        determine_position_id, Position, PositionEnterer, PositionExiter, PositionId,
-        PositionUpdate, PositionUpdater,
+        determine_position_id, DefaultPositionEnterer, Position, PositionEnterer, PositionExiter,
+        PositionId, PositionUpdate, PositionUpdater,
     },
     repository::{error::RepositoryError, BalanceHandler, PositionHandler, StatisticHandler},
-    risk::OrderEvaluator,
+    risk::{OrderEvaluator, PositionLimits},
     Balance, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator, OrderType,
 };
+#[cfg(feature = "serde-binary")]
+use super::{binary::PositionEventLog, position::PositionEvent};
 use crate::{
     data::MarketMeta,
     event::Event,
-    execution::FillEvent,
+    execution::{FillEvent, TimeInForce},
     statistic::summary::{Initialiser, PositionSummariser},
     strategy::{Decision, Signal, SignalForceExit, SignalStrength},
 };
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::Instrument, Market, MarketId, Side};
-use chrono::Utc;
-use serde::Serialize;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, marker::PhantomData};
-use tracing::info;
+#[cfg(feature = "serde-binary")]
+use tracing::error;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 /// Lego components for constructing & initialising a [`MetaPortfolio`] via the init() constructor
@@ -66,6 +71,15 @@ where
 {
     /// Identifier for the [`Engine`](crate::engine::Engine) this Portfolio is associated with (1-to-1 relationship).
     engine_id: Uuid,
+    /// [`Market`]s being tracked by the [`MetaPortfolio`].
+    markets: Vec<Market>,
+    /// Limits on the [`Position`]s that may be open concurrently, enforced when generating entry
+    /// [`OrderEvent`]s.
+    position_limits: PositionLimits,
+    /// Gross value of every entry [`OrderEvent`] generated whilst [`PositionLimits`] are
+    /// configured that is yet to be filled, keyed by [`PositionId`]. Counted towards the
+    /// [`PositionLimits`] so concurrent Traders cannot both enter beyond a limit.
+    pending_entries: HashMap<PositionId, f64>,
     /// Repository for the [`MetaPortfolio`] to persist it's state in. Implements
     /// [`PositionHandler`], [`BalanceHandler`], and [`StatisticHandler`]
     repository: Repository,
@@ -73,6 +87,14 @@ where
     allocation_manager: Allocator,
     /// Risk manager implements [`OrderEvaluator`].
     risk_manager: RiskManager,
+    /// Enters a new [`Position`] from an entry [`FillEvent`]. Defaults to the
+    /// [`DefaultPositionEnterer`].
+    position_enterer: Box<dyn PositionEnterer>,
+    /// Optional write-ahead log every [`PositionEvent::Opened`] & [`PositionEvent::Closed`] is
+    /// appended to before the repository is updated, enabling [`Position`] recovery after a
+    /// crash.
+    #[cfg(feature = "serde-binary")]
+    event_log: Option<PositionEventLog>,
     _statistic_marker: PhantomData<Statistic>,
 }
 
@@ -133,6 +155,11 @@ where
                 Some(net_signal) => net_signal,
             };
 
+        // SignalStrength only sizes entries, since exits always close the whole Position
+        if signal_decision.is_entry() && !signal_strength.is_valid() {
+            return Err(PortfolioError::InvalidSignalStrength(signal_strength.0));
+        }
+
         // Construct mutable OrderEvent that can be modified by Allocation & Risk management
         let mut order = OrderEvent {
             time: Utc::now(),
@@ -142,6 +169,7 @@ where
             decision: *signal_decision,
             quantity: 0.0,
             order_type: OrderType::default(),
+            tif: TimeInForce::default(),
         };
 
         // Manage OrderEvent size allocation
@@ -149,7 +177,27 @@ where
             .allocate_order(&mut order, position, *signal_strength);
 
         // Manage global risk when evaluating OrderEvent - keep the same, refine or cancel
-        Ok(self.risk_manager.evaluate_order(order))
+        let Some(order) = self.risk_manager.evaluate_order(order) else {
+            return Ok(None);
+        };
+
+        // Enforce Portfolio-wide PositionLimits on entry OrderEvents, including pending entries
+        if order.decision.is_entry() && !self.position_limits.is_unlimited() {
+            let entry_value_gross = order.quantity.abs() * order.market_meta.close;
+            if self.entry_breaches_position_limits(&position_id, entry_value_gross)? {
+                warn!(
+                    position_id = &*position_id,
+                    limits = ?self.position_limits,
+                    entry_value_gross,
+                    outcome = "no entry OrderEvent generated",
+                    "entering Position would breach PositionLimits"
+                );
+                return Ok(None);
+            }
+            self.pending_entries.insert(position_id, entry_value_gross);
+        }
+
+        Ok(Some(order))
     }
 
     fn generate_exit_order(
@@ -184,8 +232,18 @@ where
             decision: position.determine_exit_decision(),
             quantity: 0.0 - position.quantity,
             order_type: OrderType::Market,
+            tif: TimeInForce::default(),
         }))
     }
+
+    fn on_order_rejected(&mut self, order: &OrderEvent) {
+        // Rejected entries will never be filled, so no longer count towards the PositionLimits
+        if order.decision.is_entry() {
+            let position_id =
+                determine_position_id(self.engine_id, &order.exchange, &order.instrument);
+            self.pending_entries.remove(&position_id);
+        }
+    }
 }
 
 impl<Repository, Allocator, RiskManager, Statistic> FillUpdater
@@ -207,12 +265,17 @@ where
         // Determine the position_id that is related to the input FillEvent
         let position_id = determine_position_id(self.engine_id, &fill.exchange, &fill.instrument);
 
+        // Any pending entry for the Position is now reflected by the FillEvent
+        self.pending_entries.remove(&position_id);
+
         // Determine FillEvent context based on existence or absence of an open Position
         match self.repository.remove_position(&position_id)? {
             // EXIT SCENARIO - FillEvent for Symbol-Exchange combination with open Position
             Some(mut position) => {
                 // Exit Position (in place mutation), & add the PositionExit event to Vec<Event>
                 let position_exit = position.exit(balance, fill)?;
+                #[cfg(feature = "serde-binary")]
+                self.log_position_event(|| PositionEvent::Closed(position_exit.clone()));
                 generated_events.push(Event::PositionExit(position_exit));
 
                 // Update Portfolio balance on Position exit
@@ -223,7 +286,11 @@ where
                 balance.total += position.realised_profit_loss;
 
                 // Update statistics for exited Position market
-                let market_id = MarketId::new(&fill.exchange, &fill.instrument);
+                // '--> MarketId derived from Market to match the key used when bootstrapping
+                let market_id = MarketId::from(&Market::<Instrument>::new(
+                    fill.exchange.clone(),
+                    fill.instrument.clone(),
+                ));
 
                 let mut stats = self.repository.get_statistics(&market_id)?;
                 stats.update(&position);
@@ -237,7 +304,9 @@ where
             // ENTRY SCENARIO - FillEvent for Symbol-Exchange with no Position
             None => {
                 // Enter new Position, & add the PositionNew event to Vec<Event>
-                let position = Position::enter(self.engine_id, fill)?;
+                let position = self.position_enterer.enter_checked(self.engine_id, fill)?;
+                #[cfg(feature = "serde-binary")]
+                self.log_position_event(|| PositionEvent::Opened(Box::new(position.clone())));
                 generated_events.push(Event::PositionNew(position.clone()));
 
                 // Update Portfolio Balance.available on Position entry
@@ -339,9 +408,15 @@ where
         // Construct MetaPortfolio instance
         let mut portfolio = Self {
             engine_id: lego.engine_id,
+            markets: lego.markets.clone(),
+            position_limits: PositionLimits::default(),
+            pending_entries: HashMap::new(),
             repository: lego.repository,
             allocation_manager: lego.allocator,
             risk_manager: lego.risk,
+            position_enterer: Box::new(DefaultPositionEnterer),
+            #[cfg(feature = "serde-binary")]
+            event_log: None,
             _statistic_marker: PhantomData,
         };
 
@@ -381,11 +456,96 @@ where
         })
     }
 
+    /// Returns a [`PortfolioSnapshot`] deep copy of the current [`MetaPortfolio`] state for the
+    /// provided [`Market`]s. The snapshot remains valid as the [`MetaPortfolio`] continues to
+    /// evolve, so it can be inspected without holding a lock on the [`MetaPortfolio`].
+    pub fn snapshot<'a, Markets>(
+        &mut self,
+        markets: Markets,
+    ) -> Result<PortfolioSnapshot, PortfolioError>
+    where
+        Markets: Iterator<Item = &'a Market>,
+    {
+        let balance = self.repository.get_balance(self.engine_id)?;
+
+        Ok(PortfolioSnapshot {
+            timestamp: Utc::now(),
+            open_positions: self
+                .repository
+                .get_open_positions(self.engine_id, markets)?,
+            closed_positions: self.repository.get_exited_positions(self.engine_id)?,
+            total_equity: balance.total,
+            available_cash: balance.available,
+        })
+    }
+
+    /// Returns a [`TradeRecord`] for every exited [`Position`], sorted by exit time.
+    pub fn trade_log(&mut self) -> Result<impl Iterator<Item = TradeRecord>, PortfolioError> {
+        let mut records = self
+            .repository
+            .get_exited_positions(self.engine_id)?
+            .iter()
+            .filter_map(to_trade_record)
+            .collect::<Vec<_>>();
+
+        records.sort_by_key(|record| record.exit_time);
+
+        Ok(records.into_iter())
+    }
+
     /// Returns a [`MetaPortfolioBuilder`] instance.
     pub fn builder() -> MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic> {
         MetaPortfolioBuilder::new()
     }
 
+    /// Appends the [`PositionEvent`] to the write-ahead event_log, if one is configured.
+    ///
+    /// A failed append is logged rather than returned, since the [`FillEvent`] that yielded the
+    /// [`PositionEvent`] has already been executed & must still be applied to the repository.
+    #[cfg(feature = "serde-binary")]
+    fn log_position_event<F>(&mut self, event: F)
+    where
+        F: FnOnce() -> PositionEvent,
+    {
+        let Some(event_log) = self.event_log.as_mut() else {
+            return;
+        };
+
+        if let Err(error) = event_log.append(&event()) {
+            error!(
+                %error,
+                error_code = error.error_code(),
+                engine_id = %self.engine_id,
+                outcome = "Position recovery from the event log may be incomplete",
+                "failed to append PositionEvent to event log"
+            );
+        }
+    }
+
+    /// Determines if entering a new [`Position`] worth `entry_value_gross` would breach the
+    /// [`PositionLimits`], counting every open [`Position`] & pending entry [`OrderEvent`] for
+    /// other [`PositionId`]s.
+    fn entry_breaches_position_limits(
+        &mut self,
+        position_id: &PositionId,
+        entry_value_gross: f64,
+    ) -> Result<bool, PortfolioError> {
+        let open_positions = self
+            .repository
+            .get_open_positions(self.engine_id, self.markets.iter())?;
+
+        let pending_entries = self
+            .pending_entries
+            .iter()
+            .filter(|(pending_id, _)| *pending_id != position_id)
+            .map(|(_, value_gross)| *value_gross)
+            .collect::<Vec<_>>();
+
+        Ok(self
+            .position_limits
+            .breached_by(&open_positions, &pending_entries, entry_value_gross))
+    }
+
     /// Determines if the Portfolio has any cash to enter a new [`Position`].
     fn no_cash_to_enter_new_position(&mut self) -> Result<bool, PortfolioError> {
         self.repository
@@ -395,6 +555,29 @@ where
     }
 }
 
+/// Immutable point-in-time view of a [`MetaPortfolio`]'s state. Generated via
+/// [`MetaPortfolio::snapshot`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct PortfolioSnapshot {
+    /// Timestamp the [`PortfolioSnapshot`] was generated.
+    pub timestamp: DateTime<Utc>,
+    /// Open [`Position`]s at the time of the [`PortfolioSnapshot`].
+    pub open_positions: Vec<Position>,
+    /// Exited [`Position`]s at the time of the [`PortfolioSnapshot`].
+    pub closed_positions: Vec<Position>,
+    /// Total equity ([`Balance::total`]) at the time of the [`PortfolioSnapshot`].
+    pub total_equity: f64,
+    /// Available cash ([`Balance::available`]) at the time of the [`PortfolioSnapshot`].
+    pub available_cash: f64,
+}
+
+impl PortfolioSnapshot {
+    /// Serialise the [`PortfolioSnapshot`] into a [`serde_json::Value`], eg/ for a REST API.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("PortfolioSnapshot is always serialisable to JSON")
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic>
 where
@@ -409,6 +592,10 @@ where
     repository: Option<Repository>,
     allocation_manager: Option<Allocator>,
     risk_manager: Option<RiskManager>,
+    position_limits: Option<PositionLimits>,
+    position_enterer: Option<Box<dyn PositionEnterer>>,
+    #[cfg(feature = "serde-binary")]
+    event_log: Option<PositionEventLog>,
     statistic_config: Option<Statistic::Config>,
     _statistic_marker: Option<PhantomData<Statistic>>,
 }
@@ -429,6 +616,10 @@ where
             repository: None,
             allocation_manager: None,
             risk_manager: None,
+            position_limits: None,
+            position_enterer: None,
+            #[cfg(feature = "serde-binary")]
+            event_log: None,
             statistic_config: None,
             _statistic_marker: None,
         }
@@ -476,6 +667,35 @@ where
         }
     }
 
+    /// Caps the number of concurrently open [`Position`]s and/or their gross exposure. Entry
+    /// [`OrderEvent`]s that would breach a limit are not generated.
+    pub fn position_limits(self, value: PositionLimits) -> Self {
+        Self {
+            position_limits: Some(value),
+            ..self
+        }
+    }
+
+    pub fn position_enterer(self, value: Box<dyn PositionEnterer>) -> Self {
+        Self {
+            position_enterer: Some(value),
+            ..self
+        }
+    }
+
+    /// Appends every [`PositionEvent::Opened`] & [`PositionEvent::Closed`] to the provided
+    /// write-ahead [`PositionEventLog`] before updating the repository. Market driven
+    /// [`PositionEvent::Updated`]s are not logged, since syncing the log on every
+    /// [`MarketEvent`] is prohibitively slow & they are re-derived from the next [`MarketEvent`].
+    /// See [`PositionEventLog::recover`].
+    #[cfg(feature = "serde-binary")]
+    pub fn event_log(self, value: PositionEventLog) -> Self {
+        Self {
+            event_log: Some(value),
+            ..self
+        }
+    }
+
     pub fn statistic_config(self, value: Statistic::Config) -> Self {
         Self {
             statistic_config: Some(value),
@@ -486,11 +706,18 @@ where
     pub fn build_and_init(
         self,
     ) -> Result<MetaPortfolio<Repository, Allocator, RiskManager, Statistic>, PortfolioError> {
+        let markets = self
+            .markets
+            .ok_or(PortfolioError::BuilderIncomplete("markets"))?;
+
         // Construct Portfolio
         let mut portfolio = MetaPortfolio {
             engine_id: self
                 .engine_id
                 .ok_or(PortfolioError::BuilderIncomplete("engine_id"))?,
+            markets: markets.clone(),
+            position_limits: self.position_limits.unwrap_or_default(),
+            pending_entries: HashMap::new(),
             repository: self
                 .repository
                 .ok_or(PortfolioError::BuilderIncomplete("repository"))?,
@@ -500,6 +727,11 @@ where
             risk_manager: self
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            position_enterer: self
+                .position_enterer
+                .unwrap_or_else(|| Box::new(DefaultPositionEnterer)),
+            #[cfg(feature = "serde-binary")]
+            event_log: self.event_log,
             _statistic_marker: PhantomData,
         };
 
@@ -507,9 +739,7 @@ where
         portfolio.bootstrap_repository(
             self.starting_cash
                 .ok_or(PortfolioError::BuilderIncomplete("starting_cash"))?,
-            &self
-                .markets
-                .ok_or(PortfolioError::BuilderIncomplete("markets"))?,
+            &markets,
             self.statistic_config
                 .ok_or(PortfolioError::BuilderIncomplete("statistic_config"))?,
         )?;
@@ -704,6 +934,9 @@ pub mod tests {
             engine_id: builder
                 .engine_id
                 .ok_or(PortfolioError::BuilderIncomplete("engine_id"))?,
+            markets: builder.markets.unwrap_or_default(),
+            position_limits: builder.position_limits.unwrap_or_default(),
+            pending_entries: HashMap::new(),
             repository: builder
                 .repository
                 .ok_or(PortfolioError::BuilderIncomplete("repository"))?,
@@ -713,6 +946,11 @@ pub mod tests {
             risk_manager: builder
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            position_enterer: builder
+                .position_enterer
+                .unwrap_or_else(|| Box::new(DefaultPositionEnterer)),
+            #[cfg(feature = "serde-binary")]
+            event_log: builder.event_log,
             _statistic_marker: Default::default(),
         })
     }
@@ -980,6 +1218,64 @@ pub mod tests {
         assert_eq!(actual.decision, Decision::Long)
     }
 
+    #[test]
+    fn generate_order_with_invalid_signal_strength_returns_error() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_position = Some(|_| Ok(None));
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 100.0,
+                available: 100.0,
+            })
+        });
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        for strength in [-0.1, 1.5, f64::NAN] {
+            let mut input_signal = signal();
+            input_signal
+                .signals
+                .insert(Decision::Long, SignalStrength(strength));
+
+            assert!(matches!(
+                portfolio.generate_order(&input_signal),
+                Err(PortfolioError::InvalidSignalStrength(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn generate_order_ignores_signal_strength_of_exit_signals() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_position = Some(|_| {
+            Ok(Some({
+                let mut position = position();
+                position.side = Side::Buy;
+                position
+            }))
+        });
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 100.0,
+                available: 100.0,
+            })
+        });
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        for strength in [-0.1, 1.5, f64::NAN] {
+            let mut input_signal = signal();
+            input_signal
+                .signals
+                .insert(Decision::CloseLong, SignalStrength(strength));
+
+            let actual = portfolio.generate_order(&input_signal).unwrap().unwrap();
+            assert_eq!(actual.decision, Decision::CloseLong);
+        }
+    }
+
     #[test]
     fn generate_order_short_with_no_position_and_input_net_short_signal() {
         // Build Portfolio
@@ -1219,6 +1515,287 @@ pub mod tests {
         assert_eq!(updated_cash, 200.0 - 100.0 - 3.0); // cash += enter_value_gross - enter_fees
     }
 
+    #[test]
+    fn update_from_fill_entering_position_with_custom_position_enterer() {
+        /// Tags every entered Position with a stop loss a fixed distance below the entry price.
+        #[derive(Debug)]
+        struct StopLossEnterer {
+            stop_distance: f64,
+        }
+
+        impl PositionEnterer for StopLossEnterer {
+            fn enter(&self, engine_id: Uuid, fill: &FillEvent) -> Result<Position, PortfolioError> {
+                let mut position = DefaultPositionEnterer.enter(engine_id, fill)?;
+                position.stop_loss_price =
+                    Some(position.enter_avg_price_gross - self.stop_distance);
+                Ok(position)
+            }
+        }
+
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 200.0,
+                available: 200.0,
+            })
+        });
+        mock_repository.remove_position = Some(|_| Ok(None));
+        mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.set_balance = Some(|_, _| Ok(()));
+        let builder = MetaPortfolio::builder()
+            .engine_id(Uuid::new_v4())
+            .repository(mock_repository)
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .position_enterer(Box::new(StopLossEnterer {
+                stop_distance: 10.0,
+            }));
+        let mut portfolio = build_uninitialised_portfolio(builder).unwrap();
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 100.0;
+
+        let events = portfolio.update_from_fill(&input_fill).unwrap();
+
+        match &events[0] {
+            Event::PositionNew(position) => {
+                assert_eq!(position.side, Side::Buy);
+                assert_eq!(position.stop_loss_price, Some(90.0));
+            }
+            event => panic!("expected PositionNew event, got: {event:?}"),
+        }
+    }
+
+    #[cfg(feature = "serde-binary")]
+    #[test]
+    fn event_log_enables_recovery_of_positions_after_crash() {
+        use crate::{
+            portfolio::repository::in_memory::InMemoryRepository,
+            statistic::summary::trading::{Config as StatisticConfig, TradingSummary},
+        };
+
+        let path = std::env::temp_dir().join(format!("portfolio_events_{}.log", Uuid::new_v4()));
+
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(Uuid::new_v4())
+            .markets(vec![Market::new(
+                "binance_spot",
+                ("btc", "usdt", InstrumentKind::Spot),
+            )])
+            .starting_cash(1000.0)
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 1000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .event_log(PositionEventLog::open(&path).unwrap())
+            .build_and_init()
+            .unwrap();
+
+        let market = market_event_trade(Side::Buy);
+        let mut entry = fill_event();
+        entry.exchange = market.exchange.clone();
+        entry.instrument = market.instrument.clone();
+        entry.time = Utc::now() - chrono::Duration::minutes(1);
+        entry.decision = Decision::Long;
+
+        let mut exit = entry.clone();
+        exit.time = Utc::now();
+        exit.decision = Decision::CloseLong;
+        exit.quantity = -1.0;
+        exit.fill_value_gross = 1000.0;
+
+        // Enter, update & exit a Position, logging the Opened & Closed PositionEvents
+        portfolio.update_from_fill(&entry).unwrap();
+        assert!(portfolio.update_from_market(&market).unwrap().is_some());
+        portfolio.update_from_fill(&exit).unwrap();
+        let exited = portfolio
+            .repository
+            .get_exited_positions(portfolio.engine_id)
+            .unwrap();
+        drop(portfolio);
+
+        let events = PositionEventLog::read(&path);
+        let recovered = PositionEventLog::recover(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(events.unwrap().len(), 2);
+        let recovered = recovered.unwrap();
+        assert_eq!(recovered.len(), 1);
+        assert_eq!(recovered[0].position_id, exited[0].position_id);
+        assert_eq!(
+            recovered[0].realised_profit_loss,
+            exited[0].realised_profit_loss
+        );
+        assert_eq!(recovered[0].meta.exit_balance, exited[0].meta.exit_balance);
+    }
+
+    #[cfg(all(feature = "serde-binary", target_os = "linux"))]
+    #[test]
+    fn event_log_append_failure_does_not_fail_fill_update() {
+        use crate::{
+            portfolio::repository::in_memory::InMemoryRepository,
+            statistic::summary::trading::{Config as StatisticConfig, TradingSummary},
+        };
+
+        // Every write to /dev/full fails with "no space left on device"
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(Uuid::new_v4())
+            .markets(vec![Market::new(
+                "binance",
+                ("eth", "usdt", InstrumentKind::Spot),
+            )])
+            .starting_cash(1000.0)
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 1000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .event_log(PositionEventLog::open("/dev/full").unwrap())
+            .build_and_init()
+            .unwrap();
+
+        let mut entry = fill_event();
+        entry.decision = Decision::Long;
+
+        let events = portfolio.update_from_fill(&entry).unwrap();
+        assert!(matches!(events[0], Event::PositionNew(_)));
+    }
+
+    #[test]
+    fn generate_order_enforces_position_limits_including_pending_entries() {
+        use crate::{
+            portfolio::repository::in_memory::InMemoryRepository,
+            statistic::summary::trading::{Config as StatisticConfig, TradingSummary},
+        };
+
+        let markets = ["btc", "eth", "sol"]
+            .map(|base| Market::new("binance", (base, "usdt", InstrumentKind::Spot)));
+        let new_portfolio = |limits: PositionLimits| {
+            MetaPortfolio::builder()
+                .engine_id(Uuid::new_v4())
+                .markets(markets.to_vec())
+                .starting_cash(1000.0)
+                .repository(InMemoryRepository::<TradingSummary>::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: 100.0,
+                })
+                .risk_manager(DefaultRisk {})
+                .position_limits(limits)
+                .statistic_config(StatisticConfig {
+                    starting_equity: 1000.0,
+                    trading_days_per_year: 365,
+                    risk_free_return: 0.0,
+                })
+                .build_and_init()
+                .unwrap()
+        };
+        let entry_signal = |market: &Market| {
+            let mut signal = signal();
+            signal.exchange = market.exchange.clone();
+            signal.instrument = market.instrument.clone();
+            signal.market_meta.close = 100.0;
+            signal.signals.insert(Decision::Long, SignalStrength(1.0));
+            signal
+        };
+        let fill = |market: &Market, decision: Decision, quantity: f64| {
+            let mut fill = fill_event();
+            fill.exchange = market.exchange.clone();
+            fill.instrument = market.instrument.clone();
+            fill.decision = decision;
+            fill.quantity = quantity;
+            fill
+        };
+
+        // Max open Positions
+        let mut portfolio = new_portfolio(PositionLimits {
+            max_open_positions: Some(2),
+            max_gross_exposure: None,
+        });
+
+        // Pending entries that are yet to be filled count towards the limit
+        assert!(portfolio
+            .generate_order(&entry_signal(&markets[0]))
+            .unwrap()
+            .is_some());
+        assert!(portfolio
+            .generate_order(&entry_signal(&markets[1]))
+            .unwrap()
+            .is_some());
+        assert!(portfolio
+            .generate_order(&entry_signal(&markets[2]))
+            .unwrap()
+            .is_none());
+
+        // Regenerating an entry for a pending Position does not count it twice
+        assert!(portfolio
+            .generate_order(&entry_signal(&markets[0]))
+            .unwrap()
+            .is_some());
+
+        // Entry fills convert pending entries to open Positions, & exits free capacity
+        portfolio
+            .update_from_fill(&fill(&markets[0], Decision::Long, 1.0))
+            .unwrap();
+        assert!(portfolio
+            .generate_order(&entry_signal(&markets[2]))
+            .unwrap()
+            .is_none());
+        portfolio
+            .update_from_fill(&fill(&markets[0], Decision::CloseLong, -1.0))
+            .unwrap();
+        assert!(portfolio
+            .generate_order(&entry_signal(&markets[2]))
+            .unwrap()
+            .is_some());
+
+        // Max gross exposure: 100.0 + 100.0 is within the limit, whereas a third 100.0 is not
+        let mut portfolio = new_portfolio(PositionLimits {
+            max_open_positions: None,
+            max_gross_exposure: Some(250.0),
+        });
+        assert!(portfolio
+            .generate_order(&entry_signal(&markets[0]))
+            .unwrap()
+            .is_some());
+        assert!(portfolio
+            .generate_order(&entry_signal(&markets[1]))
+            .unwrap()
+            .is_some());
+        assert!(portfolio
+            .generate_order(&entry_signal(&markets[2]))
+            .unwrap()
+            .is_none());
+
+        // Rejected entries free the capacity reserved by their pending entry
+        let rejected = portfolio
+            .generate_order(&entry_signal(&markets[1]))
+            .unwrap()
+            .unwrap();
+        portfolio.on_order_rejected(&rejected);
+        assert!(portfolio
+            .generate_order(&entry_signal(&markets[2]))
+            .unwrap()
+            .is_some());
+    }
+
     #[test]
     fn update_from_fill_exiting_long_position_in_profit() {
         // Build Portfolio
@@ -1272,6 +1849,52 @@ pub mod tests {
         assert_eq!(updated_value, 200.0 + (200.0 - 100.0 - 6.0));
     }
 
+    #[test]
+    fn update_from_fill_exit_updates_statistics_of_initialised_market() {
+        use crate::{
+            portfolio::repository::in_memory::InMemoryRepository,
+            statistic::summary::trading::{Config as StatisticConfig, TradingSummary},
+        };
+
+        let market = Market::new("binance_spot", ("btc", "usdt", InstrumentKind::Spot));
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(Uuid::new_v4())
+            .markets(vec![market.clone()])
+            .starting_cash(1000.0)
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 1000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .unwrap();
+
+        let mut entry = fill_event();
+        entry.exchange = market.exchange.clone();
+        entry.instrument = market.instrument.clone();
+        entry.decision = Decision::Long;
+
+        let mut exit = entry.clone();
+        exit.decision = Decision::CloseLong;
+        exit.quantity = -1.0;
+        exit.fill_value_gross = 110.0;
+
+        // Exit must update the Statistics bootstrapped for the Market during initialisation
+        portfolio.update_from_fill(&entry).unwrap();
+        portfolio.update_from_fill(&exit).unwrap();
+
+        let stats = portfolio
+            .repository
+            .get_statistics(&MarketId::from(&market))
+            .unwrap();
+        assert_eq!(stats.pnl_returns.total.count, 1);
+    }
+
     #[test]
     fn update_from_fill_exiting_long_position_in_loss() {
         // Build Portfolio
@@ -1589,4 +2212,64 @@ pub mod tests {
 
         assert_eq!(actual, None);
     }
+
+    #[test]
+    fn snapshot_deep_copies_current_portfolio_state() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 1100.0,
+                available: 900.0,
+            })
+        });
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![position()]));
+        mock_repository.get_exited_positions = Some(|_| Ok(vec![position(), position()]));
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        let market = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+        let snapshot = portfolio.snapshot([market].iter()).unwrap();
+
+        assert_eq!(snapshot.open_positions.len(), 1);
+        assert_eq!(snapshot.closed_positions.len(), 2);
+        assert_eq!(snapshot.total_equity, 1100.0);
+        assert_eq!(snapshot.available_cash, 900.0);
+
+        let json = snapshot.to_json();
+        assert_eq!(json["total_equity"], 1100.0);
+        assert_eq!(json["available_cash"], 900.0);
+        assert_eq!(json["open_positions"].as_array().unwrap().len(), 1);
+        assert_eq!(json["closed_positions"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn trade_log_contains_exited_positions_sorted_by_exit_time() {
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_exited_positions: Some(|_| {
+                let base_time = Utc::now();
+                Ok([3, 1, 2]
+                    .into_iter()
+                    .map(|hours| {
+                        let mut exited = position();
+                        exited.position_id = format!("position_{hours}");
+                        exited.meta.enter_time = base_time;
+                        exited.meta.update_time = base_time + chrono::Duration::hours(hours);
+                        exited.meta.exit_balance = Some(Balance::default());
+                        exited
+                    })
+                    .collect())
+            }),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        let position_ids = portfolio
+            .trade_log()
+            .unwrap()
+            .map(|record| record.position_id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(position_ids, ["position_1", "position_2", "position_3"]);
+    }
 }
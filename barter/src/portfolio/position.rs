@@ -1,19 +1,93 @@
 use crate::{
+    data::market_trace_id,
     execution::{FeeAmount, Fees, FillEvent},
     portfolio::{error::PortfolioError, Balance},
     strategy::Decision,
 };
-use barter_data::event::{DataKind, MarketEvent};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    exchange::ExchangeId,
+    subscription::candle::Candle,
+};
 use barter_integration::model::{instrument::Instrument, Exchange, Side};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
+use prettytable::{format::consts::FORMAT_BOX_CHARS, Table};
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    convert::TryFrom,
+    fmt::{Debug, Display, Formatter},
+    path::Path,
+    str::FromStr,
+};
+use tracing::warn;
 use uuid::Uuid;
 
-/// Enters a new [`Position`].
-pub trait PositionEnterer {
+/// Enters a new [`Position`]. Object safe, so a Portfolio can store a
+/// `Box<dyn PositionEnterer>` to make the [`Position`] entry logic pluggable.
+pub trait PositionEnterer: Debug + Send {
     /// Returns a new [`Position`], given an input [`FillEvent`] & an associated engine_id.
-    fn enter(engine_id: Uuid, fill: &FillEvent) -> Result<Position, PortfolioError>;
+    fn enter(&self, engine_id: Uuid, fill: &FillEvent) -> Result<Position, PortfolioError>;
+
+    /// Validates the input [`FillEvent`] (see [`validate_entry_fill_with_clock_skew`]) before
+    /// delegating to [`PositionEnterer::enter`]. Should be preferred over
+    /// [`PositionEnterer::enter`] outside of tests.
+    fn enter_checked(&self, engine_id: Uuid, fill: &FillEvent) -> Result<Position, PortfolioError> {
+        validate_entry_fill_with_clock_skew(fill, self.max_clock_skew())?;
+        self.enter(engine_id, fill)
+    }
+
+    /// Maximum amount an entry [`FillEvent`] timestamp may be ahead of the local clock (eg/ due
+    /// to exchange clock skew) before it is rejected by [`PositionEnterer::enter_checked`].
+    /// Defaults to [`DEFAULT_MAX_CLOCK_SKEW_MS`].
+    fn max_clock_skew(&self) -> Duration {
+        Duration::milliseconds(DEFAULT_MAX_CLOCK_SKEW_MS)
+    }
+}
+
+/// Default maximum number of milliseconds an entry [`FillEvent`] timestamp may be ahead of the
+/// local clock before it is considered to be in the future. See [`validate_entry_fill`].
+pub const DEFAULT_MAX_CLOCK_SKEW_MS: i64 = 5_000;
+
+/// Default [`PositionEnterer`] that enters a [`Position`] at the [`FillEvent`] average price,
+/// with the entry fees deducted from the unrealised profit & loss.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct DefaultPositionEnterer;
+
+/// Validates that an entry [`FillEvent`] has a finite non-zero quantity, a finite positive
+/// fill_value_gross, finite non-negative [`Fees`], and a timestamp that is not in the future,
+/// tolerating [`DEFAULT_MAX_CLOCK_SKEW_MS`] of clock skew between the exchange & local clock.
+pub fn validate_entry_fill(fill: &FillEvent) -> Result<(), PortfolioError> {
+    validate_entry_fill_with_clock_skew(fill, Duration::milliseconds(DEFAULT_MAX_CLOCK_SKEW_MS))
+}
+
+/// Validates an entry [`FillEvent`] (see [`validate_entry_fill`]), tolerating a timestamp up to
+/// `max_clock_skew` ahead of the local clock.
+pub fn validate_entry_fill_with_clock_skew(
+    fill: &FillEvent,
+    max_clock_skew: Duration,
+) -> Result<(), PortfolioError> {
+    if !fill.quantity.is_finite() || fill.quantity == 0.0 {
+        return Err(PortfolioError::InvalidQuantity(fill.quantity));
+    }
+
+    if !fill.fill_value_gross.is_finite() || fill.fill_value_gross <= 0.0 {
+        return Err(PortfolioError::InvalidQuantity(fill.fill_value_gross));
+    }
+
+    if let Some(invalid_fee) = [fill.fees.exchange, fill.fees.slippage, fill.fees.network]
+        .into_iter()
+        .find(|fee| !fee.is_finite() || fee.is_sign_negative())
+    {
+        return Err(PortfolioError::InvalidFeeAmount(invalid_fee));
+    }
+
+    if fill.time > Utc::now() + max_clock_skew {
+        return Err(PortfolioError::FillTimeInFuture(fill.time));
+    }
+
+    Ok(())
 }
 
 /// Updates an open [`Position`].
@@ -30,6 +104,9 @@ pub trait PositionExiter {
     fn exit(&mut self, balance: Balance, fill: &FillEvent) -> Result<PositionExit, PortfolioError>;
 }
 
+/// Number of days a [`Position`] must be held for before it's gain is classified as long-term.
+pub const LONG_TERM_HOLD_PERIOD_DAYS: i64 = 365;
+
 /// Communicates a String represents a unique [`Position`] identifier.
 pub type PositionId = String;
 
@@ -42,7 +119,40 @@ pub fn determine_position_id(
     format!("{}_{}_{}_position", engine_id, exchange, instrument)
 }
 
+/// Persists the [`Position`]s to the file at the provided path as a pretty-printed JSON array,
+/// creating or truncating the file as required. Eg/ to save the results of a backtest.
+pub fn export_positions_json(positions: &[Position], path: &Path) -> Result<(), PortfolioError> {
+    let json = serde_json::to_string_pretty(positions)?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Loads the [`Position`]s persisted to the file at the provided path via
+/// [`export_positions_json`].
+pub fn import_positions_json(path: &Path) -> Result<Vec<Position>, PortfolioError> {
+    let json = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// Corporate action that alters the economics of a [`Position`] without a [`FillEvent`]. Applied
+/// via [`Position::apply_corporate_action`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum CorporateAction {
+    /// Stock split, eg/ a ratio of 2.0 for a 2-for-1 split, or 0.1 for a 1-for-10 reverse split.
+    StockSplit { ratio: f64 },
+    /// Cash dividend paid per share held.
+    CashDividend { per_share: f64 },
+    /// Spin-off of a new security, where `allocation_ratio` is the fraction of the [`Position`]
+    /// cost basis allocated to the `new_symbol`.
+    SpinOff {
+        new_symbol: String,
+        allocation_ratio: f64,
+    },
+}
+
 /// Data encapsulating the state of an ongoing or closed [`Position`].
+///
+/// [`Position`]s can be ranked by P&L via [`Position::cmp_by_ranking`] or [`RankedPosition`].
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Position {
     /// Unique identifier for a [`Position`] generated from an engine_id, [`Exchange`] & [`Instrument`].
@@ -102,15 +212,47 @@ pub struct Position {
 
     /// Realised P&L after the [`Position`] has closed.
     pub realised_profit_loss: f64,
+
+    /// Optional stop loss price, managed via [`Position::update_stop_loss`].
+    #[serde(default)]
+    pub stop_loss_price: Option<f64>,
+
+    /// Absolute quantity of the [`Position`] that has already been exited by partial exits.
+    #[serde(default)]
+    pub partially_exited_quantity: f64,
+
+    /// Realised P&L accumulated from partial exits of the [`Position`].
+    #[serde(default)]
+    pub total_realised_profit_loss: f64,
+
+    /// Annual carry rate (eg/ margin interest) in decimal form charged for holding the
+    /// [`Position`]. Conventionally negative for a short that earns a rebate. Deducted from the
+    /// unrealised P&L when non-zero - see [`Position::total_holding_cost_to_date`].
+    #[serde(default)]
+    pub annual_holding_rate: f64,
+
+    /// Cash dividends accumulated via [`Position::apply_corporate_action`]. Negative for a short
+    /// [`Position`], which owes dividends to the lender.
+    #[serde(default)]
+    pub received_dividends: f64,
+
+    /// Timestamped funding payments (eg/ perpetual futures funding) recorded via
+    /// [`Position::accrue_funding`]. Positive payments are a cost to the [`Position`].
+    #[cfg(feature = "funding-history")]
+    #[serde(default)]
+    pub funding_rate_series: Vec<(DateTime<Utc>, f64)>,
 }
 
-impl PositionEnterer for Position {
-    fn enter(engine_id: Uuid, fill: &FillEvent) -> Result<Position, PortfolioError> {
+impl PositionEnterer for DefaultPositionEnterer {
+    fn enter(&self, engine_id: Uuid, fill: &FillEvent) -> Result<Position, PortfolioError> {
         // Initialise Position Metadata
         let metadata = PositionMeta {
             enter_time: fill.market_meta.time,
             update_time: fill.time,
             exit_balance: None,
+            enter_trace_id: fill.trace_id,
+            last_update_trace_id: fill.trace_id,
+            exit_trace_id: None,
         };
 
         // Enter fees
@@ -141,6 +283,13 @@ impl PositionEnterer for Position {
             current_value_gross: fill.fill_value_gross,
             unrealised_profit_loss,
             realised_profit_loss: 0.0,
+            stop_loss_price: None,
+            partially_exited_quantity: 0.0,
+            total_realised_profit_loss: 0.0,
+            annual_holding_rate: 0.0,
+            received_dividends: 0.0,
+            #[cfg(feature = "funding-history")]
+            funding_rate_series: Vec::new(),
         })
     }
 }
@@ -156,18 +305,7 @@ impl PositionUpdater for Position {
             DataKind::Liquidation(_) => return None,
         };
 
-        self.meta.update_time = market.exchange_time;
-
-        self.current_symbol_price = close;
-
-        // Market value gross
-        self.current_value_gross = close * self.quantity.abs();
-
-        // Unreal profit & loss
-        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
-
-        // Return a PositionUpdate event that communicates the change in state
-        Some(PositionUpdate::from(self))
+        Some(self.apply_price(close, market.exchange_time, market_trace_id(market)))
     }
 }
 
@@ -189,25 +327,83 @@ impl PositionExiter for Position {
         self.exit_value_gross = fill.fill_value_gross;
         self.exit_avg_price_gross = Position::calculate_avg_price_gross(fill);
 
-        // Result profit & loss
+        // Result profit & loss, net of holding costs accrued until the exit fill
+        self.meta.update_time = fill.time;
         self.realised_profit_loss = self.calculate_realised_profit_loss();
         self.unrealised_profit_loss = self.realised_profit_loss;
+        debug_assert!(
+            (self.net_exit_proceeds()
+                - self.net_entry_cost()
+                - self.accrued_holding_cost()
+                - self.realised_profit_loss)
+                .abs()
+                <= POSITION_CONSISTENCY_EPSILON * self.enter_value_gross.abs().max(1.0),
+            "net cash flows of exited Position are inconsistent with it's realised profit & loss"
+        );
 
         // Metadata
         balance.total += self.realised_profit_loss;
-        self.meta.update_time = fill.time;
         self.meta.exit_balance = Some(balance);
+        self.meta.exit_trace_id = Some(fill.trace_id);
+
+        debug_assert!(
+            self.exit_sanity_check().is_ok(),
+            "exited Position is inconsistent: {:?}",
+            self.exit_sanity_check()
+        );
 
         PositionExit::try_from(self)
     }
 }
 
+/// Constructs a [`PortfolioError::InconsistentPositionState`] for the provided field.
+fn inconsistent_position_state(
+    field: &'static str,
+    expected: &dyn Display,
+    actual: f64,
+) -> PortfolioError {
+    PortfolioError::InconsistentPositionState {
+        field,
+        expected: expected.to_string(),
+        actual: actual.to_string(),
+    }
+}
+
+/// Verifies a fee total equals the sum of it's respective [`Fees`].
+fn check_fees_total(field: &'static str, fees: &Fees, actual: f64) -> Result<(), PortfolioError> {
+    let expected = fees.calculate_total_fees();
+    let tolerance = POSITION_CONSISTENCY_EPSILON * expected.abs().max(1.0);
+    if (actual - expected).abs() > tolerance {
+        return Err(inconsistent_position_state(field, &expected, actual));
+    }
+
+    Ok(())
+}
+
 impl Position {
     /// Returns a [`PositionBuilder`] instance.
     pub fn builder() -> PositionBuilder {
         PositionBuilder::new()
     }
 
+    /// Enters a new [`Position`] using the [`DefaultPositionEnterer`].
+    pub fn enter(engine_id: Uuid, fill: &FillEvent) -> Result<Position, PortfolioError> {
+        DefaultPositionEnterer.enter(engine_id, fill)
+    }
+
+    /// Enters a new [`Position`] using the [`DefaultPositionEnterer`], validating the input
+    /// [`FillEvent`] first. See [`PositionEnterer::enter_checked`].
+    pub fn enter_checked(engine_id: Uuid, fill: &FillEvent) -> Result<Position, PortfolioError> {
+        DefaultPositionEnterer.enter_checked(engine_id, fill)
+    }
+
+    /// Returns the well-known [`ExchangeId`] of this [`Position`]'s [`Exchange`], or `None` if
+    /// the [`Exchange`] is not a well-known [`ExchangeId`]. Enables exhaustive matching on the
+    /// [`Exchange`] a [`Position`] was entered on.
+    pub fn exchange_id(&self) -> Option<ExchangeId> {
+        ExchangeId::from_str(self.exchange.as_ref()).ok()
+    }
+
     /// Calculates the [`Position::enter_avg_price_gross`] or [`Position::exit_avg_price_gross`] of
     /// a [`FillEvent`].
     pub fn calculate_avg_price_gross(fill: &FillEvent) -> f64 {
@@ -234,24 +430,54 @@ impl Position {
         }
     }
 
-    /// Calculate the approximate [`Position::unrealised_profit_loss`] of a [`Position`].
+    /// Calculate the approximate [`Position::unrealised_profit_loss`] of a [`Position`], net of
+    /// any holding costs if the [`Position::annual_holding_rate`] is non-zero.
     pub fn calculate_unrealised_profit_loss(&self) -> f64 {
         let approx_total_fees = self.enter_fees_total * 2.0;
 
-        match self.side {
+        let unrealised_profit_loss = match self.side {
             Side::Buy => self.current_value_gross - self.enter_value_gross - approx_total_fees,
             Side::Sell => self.enter_value_gross - self.current_value_gross - approx_total_fees,
+        };
+
+        unrealised_profit_loss - self.accrued_holding_cost()
+    }
+
+    /// Calculate the daily carry cost of holding the [`Position`] at the provided annual rate,
+    /// using a 365 day year. A negative rate (eg/ a short rebate) yields a credit.
+    pub fn holding_cost_per_day(&self, annual_rate: f64) -> f64 {
+        self.current_value_gross.abs() * annual_rate / 365.0
+    }
+
+    /// Calculate the carry cost of holding the [`Position`] from entry until the last update, at
+    /// the provided annual rate. See [`Position::holding_cost_per_day`].
+    pub fn total_holding_cost_to_date(&self, annual_rate: f64) -> f64 {
+        let held_secs = (self.meta.update_time - self.meta.enter_time).num_seconds() as f64;
+        let held_days = held_secs / (24.0 * 60.0 * 60.0);
+
+        self.holding_cost_per_day(annual_rate) * held_days
+    }
+
+    /// Calculate the carry cost accrued by the [`Position`] at it's
+    /// [`Position::annual_holding_rate`], or zero if the rate is zero.
+    pub fn accrued_holding_cost(&self) -> f64 {
+        match self.annual_holding_rate == 0.0 {
+            true => 0.0,
+            false => self.total_holding_cost_to_date(self.annual_holding_rate),
         }
     }
 
-    /// Calculate the exact [`Position::realised_profit_loss`] of a [`Position`].
+    /// Calculate the exact [`Position::realised_profit_loss`] of a [`Position`], net of any
+    /// holding costs if the [`Position::annual_holding_rate`] is non-zero.
     pub fn calculate_realised_profit_loss(&self) -> f64 {
         let total_fees = self.enter_fees_total + self.exit_fees_total;
 
-        match self.side {
+        let realised_profit_loss = match self.side {
             Side::Buy => self.exit_value_gross - self.enter_value_gross - total_fees,
             Side::Sell => self.enter_value_gross - self.exit_value_gross - total_fees,
-        }
+        };
+
+        realised_profit_loss - self.accrued_holding_cost()
     }
 
     /// Calculate the PnL return of a closed [`Position`] - assumed [`Position::realised_profit_loss`] is
@@ -259,163 +485,1031 @@ impl Position {
     pub fn calculate_profit_loss_return(&self) -> f64 {
         self.realised_profit_loss / self.enter_value_gross
     }
-}
-
-/// Builder to construct [`Position`] instances.
-#[derive(Debug, Default)]
-pub struct PositionBuilder {
-    pub position_id: Option<PositionId>,
-    pub exchange: Option<Exchange>,
-    pub instrument: Option<Instrument>,
-    pub meta: Option<PositionMeta>,
-    pub side: Option<Side>,
-    pub quantity: Option<f64>,
-    pub enter_fees: Option<Fees>,
-    pub enter_fees_total: Option<FeeAmount>,
-    pub enter_avg_price_gross: Option<f64>,
-    pub enter_value_gross: Option<f64>,
-    pub exit_fees: Option<Fees>,
-    pub exit_fees_total: Option<FeeAmount>,
-    pub exit_avg_price_gross: Option<f64>,
-    pub exit_value_gross: Option<f64>,
-    pub current_symbol_price: Option<f64>,
-    pub current_value_gross: Option<f64>,
-    pub unrealised_profit_loss: Option<f64>,
-    pub realised_profit_loss: Option<f64>,
-}
 
-impl PositionBuilder {
-    pub fn new() -> Self {
-        Self::default()
+    /// Calculate the absolute quantity of the [`Position`] that remains open after any partial
+    /// exits.
+    pub fn remaining_quantity(&self) -> f64 {
+        self.quantity.abs() - self.partially_exited_quantity
     }
 
-    pub fn position_id(self, value: PositionId) -> Self {
-        Self {
-            position_id: Some(value),
-            ..self
+    /// Calculate the signed market exposure of a [`Position`], where the current_value_gross is
+    /// positive for a long & negative for a short.
+    pub fn net_exposure(&self) -> f64 {
+        match self.side {
+            Side::Buy => self.current_value_gross,
+            Side::Sell => -self.current_value_gross,
         }
     }
 
-    pub fn exchange(self, value: Exchange) -> Self {
-        Self {
-            exchange: Some(value),
-            ..self
+    /// Calculate the cost basis of a [`Position`] for tax lot accounting.
+    ///
+    /// Long: amount paid to acquire the asset (enter_value_gross + enter_fees_total).
+    /// Short: proceeds received net of fees (enter_value_gross - enter_fees_total).
+    pub fn cost_basis(&self) -> f64 {
+        match self.side {
+            Side::Buy => self.enter_value_gross + self.enter_fees_total,
+            Side::Sell => self.enter_value_gross - self.enter_fees_total,
         }
     }
 
-    pub fn instrument(self, value: Instrument) -> Self {
-        Self {
-            instrument: Some(value),
-            ..self
+    /// Calculate the taxable gain of a closed [`Position`] relative to it's
+    /// [`Position::cost_basis`], net of exit fees.
+    ///
+    /// Long: (exit_value_gross - exit_fees_total) - cost_basis.
+    /// Short: cost_basis - (exit_value_gross + exit_fees_total).
+    pub fn taxable_gain(&self) -> f64 {
+        match self.side {
+            Side::Buy => self.exit_value_gross - self.exit_fees_total - self.cost_basis(),
+            Side::Sell => self.cost_basis() - self.exit_value_gross - self.exit_fees_total,
         }
     }
 
-    pub fn meta(self, value: PositionMeta) -> Self {
-        Self {
-            meta: Some(value),
-            ..self
+    /// Calculate the net cash paid to enter the [`Position`].
+    ///
+    /// Long: amount paid to acquire the asset (enter_value_gross + enter_fees_total).
+    /// Short: negated proceeds received net of fees (-(enter_value_gross - enter_fees_total)).
+    pub fn net_entry_cost(&self) -> f64 {
+        match self.side {
+            Side::Buy => self.enter_value_gross + self.enter_fees_total,
+            Side::Sell => self.enter_fees_total - self.enter_value_gross,
         }
     }
 
-    pub fn side(self, value: Side) -> Self {
-        Self {
-            side: Some(value),
-            ..self
+    /// Calculate the net cash received from exiting the [`Position`], such that
+    /// `net_exit_proceeds - net_entry_cost - accrued_holding_cost == realised_profit_loss` for a
+    /// closed [`Position`].
+    ///
+    /// Long: proceeds received net of fees (exit_value_gross - exit_fees_total).
+    /// Short: negated amount paid to buy back the asset (-(exit_value_gross + exit_fees_total)).
+    pub fn net_exit_proceeds(&self) -> f64 {
+        match self.side {
+            Side::Buy => self.exit_value_gross - self.exit_fees_total,
+            Side::Sell => -(self.exit_value_gross + self.exit_fees_total),
         }
     }
 
-    pub fn quantity(self, value: f64) -> Self {
-        Self {
-            quantity: Some(value),
-            ..self
-        }
+    /// Returns the [`Position::net_entry_cost`] of a closed [`Position`], or `None` if the
+    /// [`Position`] is still open.
+    pub fn net_entry_cost_checked(&self) -> Option<f64> {
+        self.meta.exit_balance.map(|_| self.net_entry_cost())
     }
 
-    pub fn enter_fees(self, value: Fees) -> Self {
-        Self {
-            enter_fees: Some(value),
-            ..self
-        }
+    /// Returns the [`Position::net_exit_proceeds`] of a closed [`Position`], or `None` if the
+    /// [`Position`] is still open.
+    pub fn net_exit_proceeds_checked(&self) -> Option<f64> {
+        self.meta.exit_balance.map(|_| self.net_exit_proceeds())
     }
 
-    pub fn enter_fees_total(self, value: FeeAmount) -> Self {
-        Self {
-            enter_fees_total: Some(value),
-            ..self
-        }
+    /// Returns the [`Duration`] a closed [`Position`] was held for, or `None` if the [`Position`]
+    /// is still open.
+    pub fn hold_period(&self) -> Option<Duration> {
+        self.meta
+            .exit_balance
+            .map(|_| self.meta.update_time - self.meta.enter_time)
     }
 
-    pub fn enter_avg_price_gross(self, value: f64) -> Self {
-        Self {
-            enter_avg_price_gross: Some(value),
-            ..self
+    /// Verifies the internal consistency of the [`Position`]:
+    ///  - Open [`Position`]s have zero exit fees, value & price.
+    ///  - Closed [`Position`]s have a non-zero exit value & price.
+    ///  - The enter & exit fee totals equal the sum of their respective [`Fees`].
+    ///
+    /// Returns [`PortfolioError::InconsistentPositionState`] detailing the first inconsistent
+    /// field found.
+    pub fn sanity_check(&self) -> Result<(), PortfolioError> {
+        match self.meta.exit_balance {
+            None => {
+                let exit_fields = [
+                    ("exit_fees_total", self.exit_fees_total),
+                    ("exit_avg_price_gross", self.exit_avg_price_gross),
+                    ("exit_value_gross", self.exit_value_gross),
+                ];
+
+                for (field, actual) in exit_fields {
+                    if actual != 0.0 {
+                        return Err(inconsistent_position_state(field, &0.0, actual));
+                    }
+                }
+            }
+            Some(_) => self.exit_sanity_check()?,
         }
+
+        check_fees_total("enter_fees_total", &self.enter_fees, self.enter_fees_total)?;
+        check_fees_total("exit_fees_total", &self.exit_fees, self.exit_fees_total)
     }
 
-    pub fn enter_value_gross(self, value: f64) -> Self {
-        Self {
-            enter_value_gross: Some(value),
-            ..self
+    /// Verifies the consistency of the exit fields set by [`PositionExiter::exit`]: a non-zero
+    /// exit value & price, and an exit fee total equal to the sum of the exit [`Fees`].
+    fn exit_sanity_check(&self) -> Result<(), PortfolioError> {
+        let exit_fields = [
+            ("exit_avg_price_gross", self.exit_avg_price_gross),
+            ("exit_value_gross", self.exit_value_gross),
+        ];
+
+        for (field, actual) in exit_fields {
+            if actual == 0.0 {
+                return Err(inconsistent_position_state(field, &"non-zero", actual));
+            }
         }
+
+        check_fees_total("exit_fees_total", &self.exit_fees, self.exit_fees_total)
     }
 
-    pub fn exit_fees(self, value: Fees) -> Self {
-        Self {
-            exit_fees: Some(value),
-            ..self
+    /// Determines if a closed [`Position`] was held for longer than
+    /// [`LONG_TERM_HOLD_PERIOD_DAYS`], classifying it's gain as long-term. Open [`Position`]s
+    /// are never long-term.
+    pub fn is_long_term_hold(&self) -> bool {
+        self.hold_period()
+            .is_some_and(|hold| hold > Duration::days(LONG_TERM_HOLD_PERIOD_DAYS))
+    }
+
+    /// Determines if this [`Position`] was entered strictly before the other [`Position`].
+    pub fn entered_before(&self, other: &Position) -> bool {
+        self.meta.enter_time < other.meta.enter_time
+    }
+
+    /// Determines if this [`Position`] was entered strictly after the other [`Position`].
+    pub fn entered_after(&self, other: &Position) -> bool {
+        self.meta.enter_time > other.meta.enter_time
+    }
+
+    /// Returns the time a closed [`Position`] was exited, or `None` if it is still open.
+    pub fn exit_time(&self) -> Option<DateTime<Utc>> {
+        self.meta.exit_balance.map(|_| self.meta.update_time)
+    }
+
+    /// Updates an open [`Position`] using the latest input [`MarketEvent`], rejecting the event
+    /// if it is not newer than the [`PositionMeta::update_time`]. Prevents an out-of-order data
+    /// feed from silently rewinding the [`Position`] price. The [`Position`] is left untouched if
+    /// a [`PortfolioError::StaleMarketEvent`] is returned.
+    ///
+    /// See [`PositionUpdater::update`] for the unchecked variant used in hot paths.
+    pub fn update_checked(
+        &mut self,
+        market: &MarketEvent<Instrument, DataKind>,
+    ) -> Result<Option<PositionUpdate>, PortfolioError> {
+        if market.exchange_time <= self.meta.update_time {
+            return Err(PortfolioError::StaleMarketEvent {
+                event_ts: market.exchange_time,
+                last_update_ts: self.meta.update_time,
+            });
         }
+
+        Ok(self.update(market))
     }
 
-    pub fn exit_fees_total(self, value: FeeAmount) -> Self {
-        Self {
-            exit_fees_total: Some(value),
-            ..self
+    /// Updates an open [`Position`] using the close price of the input [`Candle`], returning a
+    /// [`PositionUpdate`] that communicates the open [`Position`]'s change in state.
+    ///
+    /// Lower-level alternative to [`PositionUpdater::update`] that does not require a
+    /// [`MarketEvent`] to be constructed (eg/ batch simulations over [`Candle`]s).
+    pub fn update_with_candle(
+        &mut self,
+        candle: &Candle,
+        timestamp: DateTime<Utc>,
+    ) -> PositionUpdate {
+        self.update_with_price(candle.close, timestamp)
+    }
+
+    /// Updates an open [`Position`] using the latest symbol close price, returning a
+    /// [`PositionUpdate`] that communicates the open [`Position`]'s change in state.
+    ///
+    /// No event triggers the update, so a new trace UUID is generated for it.
+    pub fn update_with_price(&mut self, close: f64, timestamp: DateTime<Utc>) -> PositionUpdate {
+        self.apply_price(close, timestamp, Uuid::new_v4())
+    }
+
+    /// Updates an open [`Position`] using the latest symbol close price, recording the trace
+    /// UUID of the event that triggered the update.
+    fn apply_price(
+        &mut self,
+        close: f64,
+        timestamp: DateTime<Utc>,
+        trace_id: Uuid,
+    ) -> PositionUpdate {
+        self.meta.update_time = timestamp;
+        self.meta.last_update_trace_id = trace_id;
+
+        self.current_symbol_price = close;
+
+        // Market value gross
+        self.current_value_gross = close * self.quantity.abs();
+
+        // Unreal profit & loss
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+
+        // Return a PositionUpdate event that communicates the change in state
+        PositionUpdate::from(self)
+    }
+
+    /// Updates an open [`Position`] using a new symbol price, without requiring a
+    /// [`MarketEvent`]. Minimal price update path for unit tests & simple backtests.
+    ///
+    /// Returns [`PortfolioError::InvalidPrice`] if the price is not finite & positive, leaving
+    /// the [`Position`] untouched.
+    pub fn update_current_price(
+        &mut self,
+        price: f64,
+        timestamp: DateTime<Utc>,
+    ) -> Result<(), PortfolioError> {
+        if !price.is_finite() || price <= 0.0 {
+            return Err(PortfolioError::InvalidPrice(price));
         }
+
+        self.update_with_price(price, timestamp);
+        Ok(())
     }
 
-    pub fn exit_avg_price_gross(self, value: f64) -> Self {
-        Self {
-            exit_avg_price_gross: Some(value),
-            ..self
+    /// Scales into an open [`Position`] using an entry [`FillEvent`] of the same [`Side`],
+    /// blending the [`Position::enter_avg_price_gross`] weighted by quantity & accumulating the
+    /// entry [`Fees`]. The [`Position::unrealised_profit_loss`] is recomputed using the blended
+    /// entry.
+    ///
+    /// Returns [`PortfolioError::CannotScaleInPosition`] if the [`Position`] has exited or the
+    /// [`FillEvent`] is of the opposite [`Side`], leaving the [`Position`] untouched.
+    pub fn scale_in(&mut self, fill: &FillEvent) -> Result<(), PortfolioError> {
+        validate_entry_fill(fill)?;
+        if self.meta.exit_balance.is_some() || Position::parse_entry_side(fill)? != self.side {
+            return Err(PortfolioError::CannotScaleInPosition);
         }
+
+        // Blended entry
+        self.enter_value_gross += fill.fill_value_gross;
+        self.quantity += fill.quantity;
+        self.enter_avg_price_gross = self.enter_value_gross / self.quantity.abs();
+
+        // Accumulated entry fees
+        self.enter_fees = Fees {
+            exchange: self.enter_fees.exchange + fill.fees.exchange,
+            slippage: self.enter_fees.slippage + fill.fees.slippage,
+            network: self.enter_fees.network + fill.fees.network,
+        };
+        self.enter_fees_total = self.enter_fees.calculate_total_fees();
+
+        // Market value gross & unreal profit & loss using the blended entry
+        self.current_value_gross = self.current_symbol_price * self.quantity.abs();
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+
+        self.meta.update_time = fill.time;
+        self.meta.last_update_trace_id = fill.trace_id;
+
+        Ok(())
     }
 
-    pub fn exit_value_gross(self, value: f64) -> Self {
-        Self {
-            exit_value_gross: Some(value),
-            ..self
+    /// Applies a [`CorporateAction`] to the [`Position`]:
+    ///  - [`CorporateAction::StockSplit`]: scales the quantity by the ratio & all per share prices
+    ///    by the inverse, leaving the gross values unchanged.
+    ///  - [`CorporateAction::CashDividend`]: accumulates `per_share * quantity` in the
+    ///    [`Position::received_dividends`].
+    ///  - [`CorporateAction::SpinOff`]: reduces the entry cost basis by the `allocation_ratio`.
+    ///    A [`Position`] in the `new_symbol` must be tracked separately.
+    ///
+    /// Returns [`PortfolioError::InvalidCorporateAction`] if the split ratio is not positive, the
+    /// dividend is negative, or the spin-off allocation ratio is not within (0.0, 1.0). The
+    /// [`Position`] is left untouched if an error is returned.
+    pub fn apply_corporate_action(
+        &mut self,
+        action: CorporateAction,
+    ) -> Result<(), PortfolioError> {
+        match action {
+            CorporateAction::StockSplit { ratio } => {
+                if !(ratio > 0.0 && ratio.is_finite()) {
+                    return Err(PortfolioError::InvalidCorporateAction(ratio));
+                }
+
+                self.quantity *= ratio;
+                self.partially_exited_quantity *= ratio;
+                self.enter_avg_price_gross /= ratio;
+                self.current_symbol_price /= ratio;
+                self.stop_loss_price = self.stop_loss_price.map(|stop_price| stop_price / ratio);
+
+                self.enter_value_gross = self.enter_avg_price_gross * self.quantity.abs();
+                self.current_value_gross = self.current_symbol_price * self.quantity.abs();
+            }
+            CorporateAction::CashDividend { per_share } => {
+                if !(per_share >= 0.0 && per_share.is_finite()) {
+                    return Err(PortfolioError::InvalidCorporateAction(per_share));
+                }
+
+                self.received_dividends += per_share * self.quantity;
+            }
+            CorporateAction::SpinOff {
+                allocation_ratio, ..
+            } => {
+                if !(allocation_ratio > 0.0 && allocation_ratio < 1.0) {
+                    return Err(PortfolioError::InvalidCorporateAction(allocation_ratio));
+                }
+
+                self.enter_avg_price_gross *= 1.0 - allocation_ratio;
+                self.enter_value_gross *= 1.0 - allocation_ratio;
+                self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+            }
         }
+
+        Ok(())
     }
 
-    pub fn current_symbol_price(self, value: f64) -> Self {
-        Self {
-            current_symbol_price: Some(value),
-            ..self
+    /// Applies a continuous futures roll price adjustment to the [`Position`], multiplying every
+    /// price (including the [`Position::stop_loss_price`]) & gross value by the
+    /// `adjustment_factor` (eg/ 0.99 for a 1% contango roll), before recalculating the P&L.
+    /// Quantities & fees are left unchanged.
+    ///
+    /// Returns [`PortfolioError::InvalidPriceAdjustment`] if the `adjustment_factor` is not a
+    /// finite positive value, leaving the [`Position`] untouched.
+    pub fn apply_price_adjustment(&mut self, adjustment_factor: f64) -> Result<(), PortfolioError> {
+        if !(adjustment_factor > 0.0 && adjustment_factor.is_finite()) {
+            return Err(PortfolioError::InvalidPriceAdjustment(adjustment_factor));
+        }
+
+        self.enter_avg_price_gross *= adjustment_factor;
+        self.enter_value_gross *= adjustment_factor;
+        self.exit_avg_price_gross *= adjustment_factor;
+        self.exit_value_gross *= adjustment_factor;
+        self.current_symbol_price *= adjustment_factor;
+        self.current_value_gross *= adjustment_factor;
+        self.stop_loss_price = self
+            .stop_loss_price
+            .map(|stop_price| stop_price * adjustment_factor);
+
+        match self.meta.exit_balance {
+            None => self.unrealised_profit_loss = self.calculate_unrealised_profit_loss(),
+            Some(_) => self.realised_profit_loss = self.calculate_realised_profit_loss(),
         }
+
+        Ok(())
     }
 
-    pub fn current_value_gross(self, value: f64) -> Self {
-        Self {
-            current_value_gross: Some(value),
-            ..self
+    /// Returns a clone of the [`Position`] scaled to a common size, such that the
+    /// `enter_value_gross` equals the `reference_notional`. Useful for comparing Positions
+    /// across instruments with different notional values (eg/ multi-instrument statistics).
+    ///
+    /// Every quantity, gross value, fee & P&L value is scaled by
+    /// `reference_notional / enter_value_gross`, whereas per-unit prices are left unchanged. A
+    /// [`Position`] with a zero `enter_value_gross` cannot be scaled & is returned unchanged,
+    /// logging a warning since statistics generated from it will not be normalised.
+    pub fn normalize_to_unit_notional(&self, reference_notional: f64) -> Position {
+        let mut normalized = self.clone();
+
+        let scale = reference_notional / self.enter_value_gross;
+        if !scale.is_finite() {
+            warn!(
+                position_id = &*self.position_id,
+                enter_value_gross = self.enter_value_gross,
+                reference_notional,
+                outcome = "returning unscaled Position",
+                "cannot normalise Position to unit notional"
+            );
+            return normalized;
         }
+
+        let scale_fees = |fees: &Fees| Fees {
+            exchange: fees.exchange * scale,
+            slippage: fees.slippage * scale,
+            network: fees.network * scale,
+        };
+
+        normalized.quantity *= scale;
+        normalized.partially_exited_quantity *= scale;
+        normalized.enter_fees = scale_fees(&self.enter_fees);
+        normalized.enter_fees_total *= scale;
+        normalized.enter_value_gross = reference_notional;
+        normalized.exit_fees = scale_fees(&self.exit_fees);
+        normalized.exit_fees_total *= scale;
+        normalized.exit_value_gross *= scale;
+        normalized.current_value_gross *= scale;
+        normalized.unrealised_profit_loss *= scale;
+        normalized.realised_profit_loss *= scale;
+        normalized.total_realised_profit_loss *= scale;
+        normalized.received_dividends *= scale;
+
+        #[cfg(feature = "funding-history")]
+        normalized
+            .funding_rate_series
+            .iter_mut()
+            .for_each(|(_, payment)| *payment *= scale);
+
+        normalized
     }
 
-    pub fn unrealised_profit_loss(self, value: f64) -> Self {
-        Self {
-            unrealised_profit_loss: Some(value),
-            ..self
+    /// Updates the [`Position::stop_loss_price`], validating that the stop is on the correct side
+    /// of the `current_symbol_price` (below for a long, above for a short).
+    pub fn update_stop_loss(&mut self, stop_price: f64) -> Result<(), PortfolioError> {
+        let valid = match self.side {
+            Side::Buy => stop_price < self.current_symbol_price,
+            Side::Sell => stop_price > self.current_symbol_price,
+        };
+
+        if !valid {
+            return Err(PortfolioError::InvalidStopLoss {
+                stop_price,
+                current_price: self.current_symbol_price,
+            });
         }
+
+        self.stop_loss_price = Some(stop_price);
+        Ok(())
     }
 
-    pub fn realised_profit_loss(self, value: f64) -> Self {
-        Self {
-            realised_profit_loss: Some(value),
-            ..self
+    /// Determines if the `current_symbol_price` has crossed the [`Position::stop_loss_price`].
+    /// Always false if no stop loss has been set.
+    pub fn is_stop_triggered(&self) -> bool {
+        self.stop_loss_price
+            .is_some_and(|stop_price| match self.side {
+                Side::Buy => self.current_symbol_price <= stop_price,
+                Side::Sell => self.current_symbol_price >= stop_price,
+            })
+    }
+
+    /// Records a funding payment (eg/ a perpetual futures funding rate settlement) in the
+    /// [`Position::funding_rate_series`]. Positive payments are a cost to the [`Position`],
+    /// negative payments are received.
+    ///
+    /// The payment is only recorded - it is not deducted from the [`Position`] P&L.
+    #[cfg(feature = "funding-history")]
+    pub fn accrue_funding(&mut self, timestamp: DateTime<Utc>, payment: f64) {
+        self.funding_rate_series.push((timestamp, payment));
+    }
+
+    /// Calculate the total of every funding payment in the [`Position::funding_rate_series`].
+    #[cfg(feature = "funding-history")]
+    pub fn total_funding_cost(&self) -> f64 {
+        self.funding_rate_series
+            .iter()
+            .map(|(_, payment)| payment)
+            .sum()
+    }
+
+    /// Calculate the average funding cost per distinct calendar day (UTC) in the
+    /// [`Position::funding_rate_series`]. Returns zero if no funding has been accrued.
+    #[cfg(feature = "funding-history")]
+    pub fn avg_daily_funding_cost(&self) -> f64 {
+        let days = self
+            .funding_rate_series
+            .iter()
+            .map(|(timestamp, _)| timestamp.date_naive())
+            .collect::<std::collections::HashSet<_>>()
+            .len();
+
+        match days {
+            0 => 0.0,
+            days => self.total_funding_cost() / days as f64,
         }
     }
 
-    pub fn build(self) -> Result<Position, PortfolioError> {
+    /// Calculate the unrealised P&L of the [`Position`] if the `current_symbol_price` moved by
+    /// the provided fraction (eg/ -0.1 for a 10% crash), without mutating the [`Position`].
+    ///
+    /// Uses the same approximation as [`Position::calculate_unrealised_profit_loss`], so the
+    /// result for a `price_move_pct` of 0.0 is the current unrealised P&L.
+    pub fn stress_test(&self, price_move_pct: f64) -> f64 {
+        let stressed_price = self.current_symbol_price * (1.0 + price_move_pct);
+
+        let mut stressed = self.clone();
+        stressed.current_symbol_price = stressed_price;
+        stressed.current_value_gross = stressed_price * self.quantity.abs();
+        stressed.calculate_unrealised_profit_loss()
+    }
+
+    /// Calculate the [`Position::stress_test`] unrealised P&L for each of the provided price
+    /// move scenarios.
+    pub fn stress_test_series(&self, price_moves: &[f64]) -> Vec<f64> {
+        price_moves
+            .iter()
+            .map(|&price_move_pct| self.stress_test(price_move_pct))
+            .collect()
+    }
+
+    /// Decompose the [`Position::realised_profit_loss`] of a closed [`Position`] into the gross
+    /// P&L and the contribution of each fee type. Costs are negative, gains are positive.
+    ///
+    /// Keys: "gross_pnl", "exchange_fees", "slippage_fees", "network_fees" & "net_pnl", where
+    /// gross_pnl + exchange_fees + slippage_fees + network_fees == net_pnl.
+    pub fn pnl_attribution_by_fee_type(&self) -> HashMap<&'static str, f64> {
+        let gross_pnl = match self.side {
+            Side::Buy => self.exit_value_gross - self.enter_value_gross,
+            Side::Sell => self.enter_value_gross - self.exit_value_gross,
+        };
+
+        HashMap::from([
+            ("gross_pnl", gross_pnl),
+            (
+                "exchange_fees",
+                -(self.enter_fees.exchange + self.exit_fees.exchange),
+            ),
+            (
+                "slippage_fees",
+                -(self.enter_fees.slippage + self.exit_fees.slippage),
+            ),
+            (
+                "network_fees",
+                -(self.enter_fees.network + self.exit_fees.network),
+            ),
+            ("net_pnl", self.realised_profit_loss),
+        ])
+    }
+
+    /// Builds a one-page summary [`Table`] of the [`Position`] entry & exit prices, fees, P&L,
+    /// return & hold duration. Open [`Position`]s report their unrealised P&L & the duration held
+    /// until the last update.
+    pub fn summary_table(&self) -> Table {
+        let (pnl_title, pnl) = match self.meta.exit_balance {
+            Some(_) => ("Realised P&L", self.realised_profit_loss),
+            None => ("Unrealised P&L", self.unrealised_profit_loss),
+        };
+
+        let exit_price = match self.meta.exit_balance {
+            Some(_) => format!("{:.4}", self.exit_avg_price_gross),
+            None => "n/a".to_owned(),
+        };
+
+        let hold_duration = self.meta.update_time - self.meta.enter_time;
+
+        let mut table = Table::new();
+        table.set_format(*FORMAT_BOX_CHARS);
+        table.set_titles(row![self.position_id, ""]);
+        table.add_row(row!["Exchange", self.exchange]);
+        table.add_row(row!["Instrument", self.instrument]);
+        table.add_row(row!["Side", self.side]);
+        table.add_row(row!["Quantity", format!("{:.4}", self.quantity)]);
+        table.add_row(row![
+            "Entry Price",
+            format!("{:.4}", self.enter_avg_price_gross)
+        ]);
+        table.add_row(row!["Exit Price", exit_price]);
+        table.add_row(row!["Entry Fees", format!("{:.4}", self.enter_fees_total)]);
+        table.add_row(row!["Exit Fees", format!("{:.4}", self.exit_fees_total)]);
+        table.add_row(row![pnl_title, format!("{:.4}", pnl)]);
+        table.add_row(row![
+            "Return",
+            format!("{:.2}%", pnl / self.enter_value_gross * 100.0)
+        ]);
+        table.add_row(row!["Hold Duration", format_duration(hold_duration)]);
+        table
+    }
+
+    /// Prints the [`Position::summary_table`] to stdout, eg/ for quick inspection in a REPL or
+    /// notebook.
+    pub fn print_summary(&self) {
+        self.summary_table().printstd();
+    }
+
+    /// Serialises this [`Position`] into a [`serde_json::Value`], returning [`Value::Null`] if
+    /// serialisation fails.
+    ///
+    /// [`Value::Null`]: serde_json::Value::Null
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::Value::Null)
+    }
+
+    /// Deserialises a [`Position`] from a [`serde_json::Value`].
+    pub fn from_json(value: serde_json::Value) -> Result<Position, PortfolioError> {
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Reconstructs a [`Position`] by replaying it's [`PositionEvent`]s in order, enabling
+    /// event-sourced Portfolio implementations. The first event must be a
+    /// [`PositionEvent::Opened`], followed by any number of [`PositionEvent::Updated`], and
+    /// optionally a final [`PositionEvent::Closed`].
+    ///
+    /// Trace identifiers are not captured by [`PositionUpdate`] & [`PositionExit`] events, so a
+    /// new trace identifier is generated for every replayed update & exit.
+    ///
+    /// Returns [`PortfolioError::PositionReplay`] if the events are empty, out of order, or
+    /// belong to a different [`Position`].
+    pub fn replay(events: &[PositionEvent]) -> Result<Position, PortfolioError> {
+        let (first, events) = events.split_first().ok_or(PortfolioError::PositionReplay(
+            "no PositionEvents to replay",
+        ))?;
+
+        let PositionEvent::Opened(position) = first else {
+            return Err(PortfolioError::PositionReplay(
+                "first PositionEvent is not PositionEvent::Opened",
+            ));
+        };
+        let mut position = *position.clone();
+
+        for event in events {
+            if position.meta.exit_balance.is_some() {
+                return Err(PortfolioError::PositionReplay(
+                    "PositionEvent follows PositionEvent::Closed",
+                ));
+            }
+
+            match event {
+                PositionEvent::Opened(_) => {
+                    return Err(PortfolioError::PositionReplay(
+                        "PositionEvent::Opened follows the first PositionEvent",
+                    ))
+                }
+                PositionEvent::Updated(update) => {
+                    if update.position_id != position.position_id {
+                        return Err(PortfolioError::PositionReplay(
+                            "PositionEvent::Updated position_id does not match",
+                        ));
+                    }
+
+                    position.meta.update_time = update.update_time;
+                    position.meta.last_update_trace_id = update.trace_id;
+                    position.current_symbol_price = update.current_symbol_price;
+                    position.current_value_gross = update.current_value_gross;
+                    position.unrealised_profit_loss = update.unrealised_profit_loss;
+                }
+                PositionEvent::Closed(exit) => {
+                    if exit.position_id != position.position_id {
+                        return Err(PortfolioError::PositionReplay(
+                            "PositionEvent::Closed position_id does not match",
+                        ));
+                    }
+
+                    position.meta.update_time = exit.exit_time;
+                    position.meta.exit_balance = Some(exit.exit_balance);
+                    position.meta.exit_trace_id = Some(exit.trace_id);
+                    position.exit_fees = exit.exit_fees;
+                    position.exit_fees_total = exit.exit_fees_total;
+                    position.exit_avg_price_gross = exit.exit_avg_price_gross;
+                    position.exit_value_gross = exit.exit_value_gross;
+                    position.realised_profit_loss = exit.realised_profit_loss;
+                    position.unrealised_profit_loss = exit.realised_profit_loss;
+                }
+            }
+        }
+
+        Ok(position)
+    }
+
+    /// Ranks [`Position`]s by their realised P&L if closed, or unrealised P&L if open, breaking
+    /// ties with the [`PositionMeta::enter_time`], eg/ `positions.sort_by(Position::cmp_by_ranking)`.
+    ///
+    /// Note the rank of an open [`Position`] will change as it is updated.
+    pub fn cmp_by_ranking(&self, other: &Self) -> Ordering {
+        self.ranking_profit_loss()
+            .total_cmp(&other.ranking_profit_loss())
+            .then_with(|| self.meta.enter_time.cmp(&other.meta.enter_time))
+    }
+
+    /// P&L used to rank [`Position`]s: the realised P&L of a closed [`Position`], or the
+    /// unrealised P&L of an open [`Position`].
+    fn ranking_profit_loss(&self) -> f64 {
+        match self.meta.exit_balance {
+            Some(_) => self.realised_profit_loss,
+            None => self.unrealised_profit_loss,
+        }
+    }
+}
+
+/// [`Position`] wrapper that is totally ordered by [`Position::cmp_by_ranking`], eg/ for
+/// efficient top N queries via a `BinaryHeap<RankedPosition>`.
+///
+/// Equality is consistent with the ranking, so two distinct [`Position`]s with the same P&L &
+/// enter time are equal [`RankedPosition`]s.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RankedPosition(pub Position);
+
+impl PartialEq for RankedPosition {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for RankedPosition {}
+
+impl PartialOrd for RankedPosition {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RankedPosition {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_by_ranking(&other.0)
+    }
+}
+
+/// Relative tolerance used by a strict [`PositionBuilder`] when comparing the realised profit &
+/// loss of a closed [`Position`] with the value derived from it's entry & exit fields.
+pub const POSITION_CONSISTENCY_EPSILON: f64 = 1e-9;
+
+/// Builder to construct [`Position`] instances.
+#[derive(Clone, Debug, Default)]
+pub struct PositionBuilder {
+    pub position_id: Option<PositionId>,
+    pub exchange: Option<Exchange>,
+    pub instrument: Option<Instrument>,
+    pub meta: Option<PositionMeta>,
+    pub side: Option<Side>,
+    pub quantity: Option<f64>,
+    pub enter_fees: Option<Fees>,
+    pub enter_fees_total: Option<FeeAmount>,
+    pub enter_avg_price_gross: Option<f64>,
+    pub enter_value_gross: Option<f64>,
+    pub exit_fees: Option<Fees>,
+    pub exit_fees_total: Option<FeeAmount>,
+    pub exit_avg_price_gross: Option<f64>,
+    pub exit_value_gross: Option<f64>,
+    pub current_symbol_price: Option<f64>,
+    pub current_value_gross: Option<f64>,
+    pub unrealised_profit_loss: Option<f64>,
+    pub realised_profit_loss: Option<f64>,
+    pub stop_loss_price: Option<f64>,
+    pub partially_exited_quantity: Option<f64>,
+    pub total_realised_profit_loss: Option<f64>,
+    pub annual_holding_rate: Option<f64>,
+    pub received_dividends: Option<f64>,
+    #[cfg(feature = "funding-history")]
+    pub funding_rate_series: Option<Vec<(DateTime<Utc>, f64)>>,
+    /// Verify a closed [`Position`] is internally consistent when building. See
+    /// [`PositionBuilder::strict`].
+    pub strict: bool,
+}
+
+impl PositionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Constructs a [`PositionBuilder`] populated with every field of the provided [`Position`].
+    /// Useful for building a hypothetical variation of a [`Position`] (eg/ a different exit),
+    /// since any modified values are validated by [`PositionBuilder::build`].
+    pub fn from_position(position: &Position) -> Self {
+        Self {
+            position_id: Some(position.position_id.clone()),
+            exchange: Some(position.exchange.clone()),
+            instrument: Some(position.instrument.clone()),
+            meta: Some(position.meta),
+            side: Some(position.side),
+            quantity: Some(position.quantity),
+            enter_fees: Some(position.enter_fees),
+            enter_fees_total: Some(position.enter_fees_total),
+            enter_avg_price_gross: Some(position.enter_avg_price_gross),
+            enter_value_gross: Some(position.enter_value_gross),
+            exit_fees: Some(position.exit_fees),
+            exit_fees_total: Some(position.exit_fees_total),
+            exit_avg_price_gross: Some(position.exit_avg_price_gross),
+            exit_value_gross: Some(position.exit_value_gross),
+            current_symbol_price: Some(position.current_symbol_price),
+            current_value_gross: Some(position.current_value_gross),
+            unrealised_profit_loss: Some(position.unrealised_profit_loss),
+            realised_profit_loss: Some(position.realised_profit_loss),
+            stop_loss_price: position.stop_loss_price,
+            partially_exited_quantity: Some(position.partially_exited_quantity),
+            total_realised_profit_loss: Some(position.total_realised_profit_loss),
+            annual_holding_rate: Some(position.annual_holding_rate),
+            received_dividends: Some(position.received_dividends),
+            #[cfg(feature = "funding-history")]
+            funding_rate_series: Some(position.funding_rate_series.clone()),
+            strict: false,
+        }
+    }
+
+    pub fn position_id(self, value: PositionId) -> Self {
+        Self {
+            position_id: Some(value),
+            ..self
+        }
+    }
+
+    pub fn exchange(self, value: Exchange) -> Self {
+        Self {
+            exchange: Some(value),
+            ..self
+        }
+    }
+
+    pub fn instrument(self, value: Instrument) -> Self {
+        Self {
+            instrument: Some(value),
+            ..self
+        }
+    }
+
+    pub fn meta(self, value: PositionMeta) -> Self {
+        Self {
+            meta: Some(value),
+            ..self
+        }
+    }
+
+    pub fn side(self, value: Side) -> Self {
+        Self {
+            side: Some(value),
+            ..self
+        }
+    }
+
+    pub fn quantity(self, value: f64) -> Self {
+        Self {
+            quantity: Some(value),
+            ..self
+        }
+    }
+
+    pub fn enter_fees(self, value: Fees) -> Self {
+        Self {
+            enter_fees: Some(value),
+            ..self
+        }
+    }
+
+    pub fn enter_fees_total(self, value: FeeAmount) -> Self {
+        Self {
+            enter_fees_total: Some(value),
+            ..self
+        }
+    }
+
+    pub fn enter_avg_price_gross(self, value: f64) -> Self {
+        Self {
+            enter_avg_price_gross: Some(value),
+            ..self
+        }
+    }
+
+    pub fn enter_value_gross(self, value: f64) -> Self {
+        Self {
+            enter_value_gross: Some(value),
+            ..self
+        }
+    }
+
+    pub fn exit_fees(self, value: Fees) -> Self {
+        Self {
+            exit_fees: Some(value),
+            ..self
+        }
+    }
+
+    pub fn exit_fees_total(self, value: FeeAmount) -> Self {
+        Self {
+            exit_fees_total: Some(value),
+            ..self
+        }
+    }
+
+    pub fn exit_avg_price_gross(self, value: f64) -> Self {
+        Self {
+            exit_avg_price_gross: Some(value),
+            ..self
+        }
+    }
+
+    pub fn exit_value_gross(self, value: f64) -> Self {
+        Self {
+            exit_value_gross: Some(value),
+            ..self
+        }
+    }
+
+    pub fn current_symbol_price(self, value: f64) -> Self {
+        Self {
+            current_symbol_price: Some(value),
+            ..self
+        }
+    }
+
+    pub fn current_value_gross(self, value: f64) -> Self {
+        Self {
+            current_value_gross: Some(value),
+            ..self
+        }
+    }
+
+    pub fn unrealised_profit_loss(self, value: f64) -> Self {
+        Self {
+            unrealised_profit_loss: Some(value),
+            ..self
+        }
+    }
+
+    pub fn realised_profit_loss(self, value: f64) -> Self {
+        Self {
+            realised_profit_loss: Some(value),
+            ..self
+        }
+    }
+
+    pub fn stop_loss_price(self, value: f64) -> Self {
+        Self {
+            stop_loss_price: Some(value),
+            ..self
+        }
+    }
+
+    pub fn partially_exited_quantity(self, value: f64) -> Self {
+        Self {
+            partially_exited_quantity: Some(value),
+            ..self
+        }
+    }
+
+    pub fn total_realised_profit_loss(self, value: f64) -> Self {
+        Self {
+            total_realised_profit_loss: Some(value),
+            ..self
+        }
+    }
+
+    pub fn annual_holding_rate(self, value: f64) -> Self {
+        Self {
+            annual_holding_rate: Some(value),
+            ..self
+        }
+    }
+
+    pub fn received_dividends(self, value: f64) -> Self {
+        Self {
+            received_dividends: Some(value),
+            ..self
+        }
+    }
+
+    #[cfg(feature = "funding-history")]
+    pub fn funding_rate_series(self, value: Vec<(DateTime<Utc>, f64)>) -> Self {
+        Self {
+            funding_rate_series: Some(value),
+            ..self
+        }
+    }
+
+    /// Enables strict mode, where building a closed [`Position`] (ie/ one with an exit_balance)
+    /// verifies the realised_profit_loss is consistent with the entry & exit fields. Useful when
+    /// restoring a [`Position`] from an external source.
+    pub fn strict(self) -> Self {
+        Self {
+            strict: true,
+            ..self
+        }
+    }
+
+    pub fn build(self) -> Result<Position, PortfolioError> {
+        let strict = self.strict;
+        let position = self.build_unchecked()?;
+
+        if strict && position.meta.exit_balance.is_some() {
+            let expected = position.calculate_realised_profit_loss();
+            let tolerance = POSITION_CONSISTENCY_EPSILON
+                * expected
+                    .abs()
+                    .max(position.realised_profit_loss.abs())
+                    .max(1.0);
+
+            if (position.realised_profit_loss - expected).abs() > tolerance {
+                return Err(PortfolioError::InconsistentPositionState {
+                    field: "realised_profit_loss",
+                    expected: expected.to_string(),
+                    actual: position.realised_profit_loss.to_string(),
+                });
+            }
+        }
+
+        Ok(position)
+    }
+
+    /// Builds the [`Position`] like [`PositionBuilder::build`], but reports every validation
+    /// failure at once rather than only the first. Returns [`PortfolioError::Multiple`]
+    /// containing every failure if the [`Position`] cannot be built.
+    pub fn build_collecting_errors(&self) -> Result<Position, PortfolioError> {
+        let required = [
+            ("position_id", self.position_id.is_some()),
+            ("exchange", self.exchange.is_some()),
+            ("instrument", self.instrument.is_some()),
+            ("meta", self.meta.is_some()),
+            ("side", self.side.is_some()),
+            ("quantity", self.quantity.is_some()),
+            ("enter_fees", self.enter_fees.is_some()),
+            ("enter_fees_total", self.enter_fees_total.is_some()),
+            (
+                "enter_avg_price_gross",
+                self.enter_avg_price_gross.is_some(),
+            ),
+            ("enter_value_gross", self.enter_value_gross.is_some()),
+            ("exit_fees", self.exit_fees.is_some()),
+            ("exit_fees_total", self.exit_fees_total.is_some()),
+            ("exit_avg_price_gross", self.exit_avg_price_gross.is_some()),
+            ("exit_value_gross", self.exit_value_gross.is_some()),
+            ("current_symbol_price", self.current_symbol_price.is_some()),
+            ("current_value_gross", self.current_value_gross.is_some()),
+            (
+                "unrealised_profit_loss",
+                self.unrealised_profit_loss.is_some(),
+            ),
+            ("realised_profit_loss", self.realised_profit_loss.is_some()),
+        ];
+
+        let errors = required
+            .into_iter()
+            .filter(|(_, present)| !present)
+            .map(|(field, _)| PortfolioError::BuilderIncomplete(field))
+            .collect::<Vec<_>>();
+
+        if !errors.is_empty() {
+            return Err(PortfolioError::Multiple(errors));
+        }
+
+        self.clone()
+            .build()
+            .map_err(|error| PortfolioError::Multiple(vec![error]))
+    }
+
+    fn build_unchecked(self) -> Result<Position, PortfolioError> {
         Ok(Position {
             position_id: self
                 .position_id
@@ -467,13 +1561,23 @@ impl PositionBuilder {
             realised_profit_loss: self
                 .realised_profit_loss
                 .ok_or(PortfolioError::BuilderIncomplete("realised_profit_loss"))?,
+            stop_loss_price: self.stop_loss_price,
+            partially_exited_quantity: self.partially_exited_quantity.unwrap_or_default(),
+            total_realised_profit_loss: self.total_realised_profit_loss.unwrap_or_default(),
+            annual_holding_rate: self.annual_holding_rate.unwrap_or_default(),
+            received_dividends: self.received_dividends.unwrap_or_default(),
+            #[cfg(feature = "funding-history")]
+            funding_rate_series: self.funding_rate_series.unwrap_or_default(),
         })
     }
 }
 
 /// Metadata detailing the trace UUIDs & timestamps associated with entering, updating & exiting
 /// a [`Position`].
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+///
+/// Trace UUIDs identify the events that triggered each state change, so they are excluded from
+/// equality & ordering.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
 pub struct PositionMeta {
     /// [`FillEvent`] timestamp that triggered the entering of this [`Position`].
     pub enter_time: DateTime<Utc>,
@@ -483,14 +1587,153 @@ pub struct PositionMeta {
 
     /// Portfolio [`Balance`] calculated at the point of exiting a [`Position`].
     pub exit_balance: Option<Balance>,
-}
 
-impl Default for PositionMeta {
+    /// Trace UUID of the [`FillEvent`] that entered this [`Position`].
+    #[serde(default)]
+    pub enter_trace_id: Uuid,
+
+    /// Trace UUID of the event that last updated this [`Position`]. Equal to the
+    /// enter_trace_id if the [`Position`] has never been updated.
+    #[serde(default)]
+    pub last_update_trace_id: Uuid,
+
+    /// Trace UUID of the [`FillEvent`] that exited this [`Position`].
+    #[serde(default)]
+    pub exit_trace_id: Option<Uuid>,
+}
+
+impl PartialEq for PositionMeta {
+    fn eq(&self, other: &Self) -> bool {
+        self.enter_time == other.enter_time
+            && self.update_time == other.update_time
+            && self.exit_balance == other.exit_balance
+    }
+}
+
+impl PartialOrd for PositionMeta {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.enter_time, self.update_time, self.exit_balance).partial_cmp(&(
+            other.enter_time,
+            other.update_time,
+            other.exit_balance,
+        ))
+    }
+}
+
+impl Default for PositionMeta {
     fn default() -> Self {
+        let enter_trace_id = Uuid::new_v4();
         Self {
             enter_time: Utc::now(),
             update_time: Utc::now(),
             exit_balance: None,
+            enter_trace_id,
+            last_update_trace_id: enter_trace_id,
+            exit_trace_id: None,
+        }
+    }
+}
+
+impl PositionMeta {
+    /// Returns a [`PositionMetaBuilder`] instance.
+    pub fn builder() -> PositionMetaBuilder {
+        PositionMetaBuilder::new()
+    }
+
+    /// Returns the chain of trace UUIDs that led to the current state of the [`Position`], in the
+    /// order enter, last update & exit. The last update is omitted if the [`Position`] has never
+    /// been updated, and the exit is omitted if the [`Position`] is still open.
+    pub fn trace_chain(&self) -> Vec<Uuid> {
+        let mut chain = vec![self.enter_trace_id, self.last_update_trace_id];
+        chain.dedup();
+        chain.extend(self.exit_trace_id);
+        chain
+    }
+
+    /// Determines if the [`Position`] has been updated since it was entered.
+    pub fn was_updated_since_entry(&self) -> bool {
+        self.enter_trace_id != self.last_update_trace_id
+    }
+}
+
+/// Structured key=value representation of the [`PositionMeta`] suitable for log aggregation,
+/// with ISO 8601 timestamps. The exit trace is "pending" if the [`Position`] is still open.
+///
+/// eg/ "enter_trace=<uuid> enter_time=<iso8601> last_update=<uuid> at=<iso8601> exit_trace=pending"
+impl Display for PositionMeta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "enter_trace={} enter_time={} last_update={} at={} exit_trace=",
+            self.enter_trace_id,
+            self.enter_time.format("%+"),
+            self.last_update_trace_id,
+            self.update_time.format("%+"),
+        )?;
+
+        match self.exit_trace_id {
+            Some(exit_trace_id) => write!(f, "{exit_trace_id}"),
+            None => write!(f, "pending"),
+        }
+    }
+}
+
+/// Builder to construct [`PositionMeta`] instances. Any timestamps not provided default to
+/// `Utc::now()` when built.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default)]
+pub struct PositionMetaBuilder {
+    pub enter_time: Option<DateTime<Utc>>,
+    pub update_time: Option<DateTime<Utc>>,
+    pub exit_balance: Option<Balance>,
+}
+
+impl PositionMetaBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn enter_time(self, value: DateTime<Utc>) -> Self {
+        Self {
+            enter_time: Some(value),
+            ..self
+        }
+    }
+
+    pub fn update_time(self, value: DateTime<Utc>) -> Self {
+        Self {
+            update_time: Some(value),
+            ..self
+        }
+    }
+
+    pub fn exit_balance(self, value: Balance) -> Self {
+        Self {
+            exit_balance: Some(value),
+            ..self
+        }
+    }
+
+    /// Sets the metadata associated with exiting a [`Position`] - the exit [`FillEvent`]
+    /// timestamp (which becomes the last update_time) & the exit [`Balance`].
+    pub fn with_exit(self, exit_time: DateTime<Utc>, exit_balance: Balance) -> Self {
+        Self {
+            update_time: Some(exit_time),
+            exit_balance: Some(exit_balance),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> PositionMeta {
+        let now = Utc::now();
+        let enter_trace_id = Uuid::new_v4();
+
+        PositionMeta {
+            enter_time: self.enter_time.unwrap_or(now),
+            update_time: self.update_time.unwrap_or(now),
+            exit_balance: self.exit_balance,
+            enter_trace_id,
+            last_update_trace_id: enter_trace_id,
+            exit_trace_id: self.exit_balance.map(|_| Uuid::new_v4()),
         }
     }
 }
@@ -508,6 +1751,13 @@ pub struct PositionUpdate {
     pub current_value_gross: f64,
     /// Unrealised P&L whilst the [`Position`] is open.
     pub unrealised_profit_loss: f64,
+    /// Whether the [`Position`] stop loss has been triggered, indicating a close signal should be
+    /// generated. See [`Position::is_stop_triggered`].
+    #[serde(default)]
+    pub stop_triggered: bool,
+    /// Trace UUID of the event that triggered the [`Position`] update.
+    #[serde(default)]
+    pub trace_id: Uuid,
 }
 
 impl From<&mut Position> for PositionUpdate {
@@ -518,6 +1768,8 @@ impl From<&mut Position> for PositionUpdate {
             current_symbol_price: updated_position.current_symbol_price,
             current_value_gross: updated_position.current_value_gross,
             unrealised_profit_loss: updated_position.unrealised_profit_loss,
+            stop_triggered: updated_position.is_stop_triggered(),
+            trace_id: updated_position.meta.last_update_trace_id,
         }
     }
 }
@@ -548,6 +1800,10 @@ pub struct PositionExit {
 
     /// Realised P&L after the [`Position`] has closed.
     pub realised_profit_loss: f64,
+
+    /// Trace UUID of the [`FillEvent`] that exited the [`Position`].
+    #[serde(default)]
+    pub trace_id: Uuid,
 }
 
 impl TryFrom<&mut Position> for PositionExit {
@@ -566,15 +1822,38 @@ impl TryFrom<&mut Position> for PositionExit {
             exit_avg_price_gross: exited_position.exit_avg_price_gross,
             exit_value_gross: exited_position.exit_value_gross,
             realised_profit_loss: exited_position.realised_profit_loss,
+            trace_id: exited_position.meta.exit_trace_id.unwrap_or_default(),
         })
     }
 }
 
+/// [`Position`] lifecycle event, communicating the entry, update, or exit of a [`Position`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum PositionEvent {
+    /// A new [`Position`] was entered.
+    Opened(Box<Position>),
+    /// An open [`Position`] was updated by a [`MarketEvent`].
+    Updated(PositionUpdate),
+    /// An open [`Position`] was exited.
+    Closed(PositionExit),
+}
+
+/// Formats a [`Duration`] as days, hours, minutes & seconds, eg/ "1d 2h 3m 4s".
+pub(crate) fn format_duration(duration: Duration) -> String {
+    format!(
+        "{}d {}h {}m {}s",
+        duration.num_days(),
+        duration.num_hours() % 24,
+        duration.num_minutes() % 60,
+        duration.num_seconds() % 60
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_util::{fill_event, market_event_trade, position};
-    use barter_integration::model::Side;
+    use crate::test_util::{fill_event, market_event_candle, market_event_trade, position};
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
 
     #[test]
     fn enter_new_position_with_long_decision_provided() {
@@ -1640,4 +2919,1375 @@ mod tests {
 
         assert!(PositionExit::try_from(&mut exited_position).is_err());
     }
+
+    #[test]
+    fn enter_checked_with_valid_fill_enters_position() {
+        let mut input_fill = fill_event();
+        input_fill.fees = Fees {
+            exchange: 1.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let checked = Position::enter_checked(Uuid::new_v4(), &input_fill).unwrap();
+
+        assert_eq!(checked.side, Side::Buy);
+        assert_eq!(checked.enter_value_gross, input_fill.fill_value_gross);
+        assert_eq!(checked.enter_fees_total, 1.0);
+    }
+
+    #[test]
+    fn enter_checked_with_invalid_fill_value_gross_returns_invalid_quantity() {
+        for invalid in [0.0, -100.0, f64::NAN, f64::INFINITY] {
+            let mut input_fill = fill_event();
+            input_fill.fill_value_gross = invalid;
+
+            match Position::enter_checked(Uuid::new_v4(), &input_fill) {
+                Err(PortfolioError::InvalidQuantity(value)) => {
+                    assert!(value == invalid || (value.is_nan() && invalid.is_nan()))
+                }
+                other => panic!("expected InvalidQuantity error, got: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn enter_checked_with_negative_fee_returns_invalid_fee_amount() {
+        let mut input_fill = fill_event();
+        input_fill.fees.slippage = -0.5;
+
+        match Position::enter_checked(Uuid::new_v4(), &input_fill) {
+            Err(PortfolioError::InvalidFeeAmount(value)) => assert_eq!(value, -0.5),
+            other => panic!("expected InvalidFeeAmount error, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn enter_checked_with_future_fill_time_returns_error() {
+        let mut input_fill = fill_event();
+        input_fill.time = Utc::now() + Duration::hours(1);
+
+        match Position::enter_checked(Uuid::new_v4(), &input_fill) {
+            Err(PortfolioError::FillTimeInFuture(time)) => assert_eq!(time, input_fill.time),
+            other => panic!("expected FillTimeInFuture error, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn enter_checked_tolerates_fill_time_within_clock_skew() {
+        #[derive(Debug)]
+        struct NoClockSkewEnterer;
+
+        impl PositionEnterer for NoClockSkewEnterer {
+            fn enter(&self, engine_id: Uuid, fill: &FillEvent) -> Result<Position, PortfolioError> {
+                DefaultPositionEnterer.enter(engine_id, fill)
+            }
+
+            fn max_clock_skew(&self) -> Duration {
+                Duration::zero()
+            }
+        }
+
+        // Exchange clock slightly ahead of the local clock
+        let mut input_fill = fill_event();
+        input_fill.time = Utc::now() + Duration::seconds(1);
+
+        assert!(Position::enter_checked(Uuid::new_v4(), &input_fill).is_ok());
+        assert!(matches!(
+            NoClockSkewEnterer.enter_checked(Uuid::new_v4(), &input_fill),
+            Err(PortfolioError::FillTimeInFuture(_))
+        ));
+    }
+
+    #[test]
+    fn position_meta_builder_builds_with_provided_fields() {
+        let enter_time = DateTime::<Utc>::MIN_UTC;
+        let exit_time = enter_time + Duration::days(1);
+        let exit_balance = Balance {
+            time: exit_time,
+            total: 1100.0,
+            available: 1100.0,
+        };
+
+        let meta = PositionMeta::builder()
+            .enter_time(enter_time)
+            .with_exit(exit_time, exit_balance)
+            .build();
+
+        assert_eq!(
+            meta,
+            PositionMeta {
+                enter_time,
+                update_time: exit_time,
+                exit_balance: Some(exit_balance),
+                enter_trace_id: meta.enter_trace_id,
+                last_update_trace_id: meta.enter_trace_id,
+                exit_trace_id: meta.exit_trace_id,
+            }
+        );
+        assert!(meta.exit_trace_id.is_some());
+    }
+
+    #[test]
+    fn position_meta_builder_defaults_missing_fields() {
+        let enter_time = DateTime::<Utc>::MIN_UTC;
+
+        let meta = PositionMeta::builder().enter_time(enter_time).build();
+
+        assert_eq!(meta.enter_time, enter_time);
+        assert!(meta.update_time > enter_time);
+        assert_eq!(meta.exit_balance, None);
+    }
+
+    #[test]
+    fn position_meta_trace_chain_follows_position_state_changes() {
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+
+        // Never updated
+        let mut position = Position::enter(Uuid::new_v4(), &input_fill).unwrap();
+        assert!(!position.meta.was_updated_since_entry());
+        assert_eq!(position.meta.trace_chain(), vec![input_fill.trace_id]);
+
+        // Updated by a MarketEvent
+        let market = market_event_trade(Side::Buy);
+        position.update(&market).unwrap();
+        assert!(position.meta.was_updated_since_entry());
+        let updated_chain = position.meta.trace_chain();
+        assert_eq!(
+            updated_chain,
+            vec![input_fill.trace_id, market_trace_id(&market)]
+        );
+
+        // Exited
+        let exit_fill = FillEvent {
+            decision: Decision::CloseLong,
+            quantity: -1.0,
+            trace_id: Uuid::new_v4(),
+            ..input_fill
+        };
+        position.exit(Balance::default(), &exit_fill).unwrap();
+        let exited_chain = position.meta.trace_chain();
+        assert_eq!(exited_chain.len(), 3);
+        assert_eq!(exited_chain[..2], updated_chain[..]);
+        assert_eq!(exited_chain[2], exit_fill.trace_id);
+    }
+
+    #[test]
+    fn position_meta_equality_excludes_trace_ids() {
+        let meta = PositionMeta::default();
+        let retraced = PositionMeta {
+            enter_trace_id: Uuid::new_v4(),
+            last_update_trace_id: Uuid::new_v4(),
+            exit_trace_id: Some(Uuid::new_v4()),
+            ..meta
+        };
+        assert_eq!(meta, retraced);
+
+        let later = PositionMeta {
+            update_time: meta.update_time + Duration::seconds(1),
+            ..meta
+        };
+        assert_ne!(meta, later);
+        assert!(meta < later);
+    }
+
+    #[test]
+    fn position_meta_display_contains_trace_ids_and_timestamps() {
+        let mut meta = PositionMeta {
+            last_update_trace_id: Uuid::new_v4(),
+            ..PositionMeta::default()
+        };
+
+        let open = meta.to_string();
+        assert!(open.contains(&format!("enter_trace={}", meta.enter_trace_id)));
+        assert!(open.contains(&format!("last_update={}", meta.last_update_trace_id)));
+        assert!(open.contains(&format!("enter_time={}", meta.enter_time.format("%+"))));
+        assert!(open.ends_with("exit_trace=pending"));
+
+        meta.exit_trace_id = Some(Uuid::new_v4());
+        let exited = meta.to_string();
+        assert!(exited.ends_with(&format!("exit_trace={}", meta.exit_trace_id.unwrap())));
+        assert!(!exited.contains("pending"));
+    }
+
+    #[test]
+    fn exchange_id_of_well_known_and_unknown_exchanges() {
+        let mut position = position();
+
+        position.exchange = Exchange::from(ExchangeId::BinanceSpot);
+        assert_eq!(position.exchange_id(), Some(ExchangeId::BinanceSpot));
+
+        position.exchange = Exchange::from("KRAKEN");
+        assert_eq!(position.exchange_id(), Some(ExchangeId::Kraken));
+
+        position.exchange = Exchange::from("not_an_exchange");
+        assert_eq!(position.exchange_id(), None);
+    }
+
+    #[test]
+    fn cost_basis_and_taxable_gain_of_long_position() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.enter_value_gross = 100.0;
+        position.enter_fees_total = 1.0;
+        position.exit_value_gross = 150.0;
+        position.exit_fees_total = 2.0;
+
+        assert_eq!(position.cost_basis(), 101.0);
+        assert_eq!(position.taxable_gain(), 47.0);
+        assert_eq!(
+            position.taxable_gain(),
+            position.calculate_realised_profit_loss()
+        );
+    }
+
+    #[test]
+    fn cost_basis_and_taxable_gain_of_short_position() {
+        let mut position = position();
+        position.side = Side::Sell;
+        position.enter_value_gross = 100.0;
+        position.enter_fees_total = 1.0;
+        position.exit_value_gross = 150.0;
+        position.exit_fees_total = 2.0;
+
+        assert_eq!(position.cost_basis(), 99.0);
+        assert_eq!(position.taxable_gain(), -53.0);
+        assert_eq!(
+            position.taxable_gain(),
+            position.calculate_realised_profit_loss()
+        );
+    }
+
+    #[test]
+    fn net_cash_flows_of_closed_long_and_short_positions() {
+        let mut long = position();
+        long.side = Side::Buy;
+        long.enter_value_gross = 100.0;
+        long.enter_fees_total = 1.0;
+        long.exit_value_gross = 150.0;
+        long.exit_fees_total = 2.0;
+
+        assert_eq!(long.net_entry_cost(), 101.0);
+        assert_eq!(long.net_exit_proceeds(), 148.0);
+        assert_eq!(
+            long.net_exit_proceeds() - long.net_entry_cost(),
+            long.calculate_realised_profit_loss()
+        );
+
+        let mut short = long.clone();
+        short.side = Side::Sell;
+
+        assert_eq!(short.net_entry_cost(), -99.0);
+        assert_eq!(short.net_exit_proceeds(), -152.0);
+        assert_eq!(
+            short.net_exit_proceeds() - short.net_entry_cost(),
+            short.calculate_realised_profit_loss()
+        );
+
+        // Checked variants require a closed Position
+        assert_eq!(short.net_entry_cost_checked(), None);
+        assert_eq!(short.net_exit_proceeds_checked(), None);
+
+        short.meta.exit_balance = Some(Balance::default());
+        assert_eq!(short.net_entry_cost_checked(), Some(-99.0));
+        assert_eq!(short.net_exit_proceeds_checked(), Some(-152.0));
+    }
+
+    #[test]
+    fn positions_are_ranked_by_closed_or_open_profit_loss_then_enter_time() {
+        let base = position();
+
+        let mut closed_winner = base.clone();
+        closed_winner.meta.exit_balance = Some(Balance::default());
+        closed_winner.realised_profit_loss = 50.0;
+        closed_winner.unrealised_profit_loss = -100.0;
+
+        let mut open_loser = base.clone();
+        open_loser.unrealised_profit_loss = -10.0;
+
+        let mut open_early = base.clone();
+        open_early.unrealised_profit_loss = 20.0;
+        open_early.meta.enter_time = base.meta.enter_time - Duration::minutes(1);
+
+        let mut open_late = base;
+        open_late.unrealised_profit_loss = 20.0;
+
+        let mut positions = vec![
+            closed_winner.clone(),
+            open_late.clone(),
+            open_loser.clone(),
+            open_early.clone(),
+        ];
+        positions.sort_by(Position::cmp_by_ranking);
+        assert_eq!(
+            positions,
+            vec![open_loser, open_early, open_late, closed_winner.clone()]
+        );
+
+        let heap = positions
+            .into_iter()
+            .map(RankedPosition)
+            .collect::<std::collections::BinaryHeap<_>>();
+        assert_eq!(heap.peek().map(|ranked| &ranked.0), Some(&closed_winner));
+    }
+
+    #[test]
+    fn apply_corporate_action_stock_split_dividend_and_spin_off() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = 10.0;
+        position.enter_avg_price_gross = 100.0;
+        position.enter_value_gross = 1000.0;
+        position.current_symbol_price = 120.0;
+        position.current_value_gross = 1200.0;
+        position.stop_loss_price = Some(90.0);
+
+        // 2-for-1 split
+        position
+            .apply_corporate_action(CorporateAction::StockSplit { ratio: 2.0 })
+            .unwrap();
+        assert_eq!(position.quantity, 20.0);
+        assert_eq!(position.enter_avg_price_gross, 50.0);
+        assert_eq!(position.current_symbol_price, 60.0);
+        assert_eq!(position.stop_loss_price, Some(45.0));
+        assert_eq!(position.enter_value_gross, 1000.0);
+        assert_eq!(position.current_value_gross, 1200.0);
+
+        // Dividend received by a long, owed by a short
+        position
+            .apply_corporate_action(CorporateAction::CashDividend { per_share: 0.5 })
+            .unwrap();
+        assert_eq!(position.received_dividends, 10.0);
+
+        let mut short = position.clone();
+        short.side = Side::Sell;
+        short.quantity = -20.0;
+        short.received_dividends = 0.0;
+        short
+            .apply_corporate_action(CorporateAction::CashDividend { per_share: 0.5 })
+            .unwrap();
+        assert_eq!(short.received_dividends, -10.0);
+
+        // Spin-off allocating 25% of the cost basis to the new security
+        position
+            .apply_corporate_action(CorporateAction::SpinOff {
+                new_symbol: "spin".to_owned(),
+                allocation_ratio: 0.25,
+            })
+            .unwrap();
+        assert_eq!(position.enter_avg_price_gross, 37.5);
+        assert_eq!(position.enter_value_gross, 750.0);
+
+        // Invalid actions are rejected without mutating the Position
+        let before = position.clone();
+        for action in [
+            CorporateAction::StockSplit { ratio: 0.0 },
+            CorporateAction::StockSplit { ratio: -2.0 },
+            CorporateAction::CashDividend { per_share: -1.0 },
+            CorporateAction::SpinOff {
+                new_symbol: "spin".to_owned(),
+                allocation_ratio: 0.0,
+            },
+        ] {
+            assert!(matches!(
+                position.apply_corporate_action(action),
+                Err(PortfolioError::InvalidCorporateAction(_))
+            ));
+        }
+        assert_eq!(position, before);
+    }
+
+    #[test]
+    fn apply_price_adjustment_for_contango_roll_preserves_consistency() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = 10.0;
+        position.enter_fees = Fees {
+            exchange: 1.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+        position.enter_fees_total = 1.0;
+        position.enter_avg_price_gross = 100.0;
+        position.enter_value_gross = 1000.0;
+        position.current_symbol_price = 120.0;
+        position.current_value_gross = 1200.0;
+        position.stop_loss_price = Some(90.0);
+        position.unrealised_profit_loss = position.calculate_unrealised_profit_loss();
+
+        // 1% contango roll
+        position.apply_price_adjustment(0.99).unwrap();
+        assert!((position.enter_avg_price_gross - 99.0).abs() < 1e-9);
+        assert!((position.enter_value_gross - 990.0).abs() < 1e-9);
+        assert!((position.current_symbol_price - 118.8).abs() < 1e-9);
+        assert!((position.current_value_gross - 1188.0).abs() < 1e-9);
+        assert!((position.stop_loss_price.unwrap() - 89.1).abs() < 1e-9);
+        assert_eq!(position.quantity, 10.0);
+        assert_eq!(position.enter_fees_total, 1.0);
+
+        // Gross values remain consistent with the adjusted prices & P&L is recalculated
+        assert!(
+            (position.enter_value_gross - position.enter_avg_price_gross * position.quantity).abs()
+                < 1e-9
+        );
+        assert!(
+            (position.current_value_gross - position.current_symbol_price * position.quantity)
+                .abs()
+                < 1e-9
+        );
+        assert!((position.unrealised_profit_loss - (1188.0 - 990.0 - 2.0)).abs() < 1e-9);
+        assert!(position.sanity_check().is_ok());
+
+        // Closed Positions have their exit prices & realised P&L adjusted
+        let mut closed = position.clone();
+        closed.meta.exit_balance = Some(Balance::default());
+        closed.exit_avg_price_gross = 110.0;
+        closed.exit_value_gross = 1100.0;
+        closed.apply_price_adjustment(0.99).unwrap();
+        assert!((closed.exit_value_gross - 1089.0).abs() < 1e-9);
+        assert!((closed.realised_profit_loss - (1089.0 - 980.1 - 1.0)).abs() < 1e-9);
+        assert!(closed.sanity_check().is_ok());
+
+        // Invalid factors are rejected without mutating the Position
+        let before = position.clone();
+        for factor in [0.0, -0.99, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                position.apply_price_adjustment(factor),
+                Err(PortfolioError::InvalidPriceAdjustment(_))
+            ));
+        }
+        assert_eq!(position, before);
+    }
+
+    #[test]
+    fn normalize_to_unit_notional_scales_sizes_but_not_prices() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = 10.0;
+        position.enter_fees = Fees {
+            exchange: 1.0,
+            slippage: 0.5,
+            network: 0.0,
+        };
+        position.enter_fees_total = 1.5;
+        position.enter_avg_price_gross = 100.0;
+        position.enter_value_gross = 1000.0;
+        position.exit_fees = Fees {
+            exchange: 1.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+        position.exit_fees_total = 1.0;
+        position.exit_avg_price_gross = 110.0;
+        position.exit_value_gross = 1100.0;
+        position.current_symbol_price = 110.0;
+        position.current_value_gross = 1100.0;
+        position.meta.exit_balance = Some(Balance::default());
+        position.realised_profit_loss = position.calculate_realised_profit_loss();
+
+        let normalized = position.normalize_to_unit_notional(100.0);
+
+        // Sizes, fees & P&L scale by 100.0 / 1000.0
+        assert_eq!(normalized.enter_value_gross, 100.0);
+        assert!((normalized.quantity - 1.0).abs() < 1e-9);
+        assert!((normalized.enter_fees.slippage - 0.05).abs() < 1e-9);
+        assert!((normalized.enter_fees_total - 0.15).abs() < 1e-9);
+        assert!((normalized.exit_fees_total - 0.1).abs() < 1e-9);
+        assert!((normalized.exit_value_gross - 110.0).abs() < 1e-9);
+        assert!((normalized.current_value_gross - 110.0).abs() < 1e-9);
+        assert!(
+            (normalized.realised_profit_loss - position.realised_profit_loss * 0.1).abs() < 1e-9
+        );
+
+        // Per-unit prices are unchanged & the P&L remains consistent with the scaled values
+        assert_eq!(normalized.enter_avg_price_gross, 100.0);
+        assert_eq!(normalized.exit_avg_price_gross, 110.0);
+        assert_eq!(normalized.current_symbol_price, 110.0);
+        assert!(
+            (normalized.realised_profit_loss - normalized.calculate_realised_profit_loss()).abs()
+                < 1e-9
+        );
+
+        // Positions without an entry notional cannot be scaled
+        position.enter_value_gross = 0.0;
+        assert_eq!(position.normalize_to_unit_notional(100.0), position);
+    }
+
+    #[test]
+    fn holding_costs_accrue_with_hold_duration() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.enter_value_gross = 1000.0;
+        position.enter_fees_total = 0.0;
+        position.current_value_gross = 1095.0;
+        position.meta.update_time = position.meta.enter_time + Duration::hours(36);
+
+        assert_eq!(position.holding_cost_per_day(0.1), 0.3);
+        assert!((position.total_holding_cost_to_date(0.1) - 0.45).abs() < 1e-9);
+
+        // Negative rate yields a credit
+        assert!((position.total_holding_cost_to_date(-0.1) + 0.45).abs() < 1e-9);
+
+        // Holding costs are deducted from unrealised P&L once an annual_holding_rate is set
+        assert_eq!(position.calculate_unrealised_profit_loss(), 95.0);
+        position.annual_holding_rate = 0.1;
+        assert!((position.calculate_unrealised_profit_loss() - 94.55).abs() < 1e-9);
+
+        // The same carry is deducted from realised P&L, accrued until the exit fill
+        let mut exit_fill = fill_event();
+        exit_fill.decision = Decision::CloseLong;
+        exit_fill.time = position.meta.update_time;
+        exit_fill.fill_value_gross = 1095.0;
+        position.meta.update_time = position.meta.enter_time;
+
+        let exit = position.exit(Balance::default(), &exit_fill).unwrap();
+        assert!((position.realised_profit_loss - 94.55).abs() < 1e-9);
+        assert!((exit.realised_profit_loss - 94.55).abs() < 1e-9);
+    }
+
+    #[test]
+    fn entered_before_and_after_compare_enter_time() {
+        let earlier = position();
+        let mut later = position();
+        later.meta.enter_time = earlier.meta.enter_time + Duration::seconds(1);
+
+        assert!(earlier.entered_before(&later));
+        assert!(!earlier.entered_after(&later));
+        assert!(later.entered_after(&earlier));
+        assert!(!later.entered_before(&earlier));
+        assert!(!earlier.entered_before(&earlier));
+        assert!(!earlier.entered_after(&earlier));
+    }
+
+    #[test]
+    fn hold_period_classifies_short_and_long_term_holds() {
+        let mut position = position();
+        position.meta.enter_time = Utc::now();
+        position.meta.update_time = position.meta.enter_time + Duration::days(400);
+
+        // Open Position has no hold period
+        assert_eq!(position.hold_period(), None);
+        assert!(!position.is_long_term_hold());
+
+        position.meta.exit_balance = Some(Balance::default());
+        assert_eq!(position.hold_period(), Some(Duration::days(400)));
+        assert!(position.is_long_term_hold());
+
+        position.meta.update_time = position.meta.enter_time + Duration::days(365);
+        assert_eq!(position.hold_period(), Some(Duration::days(365)));
+        assert!(!position.is_long_term_hold());
+    }
+
+    #[test]
+    fn update_checked_rejects_stale_market_event_without_mutating_position() {
+        let mut position = position();
+        let original = position.clone();
+
+        let mut input_market = market_event_trade(Side::Buy);
+        input_market.exchange_time = position.meta.update_time - chrono::Duration::seconds(1);
+
+        match position.update_checked(&input_market) {
+            Err(PortfolioError::StaleMarketEvent {
+                event_ts,
+                last_update_ts,
+            }) => {
+                assert_eq!(event_ts, input_market.exchange_time);
+                assert_eq!(last_update_ts, original.meta.update_time);
+            }
+            other => panic!("expected StaleMarketEvent error, got: {:?}", other),
+        }
+
+        assert_eq!(position, original);
+    }
+
+    #[test]
+    fn update_checked_applies_newer_market_event() {
+        let mut position = position();
+
+        let mut input_market = market_event_trade(Side::Buy);
+        input_market.exchange_time = position.meta.update_time + chrono::Duration::seconds(1);
+
+        let update = position.update_checked(&input_market).unwrap().unwrap();
+
+        assert_eq!(update.current_symbol_price, 1000.0);
+        assert_eq!(position.meta.update_time, input_market.exchange_time);
+    }
+
+    #[test]
+    fn update_current_price_updates_value_and_unrealised_profit_loss() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = 1.0;
+        position.enter_avg_price_gross = 100.0;
+        position.enter_value_gross = 100.0;
+        position.enter_fees_total = 3.0;
+
+        let timestamp = position.meta.update_time + chrono::Duration::seconds(1);
+        position.update_current_price(110.0, timestamp).unwrap();
+
+        assert_eq!(position.current_symbol_price, 110.0);
+        assert_eq!(position.current_value_gross, 110.0);
+        assert_eq!(position.unrealised_profit_loss, 110.0 - 100.0 - 6.0);
+        assert_eq!(position.meta.update_time, timestamp);
+    }
+
+    #[test]
+    fn update_current_price_rejects_invalid_price_without_mutating_position() {
+        for price in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let mut position = position();
+            let original = position.clone();
+
+            assert!(matches!(
+                position.update_current_price(price, Utc::now()),
+                Err(PortfolioError::InvalidPrice(_))
+            ));
+            assert_eq!(position, original);
+        }
+    }
+
+    #[test]
+    fn scale_in_blends_entry_price_weighted_by_quantity() {
+        fn entry_fill(quantity: f64, price: f64) -> FillEvent {
+            let mut fill = fill_event();
+            fill.decision = Decision::Long;
+            fill.quantity = quantity;
+            fill.fill_value_gross = quantity * price;
+            fill.fees = Fees {
+                exchange: 1.0,
+                slippage: 0.5,
+                network: 0.0,
+            };
+            fill
+        }
+
+        let mut position = Position::enter(Uuid::new_v4(), &entry_fill(1.0, 100.0)).unwrap();
+        position.scale_in(&entry_fill(2.0, 110.0)).unwrap();
+        position.scale_in(&entry_fill(1.0, 130.0)).unwrap();
+
+        // (100.0 + 2.0 * 110.0 + 130.0) / 4.0
+        assert_eq!(position.quantity, 4.0);
+        assert_eq!(position.enter_value_gross, 450.0);
+        assert_eq!(position.enter_avg_price_gross, 112.5);
+        assert_eq!(
+            position.enter_fees,
+            Fees {
+                exchange: 3.0,
+                slippage: 1.5,
+                network: 0.0,
+            }
+        );
+        assert_eq!(position.enter_fees_total, 4.5);
+
+        // Current price remains at the first entry price of 100.0
+        assert_eq!(position.current_value_gross, 400.0);
+        assert_eq!(position.unrealised_profit_loss, 400.0 - 450.0 - 4.5 * 2.0);
+    }
+
+    #[test]
+    fn scale_in_rejects_opposite_side_and_exited_positions() {
+        let mut position = position();
+        let original = position.clone();
+
+        let mut short_fill = fill_event();
+        short_fill.decision = Decision::Short;
+        short_fill.quantity = -1.0;
+        assert!(matches!(
+            position.scale_in(&short_fill),
+            Err(PortfolioError::CannotScaleInPosition)
+        ));
+        assert_eq!(position, original);
+
+        let mut long_fill = fill_event();
+        long_fill.decision = Decision::Long;
+        long_fill.quantity = 1.0;
+        position.meta.exit_balance = Some(Balance::default());
+        assert!(matches!(
+            position.scale_in(&long_fill),
+            Err(PortfolioError::CannotScaleInPosition)
+        ));
+    }
+
+    #[test]
+    fn update_with_candle_matches_update_from_candle_market_event() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = 2.0;
+        position.enter_value_gross = 1800.0;
+        position.enter_fees_total = 10.0;
+
+        let market = market_event_candle();
+        let candle = match &market.kind {
+            DataKind::Candle(candle) => *candle,
+            _ => unreachable!(),
+        };
+
+        let mut via_market = position.clone();
+        let market_update = via_market.update(&market).unwrap();
+
+        let mut candle_update = position.update_with_candle(&candle, market.exchange_time);
+
+        // Only the MarketEvent update is traced to the triggering MarketEvent
+        assert_eq!(market_update.trace_id, market_trace_id(&market));
+        assert_ne!(candle_update.trace_id, market_update.trace_id);
+        candle_update.trace_id = market_update.trace_id;
+
+        assert_eq!(candle_update, market_update);
+        assert_eq!(position, via_market);
+        assert_eq!(position.current_symbol_price, 1000.0);
+        assert_eq!(position.current_value_gross, 2000.0);
+        assert_eq!(position.unrealised_profit_loss, 2000.0 - 1800.0 - 20.0);
+    }
+
+    #[test]
+    fn update_stop_loss_rejects_stop_on_wrong_side_of_current_price() {
+        let mut long = position();
+        long.side = Side::Buy;
+        long.current_symbol_price = 100.0;
+
+        assert!(matches!(
+            long.update_stop_loss(105.0),
+            Err(PortfolioError::InvalidStopLoss { .. })
+        ));
+        assert_eq!(long.stop_loss_price, None);
+        assert!(long.update_stop_loss(95.0).is_ok());
+        assert_eq!(long.stop_loss_price, Some(95.0));
+
+        let mut short = position();
+        short.side = Side::Sell;
+        short.current_symbol_price = 100.0;
+
+        assert!(matches!(
+            short.update_stop_loss(100.0),
+            Err(PortfolioError::InvalidStopLoss { .. })
+        ));
+        assert!(short.update_stop_loss(105.0).is_ok());
+        assert_eq!(short.stop_loss_price, Some(105.0));
+    }
+
+    #[cfg(feature = "funding-history")]
+    #[test]
+    fn accrue_funding_records_each_payment_in_funding_rate_series() {
+        let mut position = position();
+        assert_eq!(position.total_funding_cost(), 0.0);
+        assert_eq!(position.avg_daily_funding_cost(), 0.0);
+
+        // Three 8 hourly funding payments on the first day & one on the second
+        let start = DateTime::<Utc>::from_timestamp(1_700_006_400, 0).unwrap();
+        let payments = [1.0, -0.5, 2.0, 1.5];
+        for (index, payment) in payments.into_iter().enumerate() {
+            position.accrue_funding(start + Duration::hours(8 * index as i64), payment);
+            assert_eq!(position.funding_rate_series.len(), index + 1);
+        }
+
+        assert_eq!(position.total_funding_cost(), payments.iter().sum::<f64>());
+        assert_eq!(position.avg_daily_funding_cost(), 4.0 / 2.0);
+    }
+
+    #[test]
+    fn stress_test_calculates_unrealised_pnl_of_price_move_without_mutating() {
+        let mut long = position();
+        long.side = Side::Buy;
+        long.quantity = 2.0;
+        long.enter_value_gross = 200.0;
+        long.enter_fees_total = 1.0;
+        long.current_symbol_price = 100.0;
+        long.current_value_gross = 200.0;
+        let before = long.clone();
+
+        // 10% crash: value 180.0 - enter 200.0 - approx fees 2.0
+        assert!((long.stress_test(-0.1) - -22.0).abs() < 1e-9);
+        assert!((long.stress_test(0.0) - long.calculate_unrealised_profit_loss()).abs() < 1e-9);
+        assert_eq!(long, before);
+
+        let mut short = long.clone();
+        short.side = Side::Sell;
+        short.quantity = -2.0;
+
+        // 10% crash: enter 200.0 - value 180.0 - approx fees 2.0
+        assert!((short.stress_test(-0.1) - 18.0).abs() < 1e-9);
+
+        let series = long.stress_test_series(&[-0.1, 0.0, 0.1]);
+        assert_eq!(series.len(), 3);
+        assert!((series[2] - 18.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn update_flags_stop_triggered_once_price_crosses_stop_loss() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.current_symbol_price = 1100.0;
+        assert!(!position.is_stop_triggered());
+
+        position.update_stop_loss(1050.0).unwrap();
+        assert!(!position.is_stop_triggered());
+
+        // market_event_trade price of 1000.0 crosses the 1050.0 stop
+        let update = position.update(&market_event_trade(Side::Buy)).unwrap();
+        assert!(update.stop_triggered);
+        assert!(position.is_stop_triggered());
+
+        // Short stop is triggered when price rises to meet it
+        position.side = Side::Sell;
+        position.stop_loss_price = Some(1000.0);
+        assert!(position.is_stop_triggered());
+        position.current_symbol_price = 999.0;
+        assert!(!position.is_stop_triggered());
+    }
+
+    fn closed_long_position_builder(realised_profit_loss: f64) -> PositionBuilder {
+        Position::builder()
+            .position_id("position_id".to_owned())
+            .exchange(Exchange::from("binance"))
+            .instrument(Instrument::from(("eth", "usdt", InstrumentKind::Spot)))
+            .meta(PositionMeta {
+                exit_balance: Some(Balance::default()),
+                ..PositionMeta::default()
+            })
+            .side(Side::Buy)
+            .quantity(1.0)
+            .enter_fees(Fees::default())
+            .enter_fees_total(1.0)
+            .enter_avg_price_gross(100.0)
+            .enter_value_gross(100.0)
+            .exit_fees(Fees::default())
+            .exit_fees_total(1.0)
+            .exit_avg_price_gross(90.0)
+            .exit_value_gross(90.0)
+            .current_symbol_price(90.0)
+            .current_value_gross(90.0)
+            .unrealised_profit_loss(0.0)
+            .realised_profit_loss(realised_profit_loss)
+    }
+
+    #[test]
+    fn strict_position_builder_rejects_inconsistent_realised_profit_loss() {
+        // Long exited below entry with 2.0 total fees => -12.0
+        assert!(closed_long_position_builder(-12.0).strict().build().is_ok());
+
+        assert!(matches!(
+            closed_long_position_builder(12.0).strict().build(),
+            Err(PortfolioError::InconsistentPositionState { .. })
+        ));
+
+        // Consistency is only verified in strict mode
+        assert!(closed_long_position_builder(12.0).build().is_ok());
+    }
+
+    #[test]
+    fn sanity_check_detects_inconsistent_exit_and_fee_fields() {
+        let mut open = position();
+        assert!(open.sanity_check().is_ok());
+
+        open.exit_avg_price_gross = 90.0;
+        match open.sanity_check() {
+            Err(PortfolioError::InconsistentPositionState {
+                field,
+                expected,
+                actual,
+            }) => {
+                assert_eq!(field, "exit_avg_price_gross");
+                assert_eq!(expected, "0");
+                assert_eq!(actual, "90");
+            }
+            other => panic!("expected InconsistentPositionState error, got: {:?}", other),
+        }
+
+        let mut closed = closed_long_position_builder(-12.0).build().unwrap();
+        closed.exit_fees_total = closed.exit_fees.calculate_total_fees();
+        closed.enter_fees_total = closed.enter_fees.calculate_total_fees();
+        assert!(closed.sanity_check().is_ok());
+
+        closed.enter_fees_total = 5.0;
+        assert!(matches!(
+            closed.sanity_check(),
+            Err(PortfolioError::InconsistentPositionState {
+                field: "enter_fees_total",
+                ..
+            })
+        ));
+
+        closed.enter_fees_total = closed.enter_fees.calculate_total_fees();
+        closed.exit_value_gross = 0.0;
+        assert!(matches!(
+            closed.sanity_check(),
+            Err(PortfolioError::InconsistentPositionState {
+                field: "exit_value_gross",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn strict_position_builder_ignores_open_position() {
+        let position = closed_long_position_builder(0.0)
+            .meta(PositionMeta::default())
+            .strict()
+            .build();
+
+        assert!(position.is_ok());
+    }
+
+    #[test]
+    fn position_builder_from_position_builds_modified_copy() {
+        let mut original = position();
+        original.stop_loss_price = Some(90.0);
+        original.partially_exited_quantity = 0.5;
+
+        // Unmodified builder reproduces the Position
+        let copy = PositionBuilder::from_position(&original).build().unwrap();
+        assert_eq!(copy, original);
+
+        // Hypothetical exit at a higher price
+        let closed = closed_long_position_builder(-12.0).build().unwrap();
+        let hypothetical = PositionBuilder::from_position(&closed)
+            .exit_avg_price_gross(120.0)
+            .exit_value_gross(120.0)
+            .realised_profit_loss(18.0)
+            .strict()
+            .build()
+            .unwrap();
+        assert_eq!(hypothetical.exit_value_gross, 120.0);
+        assert_eq!(hypothetical.enter_value_gross, closed.enter_value_gross);
+
+        // Modified values are validated by a strict build
+        assert!(matches!(
+            PositionBuilder::from_position(&closed)
+                .exit_value_gross(120.0)
+                .strict()
+                .build(),
+            Err(PortfolioError::InconsistentPositionState { .. })
+        ));
+    }
+
+    #[test]
+    fn build_collecting_errors_reports_every_validation_failure() {
+        let position = position();
+        let builder = PositionBuilder::from_position(&position);
+        assert_eq!(builder.build_collecting_errors().unwrap(), position);
+
+        let builder = PositionBuilder {
+            meta: None,
+            quantity: None,
+            current_symbol_price: None,
+            ..builder
+        };
+
+        let error = builder.build_collecting_errors().unwrap_err();
+        match &error {
+            PortfolioError::Multiple(errors) => {
+                let fields = errors
+                    .iter()
+                    .map(|error| match error {
+                        PortfolioError::BuilderIncomplete(field) => *field,
+                        other => panic!("expected BuilderIncomplete error, got: {other:?}"),
+                    })
+                    .collect::<Vec<_>>();
+                assert_eq!(fields, ["meta", "quantity", "current_symbol_price"]);
+            }
+            other => panic!("expected Multiple error, got: {other:?}"),
+        }
+
+        let message = error.to_string();
+        assert!(message.starts_with("3 errors: 1) "));
+        for field in ["meta", "quantity", "current_symbol_price"] {
+            assert!(message.contains(field));
+        }
+
+        // Strict consistency failures are reported once every field is present
+        assert!(matches!(
+            closed_long_position_builder(12.0).strict().build_collecting_errors(),
+            Err(PortfolioError::Multiple(errors))
+                if matches!(errors[..], [PortfolioError::InconsistentPositionState { .. }])
+        ));
+    }
+
+    #[test]
+    fn remaining_quantity_excludes_partially_exited_quantity() {
+        let mut short = position();
+        short.side = Side::Sell;
+        short.quantity = -10.0;
+        assert_eq!(short.remaining_quantity(), 10.0);
+
+        short.partially_exited_quantity = 4.0;
+        assert_eq!(short.remaining_quantity(), 6.0);
+
+        let entered = Position::enter(Uuid::new_v4(), &fill_event()).unwrap();
+        assert_eq!(entered.partially_exited_quantity, 0.0);
+        assert_eq!(entered.total_realised_profit_loss, 0.0);
+
+        let built = closed_long_position_builder(-12.0)
+            .partially_exited_quantity(0.5)
+            .total_realised_profit_loss(3.0)
+            .build()
+            .unwrap();
+        assert_eq!(built.remaining_quantity(), 0.5);
+        assert_eq!(built.total_realised_profit_loss, 3.0);
+        assert_eq!(
+            closed_long_position_builder(-12.0)
+                .build()
+                .unwrap()
+                .remaining_quantity(),
+            1.0
+        );
+    }
+
+    #[test]
+    fn pnl_attribution_by_fee_type_components_sum_to_net_pnl() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = 1.0;
+        position.enter_fees = Fees {
+            exchange: 1.0,
+            slippage: 0.5,
+            network: 0.25,
+        };
+        position.enter_fees_total = 1.75;
+        position.enter_value_gross = 100.0;
+        position.exit_fees = Fees {
+            exchange: 2.0,
+            slippage: 1.0,
+            network: 0.5,
+        };
+        position.exit_fees_total = 3.5;
+        position.exit_value_gross = 200.0;
+        position.realised_profit_loss = position.calculate_realised_profit_loss();
+
+        let attribution = position.pnl_attribution_by_fee_type();
+
+        assert_eq!(attribution["gross_pnl"], 100.0);
+        assert_eq!(attribution["exchange_fees"], -3.0);
+        assert_eq!(attribution["slippage_fees"], -1.5);
+        assert_eq!(attribution["network_fees"], -0.75);
+        assert_eq!(attribution["net_pnl"], 94.75);
+
+        let sum_of_components = attribution["gross_pnl"]
+            + attribution["exchange_fees"]
+            + attribution["slippage_fees"]
+            + attribution["network_fees"];
+        assert!((sum_of_components - attribution["net_pnl"]).abs() < 1e-10);
+    }
+
+    #[test]
+    fn pnl_attribution_by_fee_type_of_losing_short_has_negative_gross_pnl() {
+        let mut position = position();
+        position.side = Side::Sell;
+        position.quantity = -1.0;
+        position.enter_fees = Fees {
+            exchange: 1.0,
+            slippage: 1.0,
+            network: 1.0,
+        };
+        position.enter_fees_total = 3.0;
+        position.enter_value_gross = 100.0;
+        position.exit_fees = Fees {
+            exchange: 1.0,
+            slippage: 1.0,
+            network: 1.0,
+        };
+        position.exit_fees_total = 3.0;
+        position.exit_value_gross = 150.0;
+        position.realised_profit_loss = position.calculate_realised_profit_loss();
+
+        let attribution = position.pnl_attribution_by_fee_type();
+
+        assert_eq!(attribution["gross_pnl"], -50.0);
+        assert!(attribution["exchange_fees"] < 0.0);
+        assert!(attribution["slippage_fees"] < 0.0);
+        assert!(attribution["network_fees"] < 0.0);
+        assert_eq!(attribution["net_pnl"], -56.0);
+
+        let sum_of_components = attribution["gross_pnl"]
+            + attribution["exchange_fees"]
+            + attribution["slippage_fees"]
+            + attribution["network_fees"];
+        assert!((sum_of_components - attribution["net_pnl"]).abs() < 1e-10);
+    }
+
+    #[test]
+    fn summary_table_of_closed_and_open_positions() {
+        let cell = |table: &Table, row: usize| {
+            let row = table.get_row(row).unwrap();
+            (
+                row.get_cell(0).unwrap().get_content(),
+                row.get_cell(1).unwrap().get_content(),
+            )
+        };
+
+        let mut position = position();
+        position.meta.update_time =
+            position.meta.enter_time + Duration::days(1) + Duration::minutes(30);
+        let open = position.summary_table();
+
+        assert_eq!(cell(&open, 5), ("Exit Price".to_owned(), "n/a".to_owned()));
+        assert_eq!(cell(&open, 8).0, "Unrealised P&L");
+
+        position.meta.exit_balance = Some(Balance::default());
+        position.exit_avg_price_gross = 110.0;
+        position.enter_fees_total = 1.0;
+        position.exit_fees_total = 1.0;
+        position.realised_profit_loss = 8.0;
+        let closed = position.summary_table();
+
+        assert_eq!(closed.len(), 11);
+        assert_eq!(cell(&closed, 4).1, "100.0000");
+        assert_eq!(cell(&closed, 5).1, "110.0000");
+        assert_eq!(cell(&closed, 6).1, "1.0000");
+        assert_eq!(
+            cell(&closed, 8),
+            ("Realised P&L".to_owned(), "8.0000".to_owned())
+        );
+        assert_eq!(cell(&closed, 9).1, "8.00%");
+        assert_eq!(cell(&closed, 10).1, "1d 0h 30m 0s");
+    }
+
+    #[test]
+    fn position_json_round_trip() {
+        let mut input = position();
+        input.stop_loss_price = Some(90.0);
+
+        let json = input.to_json();
+        assert_eq!(json["position_id"], input.position_id.as_str());
+
+        assert_eq!(Position::from_json(json).unwrap(), input);
+        assert!(matches!(
+            Position::from_json(serde_json::json!({ "position_id": 1 })),
+            Err(PortfolioError::SerializeError(_))
+        ));
+    }
+
+    #[test]
+    fn export_and_import_positions_json_round_trip() {
+        let positions = (0..5)
+            .map(|index| {
+                let mut position = position();
+                position.position_id = format!("position_{index}");
+                position.enter_avg_price_gross = 100.0 + index as f64;
+                position.unrealised_profit_loss = index as f64 * 1.5;
+                position
+            })
+            .collect::<Vec<_>>();
+
+        let path = std::env::temp_dir().join(format!("positions_{}.json", Uuid::new_v4()));
+        export_positions_json(&positions, &path).unwrap();
+        let imported = import_positions_json(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(imported.unwrap(), positions);
+        assert!(matches!(
+            import_positions_json(&path),
+            Err(PortfolioError::IoError(_))
+        ));
+    }
+
+    #[test]
+    fn replay_position_events_reconstructs_updated_position() {
+        let mut position = position();
+        let mut events = vec![PositionEvent::Opened(Box::new(position.clone()))];
+        events.push(PositionEvent::Updated(
+            position.update_with_price(110.0, Utc::now()),
+        ));
+
+        let replayed = Position::replay(&events).unwrap();
+
+        assert_eq!(replayed.current_symbol_price, 110.0);
+        assert_eq!(
+            replayed.unrealised_profit_loss,
+            position.unrealised_profit_loss
+        );
+        assert_eq!(replayed.meta.update_time, position.meta.update_time);
+    }
+
+    #[test]
+    fn replay_rejects_invalid_position_event_sequences() {
+        let mut position = position();
+        let opened = PositionEvent::Opened(Box::new(position.clone()));
+        let updated = PositionEvent::Updated(position.update_with_price(110.0, Utc::now()));
+
+        let mut other = position.clone();
+        other.position_id = "other_position".to_owned();
+        let other_updated = PositionEvent::Updated(other.update_with_price(110.0, Utc::now()));
+
+        let invalid_sequences = [
+            vec![],
+            vec![updated.clone()],
+            vec![opened.clone(), opened.clone()],
+            vec![opened, other_updated],
+        ];
+
+        for events in invalid_sequences {
+            assert!(matches!(
+                Position::replay(&events),
+                Err(PortfolioError::PositionReplay(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    mod proptest_position {
+        use super::*;
+        use proptest::prelude::*;
+
+        const EPSILON: f64 = 1e-6;
+
+        /// Generates arbitrary non-negative [`Fees`].
+        fn fees() -> impl Strategy<Value = Fees> {
+            (0.0..10.0_f64, 0.0..10.0_f64, 0.0..10.0_f64).prop_map(
+                |(exchange, slippage, network)| Fees {
+                    exchange,
+                    slippage,
+                    network,
+                },
+            )
+        }
+
+        /// Generates an arbitrary valid entry [`FillEvent`] (Long or Short) alongside a valid
+        /// exit [`FillEvent`] that closes it.
+        fn entry_and_exit_fills() -> impl Strategy<Value = (FillEvent, FillEvent)> {
+            (
+                any::<bool>(),
+                0.001..1_000.0_f64,
+                0.01..100_000.0_f64,
+                0.01..100_000.0_f64,
+                fees(),
+                fees(),
+            )
+                .prop_map(
+                    |(is_long, quantity, enter_price, exit_price, enter_fees, exit_fees)| {
+                        let (entry_decision, exit_decision, entry_quantity) = match is_long {
+                            true => (Decision::Long, Decision::CloseLong, quantity),
+                            false => (Decision::Short, Decision::CloseShort, -quantity),
+                        };
+
+                        let mut entry = fill_event();
+                        entry.decision = entry_decision;
+                        entry.quantity = entry_quantity;
+                        entry.fill_value_gross = quantity * enter_price;
+                        entry.fees = enter_fees;
+
+                        let mut exit = fill_event();
+                        exit.decision = exit_decision;
+                        exit.quantity = -entry_quantity;
+                        exit.fill_value_gross = quantity * exit_price;
+                        exit.fees = exit_fees;
+
+                        (entry, exit)
+                    },
+                )
+        }
+
+        /// Enters, updates with every price, & exits a [`Position`], returning the final
+        /// [`Position`] alongside every [`PositionEvent`] generated along the way.
+        fn position_with_events(
+            entry: &FillEvent,
+            prices: &[f64],
+            exit: Option<&FillEvent>,
+        ) -> (Position, Vec<PositionEvent>) {
+            let mut position = Position::enter(Uuid::new_v4(), entry).unwrap();
+            let mut events = vec![PositionEvent::Opened(Box::new(position.clone()))];
+
+            for price in prices {
+                let update = position.update_with_price(*price, Utc::now());
+                events.push(PositionEvent::Updated(update));
+            }
+
+            if let Some(exit) = exit {
+                let position_exit = position.exit(Balance::default(), exit).unwrap();
+                events.push(PositionEvent::Closed(position_exit));
+            }
+
+            (position, events)
+        }
+
+        /// Direction sign of a [`Position`] - +1.0 for Long, -1.0 for Short.
+        fn direction_sign(position: &Position) -> f64 {
+            match position.side {
+                Side::Buy => 1.0,
+                Side::Sell => -1.0,
+            }
+        }
+
+        proptest! {
+            #[test]
+            fn realised_profit_loss_plus_fees_equals_signed_gross_value_change(
+                (entry, exit) in entry_and_exit_fills()
+            ) {
+                let mut position = Position::enter(Uuid::new_v4(), &entry).unwrap();
+                position.exit(Balance::default(), &exit).unwrap();
+
+                let actual = position.calculate_realised_profit_loss()
+                    + position.enter_fees_total
+                    + position.exit_fees_total;
+                let expected = (position.exit_value_gross - position.enter_value_gross)
+                    * direction_sign(&position);
+
+                prop_assert!((actual - expected).abs() < EPSILON);
+            }
+
+            #[test]
+            fn unrealised_profit_loss_at_entry_is_only_approximate_fees(
+                (entry, _) in entry_and_exit_fills()
+            ) {
+                let position = Position::enter(Uuid::new_v4(), &entry).unwrap();
+
+                let unrealised_excluding_fees =
+                    position.unrealised_profit_loss + position.enter_fees_total * 2.0;
+
+                prop_assert!(unrealised_excluding_fees.abs() < EPSILON);
+                prop_assert!(
+                    (position.calculate_unrealised_profit_loss() - position.unrealised_profit_loss)
+                        .abs() < EPSILON
+                );
+            }
+
+            #[test]
+            fn profit_loss_return_scaled_by_enter_value_equals_realised_profit_loss(
+                (entry, exit) in entry_and_exit_fills()
+            ) {
+                let mut position = Position::enter(Uuid::new_v4(), &entry).unwrap();
+                position.exit(Balance::default(), &exit).unwrap();
+
+                let actual = position.calculate_profit_loss_return() * position.enter_value_gross;
+
+                prop_assert!((actual - position.realised_profit_loss).abs() < EPSILON);
+            }
+
+            #[test]
+            fn update_with_any_trade_price_keeps_current_value_consistent(
+                (entry, _) in entry_and_exit_fills(),
+                price in 0.01..100_000.0_f64,
+            ) {
+                let mut position = Position::enter(Uuid::new_v4(), &entry).unwrap();
+
+                let mut market = market_event_trade(Side::Buy);
+                if let DataKind::Trade(ref mut trade) = market.kind {
+                    trade.price = price;
+                }
+
+                let update = position.update(&market).unwrap();
+
+                prop_assert_eq!(update.current_symbol_price, price);
+                prop_assert!(
+                    (position.current_value_gross - price * position.quantity.abs()).abs()
+                        < EPSILON
+                );
+            }
+
+            #[test]
+            fn replaying_position_events_reconstructs_the_position(
+                (entry, exit) in entry_and_exit_fills(),
+                prices in proptest::collection::vec(0.01..100_000.0_f64, 0..10),
+                is_exited in any::<bool>(),
+            ) {
+                let (position, events) =
+                    position_with_events(&entry, &prices, is_exited.then_some(&exit));
+
+                let replayed = Position::replay(&events).unwrap();
+
+                prop_assert_eq!(replayed.meta.trace_chain(), position.meta.trace_chain());
+                prop_assert_eq!(replayed, position);
+            }
+        }
+    }
 }
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::portfolio::{OrderEvent, OrderType};
+use crate::portfolio::{
+    analysis::gross_exposure, position::Position, Balance, OrderEvent, OrderType,
+};
+use chrono::{DateTime, NaiveDate, Utc};
 
 /// Evaluates the risk associated with an [`OrderEvent`] to determine if it should be actioned. It
 /// can also amend the order (eg/ [`OrderType`]) to better fit the risk strategy required for
@@ -34,3 +37,294 @@ impl DefaultRisk {
         false
     }
 }
+
+/// Circuit breaker that halts the opening of new [`Position`](super::position::Position)s after
+/// a configured number of consecutive losing trades. A `consecutive_loss_limit` of zero disables
+/// the [`CircuitBreaker`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CircuitBreaker {
+    /// Number of consecutive losing trades that triggers the [`CircuitBreaker`], or zero if
+    /// disabled.
+    pub consecutive_loss_limit: usize,
+    /// Number of consecutive losing trades since the last winning trade (or reset).
+    pub current_consecutive_losses: usize,
+    /// Determines if the [`CircuitBreaker`] has been triggered.
+    pub triggered: bool,
+}
+
+impl CircuitBreaker {
+    /// Constructs a new un-triggered [`CircuitBreaker`] with the provided consecutive loss limit.
+    /// A limit of zero constructs a disabled [`CircuitBreaker`] that never triggers.
+    pub fn new(consecutive_loss_limit: usize) -> Self {
+        Self {
+            consecutive_loss_limit,
+            current_consecutive_losses: 0,
+            triggered: false,
+        }
+    }
+
+    /// Updates the [`CircuitBreaker`] using the realised profit & loss of an exited
+    /// [`Position`](super::position::Position). A loss increments the consecutive loss counter,
+    /// whereas a profit resets it. Returns true if the [`CircuitBreaker`] is triggered.
+    ///
+    /// A disabled [`CircuitBreaker`] still counts consecutive losses, but never triggers.
+    pub fn update(&mut self, realised_profit_loss: f64) -> bool {
+        if realised_profit_loss < 0.0 {
+            self.current_consecutive_losses += 1;
+        } else if realised_profit_loss > 0.0 {
+            self.current_consecutive_losses = 0;
+        }
+
+        if !self.is_disabled() && self.current_consecutive_losses >= self.consecutive_loss_limit {
+            self.triggered = true;
+        }
+
+        self.triggered
+    }
+
+    /// Determines if the [`CircuitBreaker`] is disabled, ie/ it's consecutive loss limit is zero.
+    pub fn is_disabled(&self) -> bool {
+        self.consecutive_loss_limit == 0
+    }
+
+    /// Manually resets the [`CircuitBreaker`], clearing the consecutive loss counter and allowing
+    /// new [`Position`](super::position::Position)s to be opened.
+    pub fn reset(&mut self) {
+        self.current_consecutive_losses = 0;
+        self.triggered = false;
+    }
+}
+
+/// Portfolio-wide limits on the [`Position`]s that may be open concurrently, enforced by the
+/// [`MetaPortfolio`](super::portfolio::MetaPortfolio) when generating entry [`OrderEvent`]s.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct PositionLimits {
+    /// Optional maximum number of concurrently open [`Position`]s.
+    pub max_open_positions: Option<usize>,
+    /// Optional maximum gross exposure (sum of every open [`Position`]'s gross value, eg/ in
+    /// USD).
+    pub max_gross_exposure: Option<f64>,
+}
+
+impl PositionLimits {
+    /// Determines if no limits are configured.
+    pub fn is_unlimited(&self) -> bool {
+        self.max_open_positions.is_none() && self.max_gross_exposure.is_none()
+    }
+
+    /// Determines if entering a new [`Position`] worth `entry_value_gross` would breach a limit,
+    /// given the currently open [`Position`]s & the gross values of any pending entries that are
+    /// yet to be filled.
+    pub fn breached_by(
+        &self,
+        open_positions: &[Position],
+        pending_entries: &[f64],
+        entry_value_gross: f64,
+    ) -> bool {
+        let open_count = open_positions.len() + pending_entries.len();
+        if self
+            .max_open_positions
+            .is_some_and(|limit| open_count >= limit)
+        {
+            return true;
+        }
+
+        self.max_gross_exposure.is_some_and(|limit| {
+            let open_positions = open_positions.iter().collect::<Vec<_>>();
+            let exposure = gross_exposure(&open_positions)
+                + pending_entries.iter().sum::<f64>()
+                + entry_value_gross;
+            exposure > limit
+        })
+    }
+}
+
+/// Determines if the intraday loss, as a fraction of the start-of-day equity, is greater than or
+/// equal to the provided limit_fraction (eg/ 0.02 for 2%).
+///
+/// A non-positive start-of-day equity leaves nothing to lose, so is always considered breached.
+pub fn daily_loss_limit_breached(
+    current_equity: f64,
+    start_of_day_equity: f64,
+    limit_fraction: f64,
+) -> bool {
+    if start_of_day_equity <= 0.0 {
+        return true;
+    }
+
+    (start_of_day_equity - current_equity) / start_of_day_equity >= limit_fraction
+}
+
+/// Daily loss limit that is breached when the intraday loss of the Portfolio equity exceeds a
+/// fraction of the start-of-day equity. Days are delimited in UTC.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DailyLossLimit {
+    /// Fraction of the start-of-day equity that may be lost before the limit is breached.
+    pub limit_fraction: f64,
+    /// Portfolio equity at the start of the current day.
+    pub start_of_day_equity: f64,
+    /// Most recent Portfolio equity, used as the next day's start-of-day equity.
+    pub last_equity: f64,
+    /// Current UTC day being tracked. `None` until the first [`Balance`] update.
+    pub day: Option<NaiveDate>,
+    /// Determines if the [`DailyLossLimit`] has been breached.
+    pub breached: bool,
+}
+
+impl DailyLossLimit {
+    /// Constructs a new [`DailyLossLimit`] using the provided limit fraction & starting equity.
+    pub fn new(limit_fraction: f64, starting_equity: f64) -> Self {
+        Self {
+            limit_fraction,
+            start_of_day_equity: starting_equity,
+            last_equity: starting_equity,
+            day: None,
+            breached: false,
+        }
+    }
+
+    /// Rolls the [`DailyLossLimit`] over if the provided time is from a new UTC day, sampling
+    /// the most recent Portfolio equity as the start-of-day equity & clearing any breach from
+    /// the previous day. Returns true if the [`DailyLossLimit`] rolled over.
+    pub fn roll_over(&mut self, time: DateTime<Utc>) -> bool {
+        let time_day = time.date_naive();
+
+        match self.day {
+            Some(day) if day >= time_day => false,
+            Some(_) => {
+                self.day = Some(time_day);
+                self.start_of_day_equity = self.last_equity;
+                self.breached = false;
+                true
+            }
+            None => {
+                self.day = Some(time_day);
+                false
+            }
+        }
+    }
+
+    /// Updates the [`DailyLossLimit`] using the latest Portfolio [`Balance`]. Rolls the
+    /// start-of-day equity over if the [`Balance`] is from a new day. Returns true if the
+    /// [`DailyLossLimit`] is breached.
+    pub fn update(&mut self, balance: &Balance) -> bool {
+        self.roll_over(balance.time);
+
+        self.last_equity = balance.total;
+
+        if daily_loss_limit_breached(balance.total, self.start_of_day_equity, self.limit_fraction) {
+            self.breached = true;
+        }
+
+        self.breached
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circuit_breaker_triggers_at_exactly_the_consecutive_loss_limit() {
+        let mut circuit_breaker = CircuitBreaker::new(3);
+
+        assert!(!circuit_breaker.update(-10.0));
+        assert!(!circuit_breaker.update(-10.0));
+        assert!(circuit_breaker.update(-10.0));
+        assert_eq!(circuit_breaker.current_consecutive_losses, 3);
+
+        // Remains triggered until manually reset, even after a profit
+        assert!(circuit_breaker.update(10.0));
+
+        circuit_breaker.reset();
+        assert_eq!(circuit_breaker, CircuitBreaker::new(3));
+    }
+
+    #[test]
+    fn circuit_breaker_resets_consecutive_losses_after_a_profit() {
+        let mut circuit_breaker = CircuitBreaker::new(2);
+
+        assert!(!circuit_breaker.update(-10.0));
+        assert!(!circuit_breaker.update(10.0));
+        assert_eq!(circuit_breaker.current_consecutive_losses, 0);
+
+        assert!(!circuit_breaker.update(-10.0));
+        assert!(circuit_breaker.update(-10.0));
+    }
+
+    #[test]
+    fn circuit_breaker_with_zero_loss_limit_is_disabled() {
+        let mut circuit_breaker = CircuitBreaker::new(0);
+        assert!(circuit_breaker.is_disabled());
+
+        assert!(!circuit_breaker.update(-10.0));
+        assert!(!circuit_breaker.update(0.0));
+        assert!(!circuit_breaker.update(-10.0));
+        assert_eq!(circuit_breaker.current_consecutive_losses, 2);
+        assert!(!circuit_breaker.triggered);
+    }
+
+    #[test]
+    fn daily_loss_limit_breached_at_or_beyond_limit_fraction() {
+        assert!(!daily_loss_limit_breached(990.0, 1000.0, 0.02));
+        assert!(daily_loss_limit_breached(980.0, 1000.0, 0.02));
+        assert!(daily_loss_limit_breached(900.0, 1000.0, 0.02));
+        assert!(!daily_loss_limit_breached(1100.0, 1000.0, 0.02));
+    }
+
+    #[test]
+    fn daily_loss_limit_breached_if_start_of_day_equity_is_not_positive() {
+        assert!(daily_loss_limit_breached(0.0, 0.0, 0.02));
+        assert!(daily_loss_limit_breached(100.0, 0.0, 0.02));
+        assert!(daily_loss_limit_breached(-50.0, -100.0, 0.02));
+        assert!(daily_loss_limit_breached(-150.0, -100.0, 0.02));
+    }
+
+    #[test]
+    fn daily_loss_limit_rolls_start_of_day_equity_over_on_new_day() {
+        let mut limit = DailyLossLimit::new(0.05, 1000.0);
+        let day_one = chrono::Utc::now();
+
+        let balance = |time, total| Balance {
+            time,
+            total,
+            available: total,
+        };
+
+        // Day one: 4% loss from 1000.0 does not breach
+        assert!(!limit.update(&balance(day_one, 960.0)));
+
+        // Day two: start-of-day equity rolls over to 960.0, so a further 4% loss does not breach
+        let day_two = day_one + chrono::Duration::days(1);
+        assert!(!limit.update(&balance(day_two, 925.0)));
+        assert_eq!(limit.start_of_day_equity, 960.0);
+
+        // Day two: 6.25% loss from 960.0 breaches
+        assert!(limit.update(&balance(day_two, 900.0)));
+    }
+
+    #[test]
+    fn daily_loss_limit_roll_over_samples_equity_and_clears_breach() {
+        let mut limit = DailyLossLimit::new(0.05, 1000.0);
+        let day_one = chrono::Utc::now();
+
+        // Day one: 10% loss from 1000.0 breaches
+        assert!(limit.update(&Balance {
+            time: day_one,
+            total: 900.0,
+            available: 900.0,
+        }));
+
+        // Same day: no roll over, breach persists
+        assert!(!limit.roll_over(day_one));
+        assert!(limit.breached);
+
+        // Day two: equity at the roll over becomes the start-of-day equity & breach is cleared
+        assert!(limit.roll_over(day_one + chrono::Duration::days(1)));
+        assert!(!limit.breached);
+        assert_eq!(limit.start_of_day_equity, 900.0);
+
+        // Stale timestamps from the previous day do not roll the limit over again
+        assert!(!limit.roll_over(day_one));
+    }
+}
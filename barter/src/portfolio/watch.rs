@@ -0,0 +1,132 @@
+use super::position::{Position, PositionUpdate, PositionUpdater};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use std::ops::{Deref, DerefMut};
+use tokio::sync::watch;
+
+impl Position {
+    /// Wraps the [`Position`] in a [`ManagedPosition`] that publishes it's state to the returned
+    /// [`PositionWatcher`] after every update (eg/ for a reactive dashboard).
+    pub fn watch(self) -> (ManagedPosition, PositionWatcher) {
+        let (tx, rx) = watch::channel(self.clone());
+
+        (
+            ManagedPosition { position: self, tx },
+            PositionWatcher { rx },
+        )
+    }
+}
+
+/// [`Position`] that publishes it's state to every [`PositionWatcher`] after each mutation made
+/// via [`PositionUpdater::update`] or [`ManagedPosition::modify`].
+///
+/// Dereferences to the inner [`Position`] so every existing method is available. Mutations made
+/// directly through [`DerefMut`] are not published until [`ManagedPosition::notify`] is called.
+#[derive(Debug)]
+pub struct ManagedPosition {
+    position: Position,
+    tx: watch::Sender<Position>,
+}
+
+impl ManagedPosition {
+    /// Applies the provided mutation to the inner [`Position`], then publishes the new state.
+    pub fn modify<F, T>(&mut self, mutate: F) -> T
+    where
+        F: FnOnce(&mut Position) -> T,
+    {
+        let output = mutate(&mut self.position);
+        self.notify();
+        output
+    }
+
+    /// Publishes the current state of the inner [`Position`] to every [`PositionWatcher`].
+    pub fn notify(&self) {
+        self.tx.send_replace(self.position.clone());
+    }
+
+    /// Consumes the [`ManagedPosition`], returning the inner [`Position`].
+    pub fn into_inner(self) -> Position {
+        self.position
+    }
+}
+
+impl Deref for ManagedPosition {
+    type Target = Position;
+
+    fn deref(&self) -> &Self::Target {
+        &self.position
+    }
+}
+
+impl DerefMut for ManagedPosition {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.position
+    }
+}
+
+impl PositionUpdater for ManagedPosition {
+    /// Updates the inner [`Position`] using the latest [`MarketEvent`], then publishes the new
+    /// state.
+    fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<PositionUpdate> {
+        self.modify(|position| position.update(market))
+    }
+}
+
+/// Observes the state of a [`ManagedPosition`] published after every mutation.
+#[derive(Clone, Debug)]
+pub struct PositionWatcher {
+    rx: watch::Receiver<Position>,
+}
+
+impl PositionWatcher {
+    /// Returns the latest published [`Position`] state, marking it as seen.
+    pub fn latest(&mut self) -> Position {
+        self.rx.borrow_and_update().clone()
+    }
+
+    /// Determines if a new [`Position`] state has been published since it was last seen via
+    /// [`PositionWatcher::latest`]. Returns false once the [`ManagedPosition`] is dropped.
+    pub fn has_changed(&self) -> bool {
+        self.rx.has_changed().unwrap_or(false)
+    }
+
+    /// Waits for a new [`Position`] state to be published, returning it & marking it as seen.
+    /// Returns `None` once the [`ManagedPosition`] is dropped.
+    pub async fn changed(&mut self) -> Option<Position> {
+        self.rx.changed().await.ok()?;
+        Some(self.latest())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{market_event_trade, position};
+    use barter_integration::model::Side;
+
+    #[test]
+    fn watcher_sees_position_after_managed_update() {
+        let (mut managed, mut watcher) = position().watch();
+        assert!(!watcher.has_changed());
+        assert_eq!(watcher.latest().current_symbol_price, 100.0);
+
+        // market_event_trade price is 1000.0
+        let update = managed.update(&market_event_trade(Side::Buy)).unwrap();
+        assert_eq!(update.current_symbol_price, 1000.0);
+
+        assert!(watcher.has_changed());
+        let latest = watcher.latest();
+        assert_eq!(latest, *managed);
+        assert_eq!(latest.current_symbol_price, 1000.0);
+        assert!(!watcher.has_changed());
+
+        // Mutations via DerefMut are only published by notify
+        managed.stop_loss_price = Some(900.0);
+        assert!(!watcher.has_changed());
+        managed.notify();
+        assert_eq!(watcher.latest().stop_loss_price, Some(900.0));
+
+        drop(managed);
+        assert!(!watcher.has_changed());
+    }
+}
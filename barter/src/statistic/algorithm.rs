@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 /// Grouping of [Welford Online](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm)
 /// algorithms for calculating running values such as mean and variance in one pass through.
 pub mod welford_online {
@@ -38,10 +40,74 @@ pub mod welford_online {
     }
 }
 
+/// Running count, mean & Welford Online recurrence relation M of a dataset, updated one value at
+/// a time via [`RunningStats::update`], or collected from an iterator of values.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct RunningStats {
+    pub count: usize,
+    pub mean: f64,
+    pub m2: f64,
+}
+
+impl RunningStats {
+    /// Iteratively updates the count, mean & recurrence relation M given the next value in the
+    /// dataset.
+    pub fn update(&mut self, new_value: f64) {
+        self.count += 1;
+
+        let prev_mean = self.mean;
+        self.mean = welford_online::calculate_mean(prev_mean, new_value, self.count as f64);
+        self.m2 = welford_online::calculate_recurrence_relation_m(
+            self.m2, prev_mean, new_value, self.mean,
+        );
+    }
+
+    /// Calculates the 'Population' Variance of the dataset.
+    pub fn variance(&self) -> f64 {
+        welford_online::calculate_population_variance(self.m2, self.count as u64)
+    }
+
+    /// Calculates the 'Population' Standard Deviation of the dataset.
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl FromIterator<f64> for RunningStats {
+    fn from_iter<Values: IntoIterator<Item = f64>>(values: Values) -> Self {
+        values
+            .into_iter()
+            .fold(RunningStats::default(), |mut stats, value| {
+                stats.update(value);
+                stats
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn running_stats_matches_population_mean_and_std_dev() {
+        let mut stats = RunningStats::default();
+        assert_eq!(stats.std_dev(), 0.0);
+
+        for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            stats.update(value);
+        }
+
+        assert_eq!(stats.count, 8);
+        assert!((stats.mean - 5.0).abs() < 1e-10);
+        assert!((stats.variance() - 4.0).abs() < 1e-10);
+        assert!((stats.std_dev() - 2.0).abs() < 1e-10);
+
+        let collected = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+            .into_iter()
+            .collect::<RunningStats>();
+        assert_eq!(collected, stats);
+    }
+
     #[test]
     fn calculate_mean() {
         struct Input {
@@ -0,0 +1,148 @@
+use crate::statistic::{
+    algorithm::RunningStats,
+    metric::{returns::total_return, EquityPoint},
+};
+use serde::{Deserialize, Serialize};
+
+/// Comparison of a strategy equity curve against a benchmark (eg/ buy-and-hold) return series.
+/// Generated via [`benchmark_comparison`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BenchmarkStats {
+    /// Total return of the strategy relative to the initial equity.
+    pub strategy_return: f64,
+    /// Compounded total return of the benchmark.
+    pub benchmark_return: f64,
+    /// Per period intercept of the regression of strategy returns on benchmark returns.
+    pub alpha: f64,
+    /// Slope of the regression of strategy returns on benchmark returns.
+    pub beta: f64,
+    /// Pearson correlation of the strategy & benchmark returns. Zero if the strategy returns
+    /// have no variance.
+    pub correlation: f64,
+    /// Standard deviation of the per period strategy returns in excess of the benchmark.
+    pub tracking_error: f64,
+}
+
+/// Compares the strategy equity curve against the benchmark period returns, where each benchmark
+/// return aligns with the corresponding [`EquityPoint::to_return_series`] period return.
+///
+/// Alpha & beta are derived from an ordinary least squares regression of the strategy period
+/// returns on the benchmark period returns. Population statistics are used throughout.
+///
+/// Returns `None` if the number of benchmark returns does not match the number of strategy period
+/// returns, there are fewer than two periods, the initial equity is not positive, or the
+/// benchmark returns have no variance.
+pub fn benchmark_comparison(
+    equity_curve: &[EquityPoint],
+    benchmark_returns: &[f64],
+    initial_equity: f64,
+) -> Option<BenchmarkStats> {
+    let strategy_returns = EquityPoint::to_return_series(equity_curve);
+
+    if strategy_returns.len() != benchmark_returns.len() || strategy_returns.len() < 2 {
+        return None;
+    }
+
+    let strategy = strategy_returns.iter().copied().collect::<RunningStats>();
+    let benchmark = benchmark_returns.iter().copied().collect::<RunningStats>();
+
+    if benchmark.variance() == 0.0 {
+        return None;
+    }
+
+    let covariance = strategy_returns
+        .iter()
+        .zip(benchmark_returns)
+        .map(|(strategy_return, benchmark_return)| {
+            (strategy_return - strategy.mean) * (benchmark_return - benchmark.mean)
+        })
+        .sum::<f64>()
+        / strategy.count as f64;
+
+    let beta = covariance / benchmark.variance();
+    let alpha = strategy.mean - beta * benchmark.mean;
+    let correlation = match strategy.variance() == 0.0 {
+        true => 0.0,
+        false => covariance / (strategy.std_dev() * benchmark.std_dev()),
+    };
+
+    let tracking_error = strategy_returns
+        .iter()
+        .zip(benchmark_returns)
+        .map(|(strategy_return, benchmark_return)| strategy_return - benchmark_return)
+        .collect::<RunningStats>()
+        .std_dev();
+
+    Some(BenchmarkStats {
+        strategy_return: total_return(equity_curve, initial_equity)?,
+        benchmark_return: benchmark_returns
+            .iter()
+            .fold(1.0, |growth, period_return| growth * (1.0 + period_return))
+            - 1.0,
+        alpha,
+        beta,
+        correlation,
+        tracking_error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    fn equity_curve(totals: &[f64]) -> Vec<EquityPoint> {
+        let base_time = Utc::now();
+        totals
+            .iter()
+            .enumerate()
+            .map(|(day, &total)| EquityPoint {
+                time: base_time + Duration::days(day as i64),
+                total,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn benchmark_comparison_against_identical_returns() {
+        let curve = equity_curve(&[1000.0, 1100.0, 1045.0, 1149.5]);
+        let benchmark_returns = EquityPoint::to_return_series(&curve);
+
+        let stats = benchmark_comparison(&curve, &benchmark_returns, 1000.0).unwrap();
+
+        assert!((stats.strategy_return - 0.1495).abs() < 1e-12);
+        assert!((stats.benchmark_return - stats.strategy_return).abs() < 1e-12);
+        assert!(stats.alpha.abs() < 1e-12);
+        assert!((stats.beta - 1.0).abs() < 1e-12);
+        assert!((stats.correlation - 1.0).abs() < 1e-12);
+        assert!(stats.tracking_error.abs() < 1e-12);
+    }
+
+    #[test]
+    fn benchmark_comparison_against_leveraged_returns() {
+        // Strategy returns are double the benchmark returns plus 1% per period
+        let benchmark_returns = [0.05, -0.02, 0.03];
+        let mut totals = vec![1000.0];
+        for benchmark in benchmark_returns {
+            let last = totals[totals.len() - 1];
+            totals.push(last * (1.0 + 2.0 * benchmark + 0.01));
+        }
+
+        let stats =
+            benchmark_comparison(&equity_curve(&totals), &benchmark_returns, 1000.0).unwrap();
+
+        assert!((stats.alpha - 0.01).abs() < 1e-12);
+        assert!((stats.beta - 2.0).abs() < 1e-12);
+        assert!((stats.correlation - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn benchmark_comparison_is_none_for_misaligned_or_degenerate_inputs() {
+        let curve = equity_curve(&[1000.0, 1100.0, 1045.0]);
+
+        assert_eq!(benchmark_comparison(&curve, &[0.1], 1000.0), None);
+        assert_eq!(benchmark_comparison(&curve, &[0.1, 0.1], 1000.0), None);
+        assert_eq!(benchmark_comparison(&curve, &[0.1, -0.05], 0.0), None);
+        assert_eq!(benchmark_comparison(&[], &[], 1000.0), None);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::statistic::{algorithm::RunningStats, metric::EquityPoint};
+use rand::Rng;
+
+/// Minimum number of [`EquityPoint`]s required to calculate a meaningful bootstrap confidence
+/// interval.
+pub const BOOTSTRAP_MIN_DATA_POINTS: usize = 10;
+
+/// Calculates a bootstrap confidence interval for the Sharpe Ratio of an equity curve, returning
+/// the `(lower, upper)` bounds.
+///
+/// Each of the `n_bootstrap` samples resamples the equity curve period returns with replacement
+/// and calculates the Sharpe Ratio of the resample relative to the per period `risk_free_rate`.
+/// The bounds are the Sharpe Ratios at the `(1 - confidence) / 2` & `(1 + confidence) / 2`
+/// percentiles of the bootstrap distribution.
+///
+/// Returns `None` if the equity curve has fewer than [`BOOTSTRAP_MIN_DATA_POINTS`], or
+/// `n_bootstrap` is zero.
+///
+/// See documentation: <https://en.wikipedia.org/wiki/Bootstrapping_(statistics)>
+pub fn sharpe_confidence_interval(
+    equity_curve: &[EquityPoint],
+    risk_free_rate: f64,
+    confidence: f64,
+    n_bootstrap: usize,
+    rng: &mut impl Rng,
+) -> Option<(f64, f64)> {
+    if equity_curve.len() < BOOTSTRAP_MIN_DATA_POINTS || n_bootstrap == 0 {
+        return None;
+    }
+
+    let returns = EquityPoint::to_return_series(equity_curve);
+
+    let mut sharpes = (0..n_bootstrap)
+        .map(|_| {
+            let resample = (0..returns.len())
+                .map(|_| returns[rng.gen_range(0..returns.len())])
+                .collect::<Vec<_>>();
+            sharpe_ratio(&resample, risk_free_rate)
+        })
+        .collect::<Vec<_>>();
+    sharpes.sort_by(f64::total_cmp);
+
+    Some((
+        percentile(&sharpes, (1.0 - confidence) / 2.0),
+        percentile(&sharpes, (1.0 + confidence) / 2.0),
+    ))
+}
+
+/// Calculates the Sharpe Ratio of the period returns provided. Returns 0.0 if the returns have no
+/// dispersion, consistent with [`SharpeRatio`](super::ratio::SharpeRatio).
+fn sharpe_ratio(returns: &[f64], risk_free_rate: f64) -> f64 {
+    let returns = returns.iter().copied().collect::<RunningStats>();
+
+    match returns.std_dev() == 0.0 {
+        true => 0.0,
+        false => (returns.mean - risk_free_rate) / returns.std_dev(),
+    }
+}
+
+/// Returns the value at the provided percentile of the ascending sorted values.
+fn percentile(sorted: &[f64], percentile: f64) -> f64 {
+    let index = (percentile * (sorted.len() - 1) as f64).round() as usize;
+    sorted[index.min(sorted.len() - 1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn equity_curve(returns: &[f64]) -> Vec<EquityPoint> {
+        let base_time = Utc::now();
+        let mut total = 100.0;
+
+        std::iter::once(0.0)
+            .chain(returns.iter().copied())
+            .enumerate()
+            .map(|(day, period_return)| {
+                total *= 1.0 + period_return;
+                EquityPoint {
+                    time: base_time + Duration::days(day as i64),
+                    total,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sharpe_confidence_interval_contains_sample_sharpe() {
+        let curve = equity_curve(&[
+            0.01, -0.005, 0.02, 0.003, -0.01, 0.015, 0.007, -0.002, 0.012, 0.004, -0.006, 0.009,
+        ]);
+        let sample_sharpe = sharpe_ratio(&EquityPoint::to_return_series(&curve), 0.0);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let (lower, upper) = sharpe_confidence_interval(&curve, 0.0, 0.95, 1000, &mut rng).unwrap();
+
+        assert!(lower < sample_sharpe && sample_sharpe < upper);
+
+        // Narrower confidence yields a narrower interval from the same resamples
+        let mut rng = StdRng::seed_from_u64(42);
+        let (narrow_lower, narrow_upper) =
+            sharpe_confidence_interval(&curve, 0.0, 0.5, 1000, &mut rng).unwrap();
+        assert!(lower <= narrow_lower && narrow_upper <= upper);
+    }
+
+    #[test]
+    fn sharpe_confidence_interval_requires_enough_data() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let short_curve = equity_curve(&[0.01; 8]);
+        assert_eq!(
+            sharpe_confidence_interval(&short_curve, 0.0, 0.95, 100, &mut rng),
+            None
+        );
+
+        let curve = equity_curve(&[0.01; 9]);
+        assert_eq!(
+            sharpe_confidence_interval(&curve, 0.0, 0.95, 0, &mut rng),
+            None
+        );
+        assert!(sharpe_confidence_interval(&curve, 0.0, 0.95, 100, &mut rng).is_some());
+    }
+}
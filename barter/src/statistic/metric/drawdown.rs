@@ -192,6 +192,52 @@ impl AvgDrawdown {
     }
 }
 
+/// Calculates the longest [`Duration`] between an equity high-water-mark and the time the equity
+/// curve next recovers to that level. Drawdowns that have not yet recovered are excluded (see
+/// [`current_drawdown_duration`]), so a curve without a recovered drawdown yields a zero
+/// [`Duration`].
+///
+/// Returns `None` for empty or single-point equity curves.
+pub fn max_drawdown_duration(equity_curve: &[EquityPoint]) -> Option<Duration> {
+    drawdown_durations(equity_curve).map(|(max_recovered, _)| max_recovered)
+}
+
+/// Calculates the [`Duration`] of the ongoing drawdown, measured from the equity high-water-mark
+/// to the final [`EquityPoint`].
+///
+/// Returns `None` if the equity curve has recovered to its peak, or is empty or single-point.
+pub fn current_drawdown_duration(equity_curve: &[EquityPoint]) -> Option<Duration> {
+    drawdown_durations(equity_curve).and_then(|(_, ongoing)| ongoing)
+}
+
+/// Walks the equity curve, returning the longest recovered drawdown [`Duration`] & the
+/// [`Duration`] of any ongoing drawdown. Returns `None` if the equity curve has fewer than two
+/// [`EquityPoint`]s.
+fn drawdown_durations(equity_curve: &[EquityPoint]) -> Option<(Duration, Option<Duration>)> {
+    let (first, rest) = equity_curve.split_first()?;
+    let last = rest.last()?;
+
+    let mut peak = first;
+    let mut in_drawdown = false;
+    let mut max_recovered = Duration::zero();
+
+    for point in rest {
+        if point.total >= peak.total {
+            if in_drawdown {
+                max_recovered = max_recovered.max(point.time - peak.time);
+                in_drawdown = false;
+            }
+            peak = point;
+        } else {
+            in_drawdown = true;
+        }
+    }
+
+    let ongoing = in_drawdown.then(|| last.time - peak.time);
+
+    Some((max_recovered, ongoing))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -576,4 +622,68 @@ mod tests {
             )
         }
     }
+
+    fn equity_curve(points: &[(i64, f64)]) -> Vec<EquityPoint> {
+        let base_time = Utc::now();
+        points
+            .iter()
+            .map(|&(day, total)| EquityPoint {
+                time: base_time.add(Duration::days(day)),
+                total,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn drawdown_durations_of_empty_and_single_point_curves_are_none() {
+        assert_eq!(max_drawdown_duration(&[]), None);
+        assert_eq!(current_drawdown_duration(&[]), None);
+
+        let single = equity_curve(&[(0, 100.0)]);
+        assert_eq!(max_drawdown_duration(&single), None);
+        assert_eq!(current_drawdown_duration(&single), None);
+    }
+
+    #[test]
+    fn drawdown_durations_with_multiple_distinct_drawdowns() {
+        let curve = equity_curve(&[
+            // Drawdown 1: peak day 0, recovers day 3 => 3 days
+            (0, 100.0),
+            (1, 90.0),
+            (2, 95.0),
+            (3, 100.0),
+            // Drawdown 2: peak day 4, recovers day 10 => 6 days
+            (4, 120.0),
+            (5, 80.0),
+            (10, 125.0),
+            // Drawdown 3: peak day 11, recovers day 13 => 2 days
+            (11, 130.0),
+            (12, 129.0),
+            (13, 131.0),
+        ]);
+
+        assert_eq!(max_drawdown_duration(&curve), Some(Duration::days(6)));
+        assert_eq!(current_drawdown_duration(&curve), None);
+    }
+
+    #[test]
+    fn current_drawdown_duration_of_unrecovered_curve() {
+        let curve = equity_curve(&[
+            // Recovered drawdown: peak day 0, recovers day 2 => 2 days
+            (0, 100.0),
+            (1, 99.0),
+            (2, 101.0),
+            // Ongoing drawdown from peak on day 2, longer than any recovered drawdown
+            (5, 90.0),
+            (9, 100.0),
+        ]);
+
+        assert_eq!(max_drawdown_duration(&curve), Some(Duration::days(2)));
+        assert_eq!(current_drawdown_duration(&curve), Some(Duration::days(7)));
+
+        // Curve without a recovered drawdown
+        let falling = equity_curve(&[(0, 100.0), (1, 90.0), (3, 80.0)]);
+        assert_eq!(max_drawdown_duration(&falling), Some(Duration::zero()));
+        assert_eq!(current_drawdown_duration(&falling), Some(Duration::days(3)));
+    }
 }
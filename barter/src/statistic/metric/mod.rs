@@ -1,12 +1,19 @@
 use crate::{
     portfolio::{position::Position, Balance},
-    statistic::summary::PositionSummariser,
+    statistic::{algorithm::RunningStats, summary::PositionSummariser},
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
+pub mod benchmark;
+#[cfg(feature = "statistics")]
+pub mod bootstrap;
 pub mod drawdown;
 pub mod ratio;
+pub mod returns;
+#[cfg(feature = "csv")]
+pub mod series;
+pub mod value_at_risk;
 
 /// Total equity at a point in time - equates to [`Balance.total`](Balance).
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
@@ -24,6 +31,192 @@ impl Default for EquityPoint {
     }
 }
 
+impl EquityPoint {
+    /// Derives the simple period returns of an equity curve, where each return is
+    /// `(e_t.total - e_{t-1}.total) / e_{t-1}.total` for each consecutive pair of
+    /// [`EquityPoint`]s.
+    pub fn to_return_series(series: &[EquityPoint]) -> Vec<f64> {
+        series
+            .windows(2)
+            .map(|window| Self::period_return(&window[0], &window[1]))
+            .collect()
+    }
+
+    /// Calculates the simple return between the `prev_point` & `point`, ie/
+    /// `(point.total - prev_point.total) / prev_point.total`.
+    pub fn period_return(prev_point: &EquityPoint, point: &EquityPoint) -> f64 {
+        (point.total - prev_point.total) / prev_point.total
+    }
+
+    /// Derives the log period returns of an equity curve, where each return is
+    /// `ln(e_t.total / e_{t-1}.total)` for each consecutive pair of [`EquityPoint`]s.
+    pub fn to_log_return_series(series: &[EquityPoint]) -> Vec<f64> {
+        series
+            .windows(2)
+            .map(|window| (window[1].total / window[0].total).ln())
+            .collect()
+    }
+
+    /// Derives the cumulative return of each [`EquityPoint`] relative to the initial equity, where
+    /// each return is `(e_t.total - initial_equity) / initial_equity`. Returns all zeros if the
+    /// initial equity is zero.
+    pub fn cumulative_return_series(series: &[EquityPoint], initial_equity: f64) -> Vec<f64> {
+        if initial_equity == 0.0 {
+            return vec![0.0; series.len()];
+        }
+
+        series
+            .iter()
+            .map(|point| (point.total - initial_equity) / initial_equity)
+            .collect()
+    }
+
+    /// Derives the distance of each [`EquityPoint`] from the running high-water-mark as a
+    /// positive fraction, where each value is `(running_max - e_t.total) / running_max`. This is
+    /// the magnitude of the [`EquityPoint::underwater_series`].
+    pub fn peak_to_trough_series(series: &[EquityPoint]) -> Vec<f64> {
+        Self::underwater_series(series)
+            .into_iter()
+            .map(f64::abs)
+            .collect()
+    }
+
+    /// Derives the underwater curve of an equity curve, where each value is
+    /// `(e_t.total - running_max) / running_max`. Values are zero at a new high-water-mark &
+    /// negative whilst in a drawdown.
+    pub fn underwater_series(series: &[EquityPoint]) -> Vec<f64> {
+        let mut running_max = f64::NEG_INFINITY;
+
+        series
+            .iter()
+            .map(|point| {
+                running_max = running_max.max(point.total);
+                (point.total - running_max) / running_max
+            })
+            .collect()
+    }
+
+    /// Calculates the fraction of [`EquityPoint`]s where the equity is below the running
+    /// high-water-mark. Returns zero for an empty equity curve.
+    pub fn time_underwater(series: &[EquityPoint]) -> f64 {
+        if series.is_empty() {
+            return 0.0;
+        }
+
+        let underwater = Self::underwater_series(series)
+            .into_iter()
+            .filter(|drawdown| *drawdown < 0.0)
+            .count();
+
+        underwater as f64 / series.len() as f64
+    }
+
+    /// Calculates the annualised volatility of an equity curve, ie/ the population standard
+    /// deviation of the [`EquityPoint::to_return_series`] scaled by `sqrt(periods_per_year)`
+    /// (eg/ 252.0 for daily [`EquityPoint`]s, or 252.0 * 24.0 for hourly). Returns `None` if
+    /// there are fewer than two [`EquityPoint`]s.
+    pub fn annualized_volatility(series: &[EquityPoint], periods_per_year: f64) -> Option<f64> {
+        if series.len() < 2 {
+            return None;
+        }
+
+        let returns = Self::to_return_series(series)
+            .into_iter()
+            .collect::<RunningStats>();
+
+        Some(returns.std_dev() * periods_per_year.sqrt())
+    }
+
+    /// Calculates the semi-deviation of an equity curve, ie/ the population standard deviation
+    /// of only the negative period returns, for use in downside risk measures such as the
+    /// Sortino ratio. Returns zero if there are no negative returns, or `None` if there are
+    /// fewer than two [`EquityPoint`]s.
+    pub fn semi_deviation(series: &[EquityPoint]) -> Option<f64> {
+        if series.len() < 2 {
+            return None;
+        }
+
+        let negative_returns = Self::to_return_series(series)
+            .into_iter()
+            .filter(|period_return| *period_return < 0.0)
+            .collect::<RunningStats>();
+
+        Some(negative_returns.std_dev())
+    }
+
+    /// Calculates the marginal contribution of the period return between the `prev_point` and
+    /// `point` to the running Sharpe ratio, ie/ the change in `(mean - risk_free_daily) / std_dev`
+    /// caused by including the period return in the `running` [`RunningStats`] of all previous
+    /// period returns. The Sharpe ratio of a dataset with zero standard deviation is zero.
+    ///
+    /// The `running` [`RunningStats`] are not updated.
+    pub fn sharpe_contribution(
+        running: &RunningStats,
+        point: &EquityPoint,
+        prev_point: &EquityPoint,
+        risk_free_daily: f64,
+    ) -> f64 {
+        let sharpe = |stats: &RunningStats| match stats.std_dev() == 0.0 {
+            true => 0.0,
+            false => (stats.mean - risk_free_daily) / stats.std_dev(),
+        };
+
+        let mut next = *running;
+        next.update(Self::period_return(prev_point, point));
+
+        sharpe(&next) - sharpe(running)
+    }
+
+    /// Detects temporal gaps in an equity curve (eg/ caused by an interrupted data feed), where
+    /// consecutive [`EquityPoint`]s are further apart than the `expected_interval` plus the
+    /// `tolerance`.
+    ///
+    /// Returns the index of the [`EquityPoint`] ending each gap, and the missing [`Duration`]
+    /// (ie/ the time between the [`EquityPoint`]s less the `expected_interval`).
+    pub fn detect_gaps(
+        series: &[EquityPoint],
+        expected_interval: Duration,
+        tolerance: Duration,
+    ) -> Vec<(usize, Duration)> {
+        series
+            .windows(2)
+            .enumerate()
+            .filter_map(|(index, window)| {
+                let elapsed = window[1].time - window[0].time;
+                (elapsed > expected_interval + tolerance)
+                    .then(|| (index + 1, elapsed - expected_interval))
+            })
+            .collect()
+    }
+
+    /// Forward-fills the missing timestamps of an equity curve, inserting an [`EquityPoint`]
+    /// every `expected_interval` within each gap that repeats the last known equity total.
+    ///
+    /// Returns the series unchanged if the `expected_interval` is not positive.
+    pub fn fill_gaps(series: &[EquityPoint], expected_interval: Duration) -> Vec<EquityPoint> {
+        if expected_interval <= Duration::zero() {
+            return series.to_vec();
+        }
+
+        let mut filled: Vec<EquityPoint> = Vec::with_capacity(series.len());
+        for point in series {
+            if let Some(&last) = filled.last() {
+                let mut next = EquityPoint {
+                    time: last.time + expected_interval,
+                    ..last
+                };
+                while next.time < point.time {
+                    filled.push(next);
+                    next.time += expected_interval;
+                }
+            }
+            filled.push(*point);
+        }
+
+        filled
+    }
+}
+
 impl From<Balance> for EquityPoint {
     fn from(balance: Balance) -> Self {
         Self {
@@ -54,7 +247,6 @@ impl PositionSummariser for EquityPoint {
 mod tests {
     use super::*;
     use crate::test_util::position;
-    use chrono::Duration;
     use std::ops::Add;
 
     #[test]
@@ -138,4 +330,171 @@ mod tests {
             );
         }
     }
+
+    fn equity_curve(totals: &[f64]) -> Vec<EquityPoint> {
+        let base_time = Utc::now();
+        totals
+            .iter()
+            .enumerate()
+            .map(|(day, &total)| EquityPoint {
+                time: base_time.add(Duration::days(day as i64)),
+                total,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn equity_point_to_return_series() {
+        let returns = EquityPoint::to_return_series(&equity_curve(&[100.0, 110.0, 99.0]));
+
+        assert_eq!(returns.len(), 2);
+        assert!((returns[0] - 0.1).abs() < 1e-12);
+        assert!((returns[1] + 0.1).abs() < 1e-12);
+
+        assert!(EquityPoint::to_return_series(&[]).is_empty());
+        assert!(EquityPoint::to_return_series(&equity_curve(&[100.0])).is_empty());
+    }
+
+    #[test]
+    fn equity_point_to_log_return_series() {
+        let returns = EquityPoint::to_log_return_series(&equity_curve(&[100.0, 110.0, 99.0]));
+
+        assert_eq!(returns.len(), 2);
+        assert!((returns[0] - 1.1_f64.ln()).abs() < 1e-12);
+        assert!((returns[1] - 0.9_f64.ln()).abs() < 1e-12);
+
+        // Log returns are additive across periods
+        let total: f64 = returns.iter().sum();
+        assert!((total - 0.99_f64.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn equity_point_cumulative_return_and_peak_to_trough_series() {
+        let curve = equity_curve(&[100.0, 80.0, 90.0, 120.0, 90.0]);
+
+        assert_eq!(
+            EquityPoint::cumulative_return_series(&curve, 100.0),
+            vec![0.0, -0.2, -0.1, 0.2, -0.1]
+        );
+        assert_eq!(
+            EquityPoint::cumulative_return_series(&curve, 0.0),
+            vec![0.0; 5]
+        );
+
+        assert_eq!(
+            EquityPoint::peak_to_trough_series(&curve),
+            vec![0.0, 0.2, 0.1, 0.0, 0.25]
+        );
+        assert!(EquityPoint::peak_to_trough_series(&[]).is_empty());
+    }
+
+    #[test]
+    fn equity_point_underwater_series() {
+        let rising = equity_curve(&[100.0, 110.0, 120.0]);
+        assert_eq!(EquityPoint::underwater_series(&rising), vec![0.0, 0.0, 0.0]);
+        assert_eq!(EquityPoint::time_underwater(&rising), 0.0);
+
+        let curve = equity_curve(&[100.0, 80.0, 90.0, 120.0, 90.0]);
+        assert_eq!(
+            EquityPoint::underwater_series(&curve),
+            vec![0.0, -0.2, -0.1, 0.0, -0.25]
+        );
+        assert_eq!(EquityPoint::time_underwater(&curve), 0.6);
+
+        assert!(EquityPoint::underwater_series(&[]).is_empty());
+        assert_eq!(EquityPoint::time_underwater(&[]), 0.0);
+    }
+
+    #[test]
+    fn equity_point_detect_and_fill_gaps() {
+        // Daily curve missing the 3 days between index 1 & index 2
+        let mut curve = equity_curve(&[100.0, 110.0, 120.0, 130.0]);
+        curve[2].time = curve[1].time + Duration::days(4);
+        curve[3].time = curve[2].time + Duration::days(1);
+
+        let gaps = EquityPoint::detect_gaps(&curve, Duration::days(1), Duration::hours(1));
+        assert_eq!(gaps, vec![(2, Duration::days(3))]);
+
+        let filled = EquityPoint::fill_gaps(&curve, Duration::days(1));
+        assert_eq!(filled.len(), 7);
+        assert_eq!(
+            filled.iter().map(|point| point.total).collect::<Vec<_>>(),
+            vec![100.0, 110.0, 110.0, 110.0, 110.0, 120.0, 130.0]
+        );
+        assert!(filled
+            .windows(2)
+            .all(|window| window[1].time - window[0].time == Duration::days(1)));
+        assert!(
+            EquityPoint::detect_gaps(&filled, Duration::days(1), Duration::hours(1)).is_empty()
+        );
+    }
+
+    #[test]
+    fn equity_point_detect_gaps_ignores_jitter_within_tolerance() {
+        let mut curve = equity_curve(&[100.0, 110.0, 120.0]);
+        curve[2].time += Duration::minutes(30);
+
+        assert!(EquityPoint::detect_gaps(&curve, Duration::days(1), Duration::hours(1)).is_empty());
+        assert!(EquityPoint::detect_gaps(&[], Duration::days(1), Duration::zero()).is_empty());
+        assert_eq!(EquityPoint::fill_gaps(&curve, Duration::zero()), curve);
+    }
+
+    #[test]
+    fn equity_point_annualized_volatility() {
+        // Constant 1% daily return
+        let constant = (0..10)
+            .map(|day| 100.0 * 1.01_f64.powi(day))
+            .collect::<Vec<_>>();
+        let volatility =
+            EquityPoint::annualized_volatility(&equity_curve(&constant), 252.0).unwrap();
+        assert!(volatility.abs() < 1e-12);
+
+        // Returns of +10% & -10%, with a population std. dev. of 10%
+        let volatility =
+            EquityPoint::annualized_volatility(&equity_curve(&[100.0, 110.0, 99.0]), 252.0)
+                .unwrap();
+        assert!((volatility - 0.1 * 252.0_f64.sqrt()).abs() < 1e-9);
+
+        assert_eq!(EquityPoint::annualized_volatility(&[], 252.0), None);
+        assert_eq!(
+            EquityPoint::annualized_volatility(&equity_curve(&[100.0]), 252.0),
+            None
+        );
+    }
+
+    #[test]
+    fn equity_point_semi_deviation() {
+        // Returns of +10%, -10%, +20%, -20%
+        let curve = equity_curve(&[100.0, 110.0, 99.0, 118.8, 95.04]);
+        let semi_deviation = EquityPoint::semi_deviation(&curve).unwrap();
+        assert!((semi_deviation - 0.05).abs() < 1e-9);
+
+        let rising = equity_curve(&[100.0, 110.0, 120.0]);
+        assert_eq!(EquityPoint::semi_deviation(&rising), Some(0.0));
+        assert_eq!(EquityPoint::semi_deviation(&equity_curve(&[100.0])), None);
+    }
+
+    #[test]
+    fn equity_point_sharpe_contributions_sum_to_running_sharpe() {
+        // Returns of +10%, -10%, +20%
+        let curve = equity_curve(&[100.0, 110.0, 99.0, 118.8]);
+
+        let mut running = RunningStats::default();
+        let mut total_contribution = 0.0;
+        for (window, period_return) in curve.windows(2).zip(EquityPoint::to_return_series(&curve)) {
+            total_contribution +=
+                EquityPoint::sharpe_contribution(&running, &window[1], &window[0], 0.0);
+            running.update(period_return);
+        }
+
+        // Population std. dev. of [0.1, -0.1, 0.2]
+        let std_dev = (0.14_f64 / 9.0).sqrt();
+        let expected_sharpe = (0.2 / 3.0) / std_dev;
+        assert!((total_contribution - expected_sharpe).abs() < 1e-9);
+
+        // First period return has zero std. dev., so contributes nothing
+        let first =
+            EquityPoint::sharpe_contribution(&RunningStats::default(), &curve[1], &curve[0], 0.0);
+        assert_eq!(first, 0.0);
+    }
 }
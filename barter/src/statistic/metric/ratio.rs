@@ -1,5 +1,10 @@
-use crate::statistic::summary::pnl::PnLReturnSummary;
+use crate::statistic::{
+    algorithm::RunningStats,
+    metric::{returns::DAYS_PER_YEAR, EquityPoint},
+    summary::pnl::PnLReturnSummary,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 pub trait Ratio {
     fn init(risk_free_return: f64) -> Self;
@@ -141,6 +146,53 @@ pub fn calculate_annual(ratio_per_trade: f64, trades_per_day: f64, trading_days:
     calculate_daily(ratio_per_trade, trades_per_day) * (trading_days as f64).sqrt()
 }
 
+/// Calculates the annualised Information Ratio of a strategy equity curve relative to a benchmark
+/// equity curve. This is the mean of the period excess returns (strategy return - benchmark
+/// return) divided by their standard deviation (tracking error).
+///
+/// Only [`EquityPoint`]s with timestamps present in both curves are used. The annualisation
+/// factor is the number of excess return periods per year spanned by the matched timestamps.
+///
+/// Returns `None` if the curves have fewer than two matched timestamps, the matched timestamps
+/// span no time, or the tracking error is zero.
+///
+/// See documentation: <https://www.investopedia.com/terms/i/informationratio.asp>
+pub fn information_ratio(
+    strategy_curve: &[EquityPoint],
+    benchmark_curve: &[EquityPoint],
+) -> Option<f64> {
+    let benchmark = benchmark_curve
+        .iter()
+        .map(|point| (point.time, *point))
+        .collect::<HashMap<_, _>>();
+
+    let (strategy, benchmark): (Vec<EquityPoint>, Vec<EquityPoint>) = strategy_curve
+        .iter()
+        .filter_map(|point| Some((*point, *benchmark.get(&point.time)?)))
+        .unzip();
+
+    if strategy.len() < 2 {
+        return None;
+    }
+
+    let excess_returns = EquityPoint::to_return_series(&strategy)
+        .into_iter()
+        .zip(EquityPoint::to_return_series(&benchmark))
+        .map(|(strategy, benchmark)| strategy - benchmark)
+        .collect::<RunningStats>();
+    let tracking_error = excess_returns.std_dev();
+
+    let seconds_per_year = DAYS_PER_YEAR * 24.0 * 60.0 * 60.0;
+    let years = (strategy[strategy.len() - 1].time - strategy[0].time).num_seconds() as f64
+        / seconds_per_year;
+
+    if years <= 0.0 || tracking_error == 0.0 {
+        return None;
+    }
+
+    Some(excess_returns.mean / tracking_error * (excess_returns.count as f64 / years).sqrt())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -451,4 +503,42 @@ mod tests {
             assert_eq!(actual, test.expected_annual)
         }
     }
+
+    #[test]
+    fn information_ratio_relative_to_benchmark() {
+        // Construct a daily equity curve starting at 100.0 from the provided period returns
+        let base_time = chrono::Utc::now();
+        let curve = |returns: &[f64]| {
+            let mut total = 100.0;
+            std::iter::once(0.0)
+                .chain(returns.iter().copied())
+                .enumerate()
+                .map(|(day, period_return)| {
+                    total *= 1.0 + period_return;
+                    EquityPoint {
+                        time: base_time + chrono::Duration::days(day as i64),
+                        total,
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let benchmark = curve(&[0.01, 0.01, 0.01, 0.01]);
+
+        // Strategy identical to the benchmark has zero tracking error
+        assert_eq!(information_ratio(&benchmark, &benchmark), None);
+
+        // Excess returns of +1%, -1%, +1%, -1% have a zero mean
+        let matched = curve(&[0.02, 0.0, 0.02, 0.0]);
+        let ir = information_ratio(&matched, &benchmark).unwrap();
+        assert!(ir.abs() < 1e-9);
+
+        // Strategy outperforms the benchmark in every period
+        let outperforming = curve(&[0.02, 0.03, 0.02, 0.03]);
+        assert!(information_ratio(&outperforming, &benchmark).unwrap() > 0.0);
+
+        // Fewer than two matched timestamps
+        assert_eq!(information_ratio(&outperforming[..1], &benchmark), None);
+        assert_eq!(information_ratio(&outperforming, &[]), None);
+    }
 }
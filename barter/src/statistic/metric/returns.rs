@@ -0,0 +1,96 @@
+use crate::statistic::metric::EquityPoint;
+
+/// Average number of days in a year, accounting for leap years.
+pub const DAYS_PER_YEAR: f64 = 365.25;
+
+/// Calculates the Compound Annual Growth Rate (CAGR) of an equity curve relative to the initial
+/// equity, using the first & last [`EquityPoint`] timestamps to determine the number of years.
+///
+/// Returns `None` if the equity curve spans no time, or the initial equity is not positive.
+///
+/// See documentation: <https://www.investopedia.com/terms/c/cagr.asp>
+pub fn cagr(equity_curve: &[EquityPoint], initial_equity: f64) -> Option<f64> {
+    let (first, last) = (equity_curve.first()?, equity_curve.last()?);
+
+    let seconds_per_year = DAYS_PER_YEAR * 24.0 * 60.0 * 60.0;
+    let years = (last.time - first.time).num_seconds() as f64 / seconds_per_year;
+
+    if years <= 0.0 || initial_equity <= 0.0 {
+        return None;
+    }
+
+    Some((last.total / initial_equity).powf(1.0 / years) - 1.0)
+}
+
+/// Calculates the total return of an equity curve relative to the initial equity.
+///
+/// Returns `None` if the equity curve is empty, or the initial equity is not positive.
+pub fn total_return(equity_curve: &[EquityPoint], initial_equity: f64) -> Option<f64> {
+    let last = equity_curve.last()?;
+
+    if initial_equity <= 0.0 {
+        return None;
+    }
+
+    Some((last.total - initial_equity) / initial_equity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    fn equity_curve(points: &[(Duration, f64)]) -> Vec<EquityPoint> {
+        let base_time = Utc::now();
+        points
+            .iter()
+            .map(|&(offset, total)| EquityPoint {
+                time: base_time + offset,
+                total,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn cagr_of_equity_doubling_over_one_year_is_one() {
+        let one_year = Duration::seconds((DAYS_PER_YEAR * 24.0 * 60.0 * 60.0) as i64);
+        let curve = equity_curve(&[
+            (Duration::zero(), 1000.0),
+            (Duration::days(100), 1300.0),
+            (one_year, 2000.0),
+        ]);
+
+        let actual = cagr(&curve, 1000.0).unwrap();
+        assert!((actual - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn cagr_of_equity_quadrupling_over_two_years_is_one() {
+        let two_years = Duration::seconds((2.0 * DAYS_PER_YEAR * 24.0 * 60.0 * 60.0) as i64);
+        let curve = equity_curve(&[(Duration::zero(), 1000.0), (two_years, 4000.0)]);
+
+        let actual = cagr(&curve, 1000.0).unwrap();
+        assert!((actual - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn cagr_is_none_without_elapsed_time_or_positive_initial_equity() {
+        assert_eq!(cagr(&[], 1000.0), None);
+
+        let single_point = equity_curve(&[(Duration::zero(), 2000.0)]);
+        assert_eq!(cagr(&single_point, 1000.0), None);
+
+        let curve = equity_curve(&[(Duration::zero(), 1000.0), (Duration::days(10), 2000.0)]);
+        assert_eq!(cagr(&curve, 0.0), None);
+        assert_eq!(cagr(&curve, -1000.0), None);
+    }
+
+    #[test]
+    fn total_return_of_equity_curve() {
+        let curve = equity_curve(&[(Duration::zero(), 1000.0), (Duration::days(10), 1250.0)]);
+
+        assert_eq!(total_return(&curve, 1000.0), Some(0.25));
+        assert_eq!(total_return(&curve, 0.0), None);
+        assert_eq!(total_return(&[], 1000.0), None);
+    }
+}
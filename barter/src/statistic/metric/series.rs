@@ -0,0 +1,154 @@
+use crate::{portfolio::error::PortfolioError, statistic::metric::EquityPoint};
+use chrono::{DateTime, Utc};
+use std::{
+    fs::File,
+    io::{Read, Write},
+    path::Path,
+};
+
+/// Header row written by [`EquityPointSeries::to_csv`].
+const HEADERS: [&str; 2] = ["timestamp", "equity"];
+
+/// Imports & exports equity curves ([`EquityPoint`] series) as CSV, eg/ to compare a backtest
+/// with results computed by an external system.
+///
+/// The CSV has one `timestamp_rfc3339,equity` row per [`EquityPoint`], optionally preceded by a
+/// header row.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct EquityPointSeries;
+
+impl EquityPointSeries {
+    /// Parses the [`EquityPoint`]s from the CSV provided by the reader. The first row is
+    /// detected as a header row, & skipped, if neither it's timestamp nor it's equity can be
+    /// parsed, so CSVs with or without a header row are supported.
+    ///
+    /// Returns [`PortfolioError::CsvParse`] if any other row is not a valid RFC 3339 timestamp &
+    /// equity pair.
+    pub fn from_csv(reader: impl Read) -> Result<Vec<EquityPoint>, PortfolioError> {
+        csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(reader)
+            .into_records()
+            .enumerate()
+            .filter_map(|(index, record)| {
+                let parsed = record.and_then(|record| {
+                    match record.deserialize::<(DateTime<Utc>, f64)>(None) {
+                        Ok(point) => Ok(Some(point)),
+                        Err(_) if index == 0 && is_header(&record) => Ok(None),
+                        Err(error) => Err(error),
+                    }
+                });
+
+                match parsed {
+                    Ok(point) => point.map(|(time, total)| Ok(EquityPoint { time, total })),
+                    Err(error) => Some(Err(PortfolioError::CsvParse(error.to_string()))),
+                }
+            })
+            .collect()
+    }
+
+    /// Parses the [`EquityPoint`]s from the CSV file at the provided path. See
+    /// [`EquityPointSeries::from_csv`].
+    pub fn from_csv_path(path: &Path) -> Result<Vec<EquityPoint>, PortfolioError> {
+        Self::from_csv(File::open(path)?)
+    }
+
+    /// Writes the [`EquityPoint`]s as CSV to the provided writer, in the format parsed by
+    /// [`EquityPointSeries::from_csv`].
+    pub fn to_csv(series: &[EquityPoint], writer: impl Write) -> std::io::Result<()> {
+        let mut writer = csv::Writer::from_writer(writer);
+
+        writer.write_record(HEADERS)?;
+        for point in series {
+            writer.serialize((point.time.to_rfc3339(), point.total))?;
+        }
+
+        writer.flush()
+    }
+}
+
+/// Determines if the CSV record is a header row, ie/ neither the timestamp nor the equity field
+/// can be parsed.
+fn is_header(record: &csv::StringRecord) -> bool {
+    let timestamp = record.get(0).unwrap_or_default();
+    let equity = record.get(1).unwrap_or_default();
+
+    timestamp.parse::<DateTime<Utc>>().is_err() && equity.trim().parse::<f64>().is_err()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    #[test]
+    fn equity_point_series_csv_round_trip() {
+        let start = Utc::now();
+        let series = (0..10)
+            .map(|index| EquityPoint {
+                time: start + Duration::milliseconds(index * 1500),
+                total: 10_000.0 + index as f64 * 12.345,
+            })
+            .collect::<Vec<_>>();
+
+        let mut csv = Vec::new();
+        EquityPointSeries::to_csv(&series, &mut csv).unwrap();
+        assert!(csv.starts_with(b"timestamp,equity\n"));
+
+        let imported = EquityPointSeries::from_csv(csv.as_slice()).unwrap();
+        assert_eq!(imported.len(), series.len());
+        for (imported, expected) in imported.iter().zip(&series) {
+            assert_eq!(imported.time, expected.time);
+            assert!((imported.total - expected.total).abs() < 1e-9);
+        }
+
+        let path = std::env::temp_dir().join(format!("equity_curve_{}.csv", Uuid::new_v4()));
+        std::fs::write(&path, &csv).unwrap();
+        let imported_path = EquityPointSeries::from_csv_path(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(imported_path.unwrap(), imported);
+        assert!(matches!(
+            EquityPointSeries::from_csv_path(&path),
+            Err(PortfolioError::IoError(_))
+        ));
+    }
+
+    #[test]
+    fn equity_point_series_from_csv_detects_header_row() {
+        let headerless = "2024-01-01T00:00:00Z,100.0\n2024-01-02T00:00:00Z,110.0\n";
+        let with_header = format!("timestamp,equity\n{headerless}");
+        let custom_header = format!("time,total_equity\n{headerless}");
+
+        for csv in [headerless, &with_header, &custom_header] {
+            let imported = EquityPointSeries::from_csv(csv.as_bytes()).unwrap();
+            assert_eq!(
+                imported.iter().map(|point| point.total).collect::<Vec<_>>(),
+                vec![100.0, 110.0]
+            );
+        }
+
+        assert!(EquityPointSeries::from_csv("timestamp,equity\n".as_bytes())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn equity_point_series_from_csv_rejects_invalid_rows() {
+        let invalid = [
+            "timestamp,equity\nyesterday,100.0\n",
+            "timestamp,equity\n2024-01-01T00:00:00Z,one hundred\n",
+            "timestamp,equity\n2024-01-01T00:00:00Z\n",
+            "2024-01-01T00:00:00Z,100.0\ntimestamp,equity\n",
+            "yesterday,100.0\n",
+        ];
+
+        for csv in invalid {
+            assert!(matches!(
+                EquityPointSeries::from_csv(csv.as_bytes()),
+                Err(PortfolioError::CsvParse(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::statistic::metric::EquityPoint;
+
+/// Minimum number of [`EquityPoint`]s required to calculate a meaningful Value-at-Risk.
+pub const VAR_MIN_DATA_POINTS: usize = 20;
+
+/// Calculates the historical simulation Value-at-Risk (VaR) of an equity curve at the provided
+/// confidence level (eg/ 0.95 for 95% VaR). Period returns are derived from the equity curve and
+/// sorted, with the VaR being the return at the `1.0 - confidence_level` percentile.
+///
+/// The result is a positive number representing the period loss (as a fraction of equity) that
+/// is not expected to be exceeded at the given confidence level. Returns `None` if the equity
+/// curve has fewer than [`VAR_MIN_DATA_POINTS`].
+///
+/// See documentation: <https://www.investopedia.com/terms/v/var.asp>
+pub fn historical_var(equity_curve: &[EquityPoint], confidence_level: f64) -> Option<f64> {
+    let returns = sorted_period_returns(equity_curve)?;
+
+    Some(loss(var_threshold(&returns, confidence_level)))
+}
+
+/// Calculates the historical simulation Conditional Value-at-Risk (CVaR), also known as Expected
+/// Shortfall, of an equity curve at the provided confidence level. This is the mean of the period
+/// returns at or below the [`historical_var`] threshold, expressed as a positive loss.
+///
+/// Returns `None` if the equity curve has fewer than [`VAR_MIN_DATA_POINTS`].
+///
+/// See documentation: <https://www.investopedia.com/terms/c/conditional_value_at_risk.asp>
+pub fn conditional_var(equity_curve: &[EquityPoint], confidence_level: f64) -> Option<f64> {
+    let returns = sorted_period_returns(equity_curve)?;
+    let threshold = var_threshold(&returns, confidence_level);
+
+    let tail = returns
+        .iter()
+        .take_while(|&&period_return| period_return <= threshold)
+        .collect::<Vec<_>>();
+
+    let tail_mean = tail.iter().copied().sum::<f64>() / tail.len() as f64;
+
+    Some(loss(tail_mean))
+}
+
+/// Derives the ascending sorted period returns of an equity curve. Returns `None` if the equity
+/// curve has fewer than [`VAR_MIN_DATA_POINTS`].
+fn sorted_period_returns(equity_curve: &[EquityPoint]) -> Option<Vec<f64>> {
+    if equity_curve.len() < VAR_MIN_DATA_POINTS {
+        return None;
+    }
+
+    let mut returns = EquityPoint::to_return_series(equity_curve);
+    returns.sort_by(f64::total_cmp);
+
+    Some(returns)
+}
+
+/// Returns the period return at the `1.0 - confidence_level` percentile of the sorted returns.
+///
+/// Note: the percentile index is rounded to the nearest integer so that floating point error in
+/// `1.0 - confidence_level` (eg/ 0.09999999999999998) does not shift the index.
+fn var_threshold(sorted_returns: &[f64], confidence_level: f64) -> f64 {
+    let index = ((1.0 - confidence_level) * sorted_returns.len() as f64).round() as usize;
+    sorted_returns[index.min(sorted_returns.len() - 1)]
+}
+
+/// Expresses a period return as a positive loss, where gains equate to no loss.
+fn loss(period_return: f64) -> f64 {
+    (-period_return).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    fn equity_curve(totals: &[f64]) -> Vec<EquityPoint> {
+        let base_time = Utc::now();
+        totals
+            .iter()
+            .enumerate()
+            .map(|(index, &total)| EquityPoint {
+                time: base_time + Duration::days(index as i64),
+                total,
+            })
+            .collect()
+    }
+
+    /// Equity curve of 21 points (20 returns) alternating between gains and losses of
+    /// increasing magnitude.
+    fn volatile_equity_curve() -> Vec<EquityPoint> {
+        let mut totals = vec![1000.0];
+        for index in 1..=20 {
+            let magnitude = index as f64 / 100.0;
+            let period_return = if index % 2 == 0 {
+                magnitude
+            } else {
+                -magnitude
+            };
+            totals.push(totals[index - 1] * (1.0 + period_return));
+        }
+        equity_curve(&totals)
+    }
+
+    #[test]
+    fn historical_and_conditional_var_with_insufficient_data_points() {
+        let curve = equity_curve(&[100.0; VAR_MIN_DATA_POINTS - 1]);
+        assert_eq!(historical_var(&curve, 0.95), None);
+        assert_eq!(conditional_var(&curve, 0.95), None);
+    }
+
+    #[test]
+    fn historical_var_is_the_loss_at_the_confidence_percentile() {
+        let curve = volatile_equity_curve();
+
+        // Sorted returns start: [-0.19, -0.17, -0.15, ...], 5th percentile index = 0.05 * 20
+        let var = historical_var(&curve, 0.95).unwrap();
+        assert!((var - 0.17).abs() < 1e-10);
+
+        // Sorted returns 10th percentile index = 0.1 * 20
+        let var = historical_var(&curve, 0.90).unwrap();
+        assert!((var - 0.15).abs() < 1e-10);
+    }
+
+    #[test]
+    fn conditional_var_is_the_mean_loss_beyond_var() {
+        let curve = volatile_equity_curve();
+
+        // Mean of [-0.19, -0.17, -0.15]
+        let cvar = conditional_var(&curve, 0.90).unwrap();
+        assert!((cvar - 0.17).abs() < 1e-10);
+    }
+
+    #[test]
+    fn conditional_var_is_at_least_var_and_both_are_non_negative() {
+        let curves = vec![
+            volatile_equity_curve(),
+            equity_curve(&(0..30).map(|i| 100.0 + i as f64).collect::<Vec<_>>()),
+            equity_curve(&(0..30).map(|i| 100.0 - i as f64).collect::<Vec<_>>()),
+        ];
+
+        for curve in curves {
+            for confidence_level in [0.9, 0.95, 0.99] {
+                let var = historical_var(&curve, confidence_level).unwrap();
+                let cvar = conditional_var(&curve, confidence_level).unwrap();
+
+                assert!(var >= 0.0);
+                assert!(cvar >= var);
+            }
+        }
+    }
+}
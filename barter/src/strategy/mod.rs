@@ -1,4 +1,8 @@
-use crate::data::MarketMeta;
+use crate::{
+    data::MarketMeta,
+    execution::FillRejected,
+    portfolio::position::{Position, PositionExit},
+};
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::Instrument, Exchange, Market};
 use chrono::{DateTime, Utc};
@@ -8,10 +12,37 @@ use std::collections::HashMap;
 /// Barter example RSI strategy [`SignalGenerator`] implementation.
 pub mod example;
 
+/// [`SignalGenerator`]s for logging the [`Decision`]s of a strategy, and replaying them to
+/// reproduce a backtest.
+pub mod replay;
+
+/// Walk-forward optimisation of [`SignalGenerator`] parameters over historical data.
+pub mod walk_forward;
+
 /// May generate an advisory [`Signal`] as a result of analysing an input [`MarketEvent`].
 pub trait SignalGenerator {
     /// Optionally return a [`Signal`] given input [`MarketEvent`].
     fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal>;
+
+    /// Callback invoked after a [`Position`] has been entered as a result of this strategy's
+    /// [`Signal`]s. Default implementation is a no-op.
+    fn on_position_opened(&mut self, _position: &Position) {}
+
+    /// Callback invoked after a [`Position`] has been exited, with the resulting
+    /// [`PositionExit`]. Default implementation is a no-op.
+    fn on_position_closed(&mut self, _position: &PositionExit) {}
+
+    /// Callback invoked after a [`FillEvent`](crate::execution::FillEvent) generated from this
+    /// strategy's [`Signal`]s is rejected for violating its
+    /// [`TimeInForce`](crate::execution::TimeInForce). Default implementation is a no-op.
+    fn on_fill_rejected(&mut self, _rejected: &FillRejected) {}
+
+    /// Number of [`MarketEvent`]s required to warm up the strategy's indicators. Any [`Signal`]s
+    /// generated from the first `warmup_bars` [`MarketEvent`]s are discarded by the
+    /// [`Trader`](crate::engine::trader::Trader). Default implementation requires no warm-up.
+    fn warmup_bars(&self) -> usize {
+        0
+    }
 }
 
 /// Advisory [`Signal`] for a [`Market`] detailing the [`SignalStrength`] associated with each
@@ -61,12 +92,27 @@ impl Decision {
     pub fn is_exit(&self) -> bool {
         matches!(self, Decision::CloseLong | Decision::CloseShort)
     }
+
+    /// Determines if a [`Decision`] closes a position (close_long or close_short). Synonymous
+    /// with [`Decision::is_exit`].
+    pub fn is_close(&self) -> bool {
+        self.is_exit()
+    }
 }
 
-/// Strength of an advisory [`Signal`] decision produced by [`SignalGenerator`] strategy.
+/// Strength of an advisory [`Signal`] decision produced by [`SignalGenerator`] strategy, within
+/// the range [0.0, 1.0]. Expresses the strategy's conviction, and is used to scale the size of
+/// the resulting order (eg/ 0.3 for a weak signal).
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct SignalStrength(pub f64);
 
+impl SignalStrength {
+    /// Determines if the [`SignalStrength`] is within the valid range [0.0, 1.0].
+    pub fn is_valid(&self) -> bool {
+        (0.0..=1.0).contains(&self.0)
+    }
+}
+
 /// Force exit Signal produced after an [`Engine`](crate::engine::Engine) receives a
 /// [`Command::ExitPosition`](crate::engine::Command) from an external source.
 #[derive(Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
@@ -154,4 +200,45 @@ mod tests {
         let decision = Decision::Long;
         assert_eq!(decision.is_exit(), false)
     }
+
+    #[test]
+    fn should_return_decision_is_close() {
+        let decision = Decision::CloseLong;
+        assert!(decision.is_close())
+    }
+
+    #[test]
+    fn should_return_decision_is_not_close() {
+        let decision = Decision::Short;
+        assert!(!decision.is_close())
+    }
+
+    #[test]
+    fn should_classify_every_decision_as_either_entry_or_close() {
+        let decisions = [
+            Decision::Long,
+            Decision::CloseLong,
+            Decision::Short,
+            Decision::CloseShort,
+        ];
+
+        for decision in decisions {
+            // Exhaustive match ensures any new Decision variant must be explicitly classified
+            let (expected_entry, expected_close) = match decision {
+                Decision::Long | Decision::Short => (true, false),
+                Decision::CloseLong | Decision::CloseShort => (false, true),
+            };
+
+            assert_eq!(
+                decision.is_entry(),
+                expected_entry,
+                "{decision:?} failed is_entry"
+            );
+            assert_eq!(
+                decision.is_close(),
+                expected_close,
+                "{decision:?} failed is_close"
+            );
+        }
+    }
 }
@@ -0,0 +1,381 @@
+use super::{Decision, Signal, SignalGenerator, SignalStrength};
+use crate::{
+    data::MarketMeta,
+    execution::FillRejected,
+    portfolio::{
+        error::PortfolioError,
+        position::{Position, PositionExit},
+    },
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use chrono::{DateTime, Utc};
+use std::{
+    collections::{HashMap, VecDeque},
+    path::Path,
+};
+
+/// [`SignalGenerator`] wrapper that records the [`Decision`]s & [`SignalStrength`]s of every
+/// [`Signal`] generated by the inner strategy, keyed by the `exchange_time` of the
+/// [`MarketEvent`] that yielded it.
+///
+/// The decision log can be saved via [`LoggingStrategy::save_json`] & replayed by a
+/// [`ReplayStrategy`] to exactly reproduce a backtest without re-running the signal generation.
+#[derive(Clone, Debug)]
+pub struct LoggingStrategy<Strategy> {
+    inner: Strategy,
+    decisions: Vec<(DateTime<Utc>, Decision, SignalStrength)>,
+    market_events: usize,
+}
+
+impl<Strategy> LoggingStrategy<Strategy>
+where
+    Strategy: SignalGenerator,
+{
+    /// Constructs a new [`LoggingStrategy`] wrapping the provided strategy.
+    pub fn new(inner: Strategy) -> Self {
+        Self {
+            inner,
+            decisions: Vec::new(),
+            market_events: 0,
+        }
+    }
+
+    /// Returns every [`Decision`] logged so far, in the order they were generated.
+    pub fn decisions(&self) -> &[(DateTime<Utc>, Decision, SignalStrength)] {
+        &self.decisions
+    }
+
+    /// Saves the [`Decision`] log as JSON to the provided path, in the format loaded by
+    /// [`ReplayStrategy::from_json`].
+    pub fn save_json(&self, path: &Path) -> Result<(), PortfolioError> {
+        let json = serde_json::to_string_pretty(&self.decisions)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Consumes the [`LoggingStrategy`], returning the inner strategy.
+    pub fn into_inner(self) -> Strategy {
+        self.inner
+    }
+}
+
+impl<Strategy> SignalGenerator for LoggingStrategy<Strategy>
+where
+    Strategy: SignalGenerator,
+{
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        // Count every MarketEvent, since the Trader warms up on them whether or not they yield
+        // a Signal
+        self.market_events += 1;
+        let signal = self.inner.generate_signal(market)?;
+
+        // Signals generated during warm-up are discarded by the Trader, so are not logged
+        if self.market_events > self.inner.warmup_bars() {
+            let mut decisions = signal
+                .signals
+                .iter()
+                .map(|(decision, strength)| (*decision, *strength))
+                .collect::<Vec<_>>();
+            decisions.sort_by_key(|(decision, _)| *decision);
+            self.decisions.extend(
+                decisions
+                    .into_iter()
+                    .map(|(decision, strength)| (market.exchange_time, decision, strength)),
+            );
+        }
+
+        Some(signal)
+    }
+
+    fn on_position_opened(&mut self, position: &Position) {
+        self.inner.on_position_opened(position)
+    }
+
+    fn on_position_closed(&mut self, position: &PositionExit) {
+        self.inner.on_position_closed(position)
+    }
+
+    fn on_fill_rejected(&mut self, rejected: &FillRejected) {
+        self.inner.on_fill_rejected(rejected)
+    }
+
+    fn warmup_bars(&self) -> usize {
+        self.inner.warmup_bars()
+    }
+}
+
+/// [`SignalGenerator`] that replays a pre-computed [`Decision`] log (eg/ one saved by a
+/// [`LoggingStrategy`]), enabling execution quality to be debugged independently of the signal
+/// generation logic.
+///
+/// Every logged [`Decision`] with a timestamp equal to the `exchange_time` of the input
+/// [`MarketEvent`] is replayed in a single [`Signal`] with its logged [`SignalStrength`]. Logged
+/// [`Decision`]s older than the input [`MarketEvent`] are skipped.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ReplayStrategy {
+    pub decisions: VecDeque<(DateTime<Utc>, Decision, SignalStrength)>,
+}
+
+impl ReplayStrategy {
+    /// Constructs a new [`ReplayStrategy`] that replays the provided [`Decision`] log.
+    pub fn new<Decisions>(decisions: Decisions) -> Self
+    where
+        Decisions: IntoIterator<Item = (DateTime<Utc>, Decision, SignalStrength)>,
+    {
+        Self {
+            decisions: decisions.into_iter().collect(),
+        }
+    }
+
+    /// Loads a [`ReplayStrategy`] from a JSON [`Decision`] log saved by
+    /// [`LoggingStrategy::save_json`].
+    pub fn from_json(path: &Path) -> Result<Self, PortfolioError> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(Self {
+            decisions: serde_json::from_str(&json)?,
+        })
+    }
+}
+
+impl SignalGenerator for ReplayStrategy {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        let close = match &market.kind {
+            DataKind::Trade(trade) => trade.price,
+            DataKind::Candle(candle) => candle.close,
+            _ => return None,
+        };
+
+        // Skip any logged Decisions older than the current MarketEvent
+        while self
+            .decisions
+            .front()
+            .is_some_and(|(time, _, _)| *time < market.exchange_time)
+        {
+            self.decisions.pop_front();
+        }
+
+        let mut signals = HashMap::new();
+        while let Some(&(time, decision, strength)) = self.decisions.front() {
+            if time != market.exchange_time {
+                break;
+            }
+            signals.insert(decision, strength);
+            self.decisions.pop_front();
+        }
+
+        if signals.is_empty() {
+            return None;
+        }
+
+        Some(Signal {
+            time: market.exchange_time,
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            signals,
+            market_meta: MarketMeta {
+                close,
+                time: market.exchange_time,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_integration::model::Side;
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    /// Alternates between a Long & CloseLong [`Decision`] on every [`MarketEvent`], after
+    /// warming up on the first.
+    struct AlternatingStrategy {
+        long: bool,
+    }
+
+    impl SignalGenerator for AlternatingStrategy {
+        fn generate_signal(
+            &mut self,
+            market: &MarketEvent<Instrument, DataKind>,
+        ) -> Option<Signal> {
+            let decision = match self.long {
+                true => Decision::Long,
+                false => Decision::CloseLong,
+            };
+            self.long = !self.long;
+
+            Some(Signal {
+                time: Utc::now(),
+                exchange: market.exchange.clone(),
+                instrument: market.instrument.clone(),
+                signals: HashMap::from([(decision, SignalStrength(0.5))]),
+                market_meta: MarketMeta {
+                    close: 1000.0,
+                    time: market.exchange_time,
+                },
+            })
+        }
+
+        fn warmup_bars(&self) -> usize {
+            1
+        }
+    }
+
+    /// Only yields a [`Signal`] once it has seen `warmup_bars` [`MarketEvent`]s, like an
+    /// indicator based strategy would.
+    struct IndicatorStrategy {
+        seen: usize,
+    }
+
+    impl SignalGenerator for IndicatorStrategy {
+        fn generate_signal(
+            &mut self,
+            market: &MarketEvent<Instrument, DataKind>,
+        ) -> Option<Signal> {
+            self.seen += 1;
+            if self.seen < self.warmup_bars() {
+                return None;
+            }
+
+            Some(Signal {
+                time: Utc::now(),
+                exchange: market.exchange.clone(),
+                instrument: market.instrument.clone(),
+                signals: HashMap::from([(Decision::Long, SignalStrength(0.7))]),
+                market_meta: MarketMeta {
+                    close: 1000.0,
+                    time: market.exchange_time,
+                },
+            })
+        }
+
+        fn warmup_bars(&self) -> usize {
+            2
+        }
+    }
+
+    fn market_events(n: i64) -> Vec<MarketEvent<Instrument, DataKind>> {
+        let start = Utc::now();
+        (0..n)
+            .map(|minute| {
+                let mut market = market_event_trade(Side::Buy);
+                market.exchange_time = start + Duration::minutes(minute);
+                market
+            })
+            .collect()
+    }
+
+    fn decisions(signal: Option<Signal>) -> Vec<Decision> {
+        let mut decisions = signal
+            .map(|signal| signal.signals.into_keys().collect::<Vec<_>>())
+            .unwrap_or_default();
+        decisions.sort();
+        decisions
+    }
+
+    #[test]
+    fn replay_strategy_reproduces_decisions_logged_by_logging_strategy() {
+        let markets = market_events(4);
+
+        let mut logging = LoggingStrategy::new(AlternatingStrategy { long: true });
+        let logged = markets
+            .iter()
+            .map(|market| decisions(logging.generate_signal(market)))
+            .collect::<Vec<_>>();
+
+        // Decisions generated during warm-up are not logged
+        assert_eq!(logging.decisions().len(), 3);
+        assert_eq!(
+            logging.decisions()[0],
+            (
+                markets[1].exchange_time,
+                Decision::CloseLong,
+                SignalStrength(0.5)
+            )
+        );
+
+        let path = std::env::temp_dir().join(format!("decisions_{}.json", Uuid::new_v4()));
+        logging.save_json(&path).unwrap();
+        let replay = ReplayStrategy::from_json(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        let mut replay = replay.unwrap();
+        assert_eq!(decisions(replay.generate_signal(&markets[0])), vec![]);
+        for (market, expected) in markets.iter().zip(logged).skip(1) {
+            let signal = replay.generate_signal(market);
+            assert!(signal
+                .as_ref()
+                .unwrap()
+                .signals
+                .values()
+                .all(|strength| *strength == SignalStrength(0.5)));
+            assert_eq!(decisions(signal), expected);
+        }
+        assert!(replay.decisions.is_empty());
+    }
+
+    #[test]
+    fn replay_strategy_skips_decisions_older_than_market_event() {
+        let markets = market_events(3);
+        let mut replay = ReplayStrategy::new([
+            (
+                markets[0].exchange_time,
+                Decision::Long,
+                SignalStrength(1.0),
+            ),
+            (
+                markets[2].exchange_time,
+                Decision::CloseLong,
+                SignalStrength(1.0),
+            ),
+            (
+                markets[2].exchange_time,
+                Decision::Short,
+                SignalStrength(0.3),
+            ),
+        ]);
+
+        // No Decision logged for the second MarketEvent, & the first is never seen
+        assert_eq!(decisions(replay.generate_signal(&markets[1])), vec![]);
+        assert_eq!(replay.decisions.len(), 2);
+
+        let signal = replay.generate_signal(&markets[2]).unwrap();
+        assert_eq!(signal.time, markets[2].exchange_time);
+        assert_eq!(signal.signals[&Decision::Short], SignalStrength(0.3));
+        assert_eq!(
+            decisions(Some(signal)),
+            vec![Decision::CloseLong, Decision::Short]
+        );
+    }
+
+    #[test]
+    fn logging_strategy_counts_warmup_market_events_that_yield_no_signal() {
+        let markets = market_events(4);
+        let mut logging = LoggingStrategy::new(IndicatorStrategy { seen: 0 });
+
+        // First MarketEvent yields None, second yields a Signal that is still within warm-up
+        assert!(logging.generate_signal(&markets[0]).is_none());
+        assert!(logging.generate_signal(&markets[1]).is_some());
+        assert!(logging.decisions().is_empty());
+
+        logging.generate_signal(&markets[2]);
+        logging.generate_signal(&markets[3]);
+        assert_eq!(
+            logging.decisions(),
+            &[
+                (
+                    markets[2].exchange_time,
+                    Decision::Long,
+                    SignalStrength(0.7)
+                ),
+                (
+                    markets[3].exchange_time,
+                    Decision::Long,
+                    SignalStrength(0.7)
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use super::SignalGenerator;
+use crate::{
+    engine::{backtest::BacktestConfig, error::EngineError, Engine},
+    execution::Fees,
+    statistic::summary::trading::TradingSummary,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Configuration for a walk-forward optimisation run via [`walk_forward_optimize`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct WalkForwardConfig {
+    /// Number of sequential periods the historical data is divided into.
+    pub n_periods: usize,
+    /// Fraction of each period used as in-sample data to fit the strategy parameters. The
+    /// remainder of the period is used as out-of-sample data to validate them.
+    pub in_sample_fraction: f64,
+    /// Candidate strategy parameter sets evaluated on each in-sample period.
+    pub parameter_grid: Vec<HashMap<String, f64>>,
+    /// Backtest configuration used to backtest every parameter set.
+    pub simulation: BacktestConfig,
+    /// Simulated fee percentages applied to every backtest fill.
+    pub simulated_fees_pct: Fees,
+}
+
+/// Outcome of a single walk-forward period.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct WalkForwardResult {
+    /// Index of the walk-forward period, starting at zero.
+    pub period: usize,
+    /// Parameter set that performed best on the in-sample data.
+    pub best_params: HashMap<String, f64>,
+    /// [`TradingSummary`] of the best parameter set on the in-sample data.
+    pub in_sample: TradingSummary,
+    /// [`TradingSummary`] of the best parameter set on the out-of-sample data.
+    pub out_of_sample: TradingSummary,
+}
+
+/// Walk-forward optimisation of strategy parameters.
+///
+/// Divides the historical data into `n_periods` sequential periods, each split into an in-sample
+/// and out-of-sample segment. Every parameter set in the grid is backtested on the in-sample
+/// segment, and the set with the highest sum of [`Position`](crate::portfolio::position::Position)
+/// PnL returns is then validated on the out-of-sample segment.
+///
+/// Every period holds `data.len() / n_periods` events, except the final period which also holds
+/// the remainder so no data is discarded. Periods with an empty in-sample or out-of-sample segment
+/// are skipped, as is every period if the parameter grid is empty.
+///
+/// Returns the first [`EngineError`] encountered whilst backtesting a segment.
+pub fn walk_forward_optimize<Strategy, F>(
+    data: Vec<MarketEvent<Instrument, DataKind>>,
+    config: WalkForwardConfig,
+    build_strategy: F,
+) -> Result<Vec<WalkForwardResult>, EngineError>
+where
+    Strategy: SignalGenerator + Send + 'static,
+    F: Fn(&HashMap<String, f64>) -> Strategy,
+{
+    if config.n_periods == 0 || config.parameter_grid.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let period_len = data.len() / config.n_periods;
+    if period_len == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut results = Vec::with_capacity(config.n_periods);
+    for period in 0..config.n_periods {
+        // Final period absorbs the remainder of the data
+        let start = period * period_len;
+        let end = match period + 1 == config.n_periods {
+            true => data.len(),
+            false => start + period_len,
+        };
+        let events = &data[start..end];
+
+        let split = (events.len() as f64 * config.in_sample_fraction).round() as usize;
+        if split == 0 || split >= events.len() {
+            continue;
+        }
+        let (in_sample, out_of_sample) = events.split_at(split);
+
+        // Fit the parameters on the in-sample segment
+        let mut best: Option<(&HashMap<String, f64>, TradingSummary)> = None;
+        for params in &config.parameter_grid {
+            let summary = backtest(in_sample, build_strategy(params), &config)?;
+            match &best {
+                Some((_, best_summary))
+                    if summary.pnl_returns.total.sum <= best_summary.pnl_returns.total.sum => {}
+                _ => best = Some((params, summary)),
+            }
+        }
+        let Some((best_params, in_sample)) = best else {
+            continue;
+        };
+
+        // Validate the fitted parameters on the out-of-sample segment
+        let out_of_sample = backtest(out_of_sample, build_strategy(best_params), &config)?;
+
+        results.push(WalkForwardResult {
+            period,
+            best_params: best_params.clone(),
+            in_sample,
+            out_of_sample,
+        });
+    }
+
+    Ok(results)
+}
+
+/// Backtest the provided strategy against the historical [`MarketEvent`]s via
+/// [`Engine::backtest`], returning the resulting [`TradingSummary`].
+fn backtest<Strategy>(
+    events: &[MarketEvent<Instrument, DataKind>],
+    strategy: Strategy,
+    config: &WalkForwardConfig,
+) -> Result<TradingSummary, EngineError>
+where
+    Strategy: SignalGenerator + Send + 'static,
+{
+    Engine::backtest(
+        strategy,
+        config.simulation,
+        events.to_vec(),
+        config.simulated_fees_pct,
+    )
+    .map(|result| result.stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::MarketMeta,
+        strategy::{Decision, Signal, SignalStrength},
+        test_util::market_event_trade,
+    };
+    use barter_data::subscription::trade::PublicTrade;
+    use barter_integration::model::Side;
+    use chrono::{Duration, Utc};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    /// Enters a long Position on the first MarketEvent & exits it after `hold` MarketEvents.
+    struct HoldStrategy {
+        hold: usize,
+        seen: usize,
+    }
+
+    impl SignalGenerator for HoldStrategy {
+        fn generate_signal(
+            &mut self,
+            market: &MarketEvent<Instrument, DataKind>,
+        ) -> Option<Signal> {
+            let close = match &market.kind {
+                DataKind::Trade(trade) => trade.price,
+                _ => return None,
+            };
+
+            let decision = match self.seen {
+                0 => Decision::Long,
+                seen if seen == self.hold => Decision::CloseLong,
+                _ => {
+                    self.seen += 1;
+                    return None;
+                }
+            };
+            self.seen += 1;
+
+            Some(Signal {
+                time: market.exchange_time,
+                exchange: market.exchange.clone(),
+                instrument: market.instrument.clone(),
+                signals: HashMap::from([(decision, SignalStrength(1.0))]),
+                market_meta: MarketMeta {
+                    close,
+                    time: market.exchange_time,
+                },
+            })
+        }
+    }
+
+    fn rising_trades(n: usize) -> Vec<MarketEvent<Instrument, DataKind>> {
+        let start = Utc::now() - Duration::days(1);
+        (0..n)
+            .map(|i| {
+                let mut market = market_event_trade(Side::Buy);
+                market.exchange_time = start + Duration::minutes(i as i64);
+                market.kind = DataKind::Trade(PublicTrade {
+                    id: i.to_string(),
+                    price: 1000.0 + (i as f64 * 10.0),
+                    amount: 1.0,
+                    side: Side::Buy,
+                });
+                market
+            })
+            .collect()
+    }
+
+    fn config(n_periods: usize, holds: &[f64]) -> WalkForwardConfig {
+        WalkForwardConfig {
+            n_periods,
+            in_sample_fraction: 0.5,
+            parameter_grid: holds
+                .iter()
+                .map(|&hold| HashMap::from([("hold".to_owned(), hold)]))
+                .collect(),
+            simulation: BacktestConfig {
+                starting_cash: 10_000.0,
+                default_order_value: 100.0,
+            },
+            simulated_fees_pct: Fees {
+                exchange: 0.0,
+                slippage: 0.0,
+                network: 0.0,
+            },
+        }
+    }
+
+    fn hold_strategy(params: &HashMap<String, f64>) -> HoldStrategy {
+        HoldStrategy {
+            hold: params["hold"] as usize,
+            seen: 0,
+        }
+    }
+
+    #[test]
+    fn walk_forward_optimize_selects_best_in_sample_params_for_each_period() {
+        let results =
+            walk_forward_optimize(rising_trades(20), config(2, &[1.0, 3.0]), hold_strategy)
+                .unwrap();
+
+        assert_eq!(results.len(), 2);
+        for (period, result) in results.iter().enumerate() {
+            assert_eq!(result.period, period);
+
+            // Holding longer in a rising market is more profitable
+            assert_eq!(result.best_params["hold"], 3.0);
+            assert_eq!(result.in_sample.pnl_returns.total.count, 1);
+            assert_eq!(result.out_of_sample.pnl_returns.total.count, 1);
+            assert!(result.out_of_sample.pnl_returns.total.sum > 0.0);
+        }
+    }
+
+    #[test]
+    fn walk_forward_optimize_final_period_includes_data_remainder() {
+        /// Counts every MarketEvent it receives across all backtests.
+        struct CountingStrategy(Arc<AtomicUsize>);
+
+        impl SignalGenerator for CountingStrategy {
+            fn generate_signal(&mut self, _: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+                self.0.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+
+        // 23 events over 2 periods of 11 events, with a remainder of 1 event
+        let seen = Arc::new(AtomicUsize::new(0));
+        let results = walk_forward_optimize(rising_trades(23), config(2, &[1.0]), |_| {
+            CountingStrategy(Arc::clone(&seen))
+        })
+        .unwrap();
+
+        // Single parameter set, so every event is backtested exactly once
+        assert_eq!(results.len(), 2);
+        assert_eq!(seen.load(Ordering::Relaxed), 23);
+    }
+
+    #[test]
+    fn walk_forward_optimize_returns_no_results_for_degenerate_config() {
+        let optimize = |data, config| walk_forward_optimize(data, config, hold_strategy).unwrap();
+
+        assert!(optimize(rising_trades(20), config(0, &[1.0])).is_empty());
+        assert!(optimize(rising_trades(20), config(2, &[])).is_empty());
+        assert!(optimize(Vec::new(), config(2, &[1.0])).is_empty());
+        assert!(optimize(rising_trades(1), config(2, &[1.0])).is_empty());
+    }
+}
@@ -116,3 +116,85 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
         "failed because Engine's command_rx.await is blocking the Engine from stopping"
     )
 }
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn engine_run_async_consumes_async_market_feed_until_finished() {
+    use barter::event::Event;
+
+    let (_command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+    let engine_id = Uuid::new_v4();
+    let market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(10_000.0)
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    // Mock async feed that yields MarketEvents from a tokio task, finishing once dropped
+    let (market_tx, market_rx) = mpsc::channel(4);
+    tokio::spawn(async move {
+        for _ in 0..10 {
+            market_tx
+                .send(market_event_trade(Side::Buy))
+                .await
+                .expect("Trader dropped the async market feed");
+        }
+    });
+
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+    let trader = Trader::builder()
+        .engine_id(engine_id)
+        .market(market.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(event_tx)
+        .portfolio(Arc::clone(&portfolio))
+        .data(market_rx)
+        .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees::default(),
+        }))
+        .build()
+        .expect("failed to build trader");
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(vec![trader])
+        .trader_command_txs(HashMap::from_iter([(market, trader_command_tx)]))
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: 10_000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .build()
+        .expect("failed to build engine");
+
+    let actual = tokio::time::timeout(Duration::from_secs(5), engine.run_async()).await;
+    assert!(
+        actual.is_ok(),
+        "Engine did not stop once the async market feed finished"
+    );
+
+    let market_events = std::iter::from_fn(|| event_rx.try_recv().ok())
+        .filter(|event| matches!(event, Event::Market(_)))
+        .count();
+    assert_eq!(market_events, 10);
+}